@@ -30,5 +30,8 @@ fn main() {
     d.insert(1.into(), Double::new(2.0));
     d.get(&Int::new(1));
     let mut a = IntArrayVector::new();
-    a.push(vec![1, 2, 3]);
+    a.push(vec![1.into(), 2.into(), 3.into()]);
+    // null elements and null rows
+    a.push_raw(Some(&[Some(4), None]));
+    a.push_null();
 }
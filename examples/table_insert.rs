@@ -10,8 +10,8 @@ async fn table_writer() -> Result<(), Error> {
     let mut client = builder.connect().await.unwrap();
 
     let mut prices = DoubleArrayVector::new();
-    let price1 = vec![1.1, 2.2, 3.3];
-    prices.push(price1);
+    let price1 = [Some(1.1), Some(2.2), Some(3.3)];
+    prices.push_raw(Some(&price1));
     println!("{prices}");
 
     // write one row
@@ -25,9 +25,10 @@ async fn table_writer() -> Result<(), Error> {
     println!("{res}");
 
     // write a table
-    let price2 = vec![4.4, 5.5];
-    prices.push(price2);
-    let v_int = IntVector::from_raw(&[2, 3]).into();
+    let price2 = [Some(4.4), None];
+    prices.push_raw(Some(&price2));
+    prices.push_null();
+    let v_int = IntVector::from_raw(&[2, 3, 4]).into();
     let v_double_array_vector = VectorImpl::from(prices);
     println!("{v_double_array_vector}");
     let mut builder = TableBuilder::new();
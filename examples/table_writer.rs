@@ -87,7 +87,7 @@ async fn main() {
 
     tokio::spawn(async move {
         let mut inserted = 0usize;
-        let mut writer = TableWriter::new(client, stream_table, 512).await;
+        let mut writer = TableWriter::new(client, stream_table, 512).await.unwrap();
         while let Some(event) = rx.recv().await {
             let mut row = build_table_row(&event);
             let res = writer.append_row(&mut row).await;
@@ -0,0 +1,201 @@
+use super::table_writer::{schema, schema_names, schema_types};
+use super::{Client, WriteTarget};
+use crate::{
+    error::{Error, Result},
+    types::*,
+};
+
+/// Options of [`AutoFitTableAppender::with_upsert`], passed on to `upsert!`.
+#[derive(Debug, Clone, Default)]
+pub struct UpsertOptions {
+    ignore_null: bool,
+    key_columns: Vec<String>,
+    sort_columns: Vec<String>,
+}
+
+impl UpsertOptions {
+    /// Keeps the old value of a column when the new one is null, false by default.
+    pub fn with_ignore_null(&mut self, ignore_null: bool) -> &mut Self {
+        self.ignore_null = ignore_null;
+        self
+    }
+
+    /// Columns identifying a row. Keyed tables use their keys by default and DFS tables need
+    /// them.
+    pub fn with_key_columns(&mut self, columns: &[&str]) -> &mut Self {
+        self.key_columns = columns.iter().map(|c| c.to_string()).collect();
+        self
+    }
+
+    /// Columns the partitions of a DFS table are sorted by after the upsert.
+    pub fn with_sort_columns(&mut self, columns: &[&str]) -> &mut Self {
+        self.sort_columns = columns.iter().map(|c| c.to_string()).collect();
+        self
+    }
+}
+
+/// Appends tables after converting their columns to the types of the target table.
+///
+/// Columns are matched by position and converted with [`VectorImpl::cast`], so integers widen,
+/// strings parse into temporal values and decimals take the scale of the target column.
+pub struct AutoFitTableAppender {
+    client: Client,
+    script: String,
+    expression: String,
+    column_names: Vec<String>,
+    column_types: Vec<DataType>,
+    scales: Vec<Option<u32>>,
+}
+
+impl AutoFitTableAppender {
+    /// Creates an `AutoFitTableAppender` for `table`, the name of a shared table or a
+    /// `(db_path, table_name)` pair.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dolphindb::client::{AutoFitTableAppender, ClientBuilder, UpsertOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut builder = ClientBuilder::new("127.0.0.1:8848");
+    ///     builder.with_auth(("admin", "123456"));
+    ///     let client = builder.connect().await.unwrap();
+    ///     let mut appender = AutoFitTableAppender::new(client, ("dfs://trades", "pt"))
+    ///         .await
+    ///         .unwrap();
+    ///     let mut options = UpsertOptions::default();
+    ///     options.with_key_columns(&["sym"]).with_ignore_null(true);
+    ///     appender.with_upsert(&options);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema of the table can't be fetched.
+    pub async fn new(mut client: Client, table: impl Into<WriteTarget>) -> Result<Self> {
+        let table = table.into();
+        let expression = table.expression();
+
+        let column_types = schema_types(&mut client, &expression).await?;
+        let column_names = schema_names(&mut client, &expression).await?;
+        // colDefs.extra holds the scale of decimal columns.
+        let scales = match schema(&mut client, &expression, "extra").await? {
+            VectorImpl::Int(scales) => scales
+                .iter()
+                .zip(column_types.iter())
+                .map(|(s, t)| match t {
+                    DataType::Decimal32
+                    | DataType::Decimal64
+                    | DataType::Decimal128
+                    | DataType::Decimal32Array
+                    | DataType::Decimal64Array
+                    | DataType::Decimal128Array => {
+                        s.into_inner().and_then(|s| u32::try_from(s).ok())
+                    }
+                    _ => None,
+                })
+                .collect(),
+            _ => return Err(Error::BadResponse("unexpected column extra".into())),
+        };
+
+        Ok(Self {
+            client,
+            script: match &table {
+                WriteTarget::Shared(name) => format!("tableInsert{{'{}'}}", name),
+                WriteTarget::Dfs { .. } => format!("tableInsert{{{}}}", expression),
+            },
+            expression,
+            column_names,
+            column_types,
+            scales,
+        })
+    }
+
+    /// Writes with `upsert!` instead of `tableInsert`, updating the rows whose keys exist and
+    /// inserting the others.
+    pub fn with_upsert(&mut self, options: &UpsertOptions) -> &mut Self {
+        let columns = |c: &[String]| c.iter().map(|c| format!("`{}", c)).collect::<String>();
+
+        let mut script = format!(
+            "upsert!{{{}, , ignoreNull={}",
+            self.expression, options.ignore_null
+        );
+        if !options.key_columns.is_empty() {
+            script += &format!(", keyColNames={}", columns(&options.key_columns));
+        }
+        if !options.sort_columns.is_empty() {
+            script += &format!(", sortColumns={}", columns(&options.sort_columns));
+        }
+        script.push('}');
+
+        self.script = script;
+        self
+    }
+
+    /// Converts the columns of `table` to the types of the target table and writes them,
+    /// returning the number of rows written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of columns differs, a column can't be converted or
+    /// writing fails.
+    pub async fn append(&mut self, table: &Table) -> Result<usize> {
+        let columns = self.fit(table)?;
+        let rows = table.len();
+
+        let mut builder = TableBuilder::new();
+        builder.with_contents(columns, self.column_names.clone());
+        self.client
+            .run_function(self.script.as_str(), &[builder.build()?.into()])
+            .await?;
+
+        Ok(rows)
+    }
+
+    fn fit(&self, table: &Table) -> Result<Vec<VectorImpl>> {
+        if table.columns().len() != self.column_types.len() {
+            return Err(Error::ConstraintsViolated(format!(
+                "table has {} columns, but {} provided",
+                self.column_types.len(),
+                table.columns().len()
+            )));
+        }
+
+        let mut columns = Vec::with_capacity(self.column_types.len());
+        for (i, column) in table.columns().iter().enumerate() {
+            let data_type = self.column_types[i];
+            let fitted = match data_type {
+                DataType::Decimal32 | DataType::Decimal64 | DataType::Decimal128 => {
+                    let scale = self.scales[i].ok_or_else(|| {
+                        Error::BadResponse(format!(
+                            "missing scale of column {}",
+                            self.column_names[i]
+                        ))
+                    })?;
+                    let mut v = if column.data_type() == data_type {
+                        column.clone()
+                    } else {
+                        column.cast_decimal(data_type, scale)?
+                    };
+                    v.set_decimal_scale(scale)?;
+                    v
+                }
+                DataType::Decimal32Array | DataType::Decimal64Array | DataType::Decimal128Array
+                    if column.data_type() == data_type =>
+                {
+                    let mut v = column.clone();
+                    if let Some(scale) = self.scales[i] {
+                        v.set_decimal_scale(scale)?;
+                    }
+                    v
+                }
+                _ if column.data_type() == data_type => column.clone(),
+                _ => column.cast(data_type)?,
+            };
+            columns.push(fitted);
+        }
+
+        Ok(columns)
+    }
+}
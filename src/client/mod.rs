@@ -2,23 +2,35 @@
 //!
 //! See [DolphinDB connection docs](https://docs.dolphindb.cn/zh/rustdoc/chap3_basic_operations_landingpage.html) for more information.
 
+mod auto_fit_table_appender;
 mod builder;
+mod multithreaded_table_writer;
+mod partitioned_table_appender;
 mod request_info;
+mod spool;
 mod table_writer;
 use bytes::BytesMut;
+use chrono_tz::Tz;
 pub(crate) use request_info::*;
 use std::collections::HashMap;
 use std::net::SocketAddr;
 use tokio::io::{AsyncWriteExt, BufReader};
 use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
 
+pub use auto_fit_table_appender::{AutoFitTableAppender, UpsertOptions};
 pub use builder::ClientBuilder;
-pub use table_writer::TableWriter;
+pub use multithreaded_table_writer::{MultithreadedTableWriter, WorkerStatus, WriterOptions};
+pub use partitioned_table_appender::PartitionedTableAppender;
+pub use spool::{SpoolMetrics, SpoolOptions};
+pub use table_writer::{TableWriter, WriteSummary, WriteTarget};
 
 use crate::request::BehaviorOptions;
 use crate::{
-    error::Result, request::Request, response::Response, types::ConstantImpl, Deserialize, Endian,
-    Serialize,
+    error::Result,
+    request::Request,
+    response::Response,
+    types::{ConstantImpl, ScalarImpl, VectorImpl},
+    Deserialize, Endian, Serialize,
 };
 
 #[derive(Debug)]
@@ -28,6 +40,7 @@ pub struct Client {
     rx: BufReader<OwnedReadHalf>,
     endian: Endian,
     option: BehaviorOptions,
+    timezone: Tz,
 }
 
 impl Client {
@@ -122,6 +135,27 @@ impl Client {
         self.tx.local_addr().unwrap()
     }
 
+    /// The zone temporal values of this server are wall clock time in, e.g. to pass to
+    /// [`Timestamp::to_zoned`](crate::types::Timestamp::to_zoned).
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Interprets a temporal value this server returned as wall clock time in its
+    /// [`timezone`](Self::timezone), see [`ScalarImpl::to_zoned`].
+    pub fn to_zoned(&self, value: &ScalarImpl) -> Result<Option<chrono::DateTime<Tz>>> {
+        value.to_zoned(&self.timezone)
+    }
+
+    /// Interprets each element of a temporal vector this server returned as wall clock time in
+    /// its [`timezone`](Self::timezone), see [`VectorImpl::to_zoned`].
+    pub fn to_zoned_vector(
+        &self,
+        values: &VectorImpl,
+    ) -> Result<Vec<Option<chrono::DateTime<Tz>>>> {
+        values.to_zoned(&self.timezone)
+    }
+
     pub(crate) fn rx(&mut self) -> &mut BufReader<OwnedReadHalf> {
         &mut self.rx
     }
@@ -0,0 +1,391 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+use tokio::task::JoinHandle;
+use tokio::time::{timeout_at, Instant};
+
+use super::partitioned_table_appender::PartitionScheme;
+use super::table_writer::{buffer_column, push_value};
+use super::{Client, TableWriter, WriteTarget};
+use crate::{
+    error::Error,
+    error::Result,
+    types::{ConstantImpl, DataType, PrimitiveType, VectorImpl},
+};
+
+/// Options of a [`MultithreadedTableWriter`].
+#[derive(Debug, Clone)]
+pub struct WriterOptions {
+    batch_size: u32,
+    throttle: Duration,
+    queue_capacity: usize,
+    partition_column: Option<String>,
+    insert_function: Option<String>,
+}
+
+impl Default for WriterOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 1024,
+            throttle: Duration::from_secs(1),
+            queue_capacity: 65536,
+            partition_column: None,
+            insert_function: None,
+        }
+    }
+}
+
+impl WriterOptions {
+    /// Rows a worker buffers before inserting them, 1024 by default.
+    pub fn with_batch_size(&mut self, batch_size: u32) -> &mut Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Longest time a row stays buffered before it is inserted, one second by default.
+    pub fn with_throttle(&mut self, throttle: Duration) -> &mut Self {
+        self.throttle = throttle;
+        self
+    }
+
+    /// Rows queued per worker before [`MultithreadedTableWriter::insert`] waits, 65536 by
+    /// default.
+    pub fn with_queue_capacity(&mut self, queue_capacity: usize) -> &mut Self {
+        self.queue_capacity = queue_capacity;
+        self
+    }
+
+    /// Routes rows of the same partition to the same worker, so that each partition is written
+    /// by one connection. Rows are distributed round robin otherwise.
+    ///
+    /// For a DFS table `column` must be a partitioning column and rows go by its partition
+    /// scheme, see [`PartitionedTableAppender`](super::PartitionedTableAppender). For a shared
+    /// table rows with the same value in `column` go to the same worker.
+    pub fn with_partition_column(&mut self, column: &str) -> &mut Self {
+        self.partition_column = Some(column.to_string());
+        self
+    }
+
+    /// Server function the workers insert batches with, see [`TableWriter::with_insert_function`].
+    pub fn with_insert_function(&mut self, function: &str) -> &mut Self {
+        self.insert_function = Some(function.to_string());
+        self
+    }
+}
+
+/// Row counts of a worker of a [`MultithreadedTableWriter`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkerStatus {
+    /// Rows inserted into the table.
+    pub sent_rows: u64,
+    /// Rows queued or buffered, not yet inserted.
+    pub pending_rows: u64,
+    /// Rows rejected by the writer or lost in a failed insert.
+    pub failed_rows: u64,
+    /// The most recent error of the worker.
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct WorkerStats {
+    sent: AtomicU64,
+    pending: AtomicU64,
+    failed: AtomicU64,
+    last_error: Mutex<Option<String>>,
+}
+
+impl WorkerStats {
+    fn sent(&self, rows: u64) {
+        self.sent.fetch_add(rows, Ordering::Relaxed);
+        self.pending.fetch_sub(rows, Ordering::Relaxed);
+    }
+
+    fn failed(&self, rows: u64, error: &Error) {
+        self.failed.fetch_add(rows, Ordering::Relaxed);
+        self.pending.fetch_sub(rows, Ordering::Relaxed);
+        *self.last_error.lock().unwrap() = Some(error.to_string());
+    }
+
+    fn status(&self) -> WorkerStatus {
+        WorkerStatus {
+            sent_rows: self.sent.load(Ordering::Relaxed),
+            pending_rows: self.pending.load(Ordering::Relaxed),
+            failed_rows: self.failed.load(Ordering::Relaxed),
+            last_error: self.last_error.lock().unwrap().clone(),
+        }
+    }
+}
+
+/// Writes rows into a table through several connections in the background.
+///
+/// Each [`Client`] is driven by its own worker task with a [`TableWriter`], which inserts a
+/// batch once `batch_size` rows are buffered or the oldest buffered row has waited for the
+/// throttle. Dropping the writer lets the workers finish in the background, use
+/// [`wait_for_completion`](Self::wait_for_completion) to wait for them.
+pub struct MultithreadedTableWriter {
+    senders: Vec<Sender<Vec<PrimitiveType>>>,
+    workers: Vec<JoinHandle<()>>,
+    stats: Vec<Arc<WorkerStats>>,
+    column_count: usize,
+    router: Option<Router>,
+    next: usize,
+}
+
+// computes the partition of a row from its value in the partition column.
+struct Router {
+    index: usize,
+    column_type: DataType,
+    // holds the value of the current row as the writer buffers it.
+    value: VectorImpl,
+    scheme: PartitionScheme,
+}
+
+impl Router {
+    fn partition(&mut self, row: &[PrimitiveType]) -> Result<u64> {
+        self.value.resize(0);
+        push_value(&mut self.value, self.column_type, row[self.index].clone())?;
+        let Some(ConstantImpl::Scalar(mut value)) = self.value.get(0) else {
+            unreachable!()
+        };
+        // temporal values are buffered as the elapsed units of the column type.
+        if value.data_type() != self.column_type {
+            value = value.cast(self.column_type)?;
+        }
+        if value.data_type() != self.scheme.data_type {
+            value = value.cast(self.scheme.data_type)?;
+        }
+        self.scheme.partition(&value).ok_or_else(|| {
+            Error::ConstraintsViolated(format!("{} is outside the partition scheme", value))
+        })
+    }
+}
+
+impl MultithreadedTableWriter {
+    /// Creates a `MultithreadedTableWriter` with one worker per client.
+    ///
+    /// The table must exist, and is either the name of a shared table or a `(db_path, table_name)`
+    /// pair, see [`TableWriter::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dolphindb::client::{ClientBuilder, MultithreadedTableWriter, WriterOptions};
+    /// use dolphindb::types::PrimitiveType;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut clients = Vec::new();
+    ///     for _ in 0..4 {
+    ///         let mut builder = ClientBuilder::new("127.0.0.1:8848");
+    ///         builder.with_auth(("admin", "123456"));
+    ///         clients.push(builder.connect().await.unwrap());
+    ///     }
+    ///     let mut options = WriterOptions::default();
+    ///     options.with_partition_column("sym");
+    ///     let mut writer = MultithreadedTableWriter::new(clients, "trades", &options)
+    ///         .await
+    ///         .unwrap();
+    ///     writer
+    ///         .insert(vec![PrimitiveType::String("AAPL".into()), PrimitiveType::F64(1.5)])
+    ///         .await
+    ///         .unwrap();
+    ///     let status = writer.wait_for_completion().await;
+    ///     println!("{:?}", status);
+    /// }
+    /// ```
+    pub async fn new(
+        mut clients: Vec<Client>,
+        table: impl Into<WriteTarget>,
+        options: &WriterOptions,
+    ) -> Result<Self> {
+        if clients.is_empty() {
+            return Err(Error::ConstraintsViolated(
+                "MultithreadedTableWriter needs at least one client".into(),
+            ));
+        }
+        if options.queue_capacity == 0 {
+            return Err(Error::ConstraintsViolated(
+                "queue capacity must be positive".into(),
+            ));
+        }
+
+        let table = table.into();
+        let scheme = match (&options.partition_column, &table) {
+            (Some(column), WriteTarget::Dfs { .. }) => {
+                Some(PartitionScheme::load(&mut clients[0], &table.expression(), column).await?)
+            }
+            _ => None,
+        };
+        let mut writers = Vec::with_capacity(clients.len());
+        for client in clients {
+            let mut writer = TableWriter::new(client, table.clone(), options.batch_size).await?;
+            if let Some(function) = &options.insert_function {
+                writer.with_insert_function(function);
+            }
+            writers.push(writer);
+        }
+
+        let column_names = writers[0].column_names().to_vec();
+        let router = match &options.partition_column {
+            Some(column) => {
+                let index = column_names
+                    .iter()
+                    .position(|n| n == column)
+                    .ok_or_else(|| {
+                        Error::ConstraintsViolated(format!(
+                            "table {} has no column {}",
+                            table.table_name(),
+                            column
+                        ))
+                    })?;
+                let column_type = writers[0].column_types()[index];
+                let value = buffer_column(column_type)?;
+                Some(Router {
+                    index,
+                    column_type,
+                    value,
+                    scheme: scheme.unwrap_or_else(|| PartitionScheme::value(column_type)),
+                })
+            }
+            None => None,
+        };
+
+        let mut senders = Vec::with_capacity(writers.len());
+        let mut workers = Vec::with_capacity(writers.len());
+        let mut stats = Vec::with_capacity(writers.len());
+        for writer in writers {
+            let (tx, rx) = channel(options.queue_capacity);
+            let worker_stats = Arc::new(WorkerStats::default());
+            workers.push(tokio::spawn(run_worker(
+                writer,
+                rx,
+                worker_stats.clone(),
+                options.throttle,
+            )));
+            senders.push(tx);
+            stats.push(worker_stats);
+        }
+
+        Ok(Self {
+            senders,
+            workers,
+            stats,
+            column_count: column_names.len(),
+            router,
+            next: 0,
+        })
+    }
+
+    /// Queues a row in schema order, waiting while the queue of its worker is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the row doesn't match the number of columns, its partition can't be
+    /// computed or the worker stopped.
+    pub async fn insert(&mut self, row: Vec<PrimitiveType>) -> Result<()> {
+        if row.len() != self.column_count {
+            return Err(Error::ConstraintsViolated(format!(
+                "table has {} columns, but {} provided",
+                self.column_count,
+                row.len()
+            )));
+        }
+
+        let worker = match &mut self.router {
+            Some(router) => (router.partition(&row)? % self.senders.len() as u64) as usize,
+            None => {
+                self.next = (self.next + 1) % self.senders.len();
+                self.next
+            }
+        };
+
+        let stats = &self.stats[worker];
+        stats.pending.fetch_add(1, Ordering::Relaxed);
+        if let Err(e) = self.senders[worker].send(row).await {
+            stats.pending.fetch_sub(1, Ordering::Relaxed);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
+    /// Returns the row counts of each worker.
+    pub fn get_status(&self) -> Vec<WorkerStatus> {
+        self.stats.iter().map(|s| s.status()).collect()
+    }
+
+    /// Stops accepting rows, waits until the workers have inserted every queued row and
+    /// returns the final row counts of each worker.
+    pub async fn wait_for_completion(mut self) -> Vec<WorkerStatus> {
+        self.senders.clear();
+
+        for (worker, stats) in self.workers.drain(..).zip(self.stats.iter()) {
+            if let Err(e) = worker.await {
+                // the worker is gone along with its rows.
+                let pending = stats.pending.load(Ordering::Relaxed);
+                stats.failed(pending, &Error::ChannelClosed(e.to_string()));
+            }
+        }
+
+        self.get_status()
+    }
+}
+
+async fn run_worker(
+    mut writer: TableWriter,
+    mut rx: Receiver<Vec<PrimitiveType>>,
+    stats: Arc<WorkerStats>,
+    throttle: Duration,
+) {
+    // when the rows in the writer's buffer are due.
+    let mut deadline: Option<Instant> = None;
+
+    loop {
+        let row = match deadline {
+            Some(d) => match timeout_at(d, rx.recv()).await {
+                Ok(row) => row,
+                Err(_) => {
+                    flush(&mut writer, &stats).await;
+                    deadline = None;
+                    continue;
+                }
+            },
+            None => rx.recv().await,
+        };
+
+        let Some(mut row) = row else {
+            break;
+        };
+
+        // the result doesn't tell whether a batch was inserted, e.g. with a void insert
+        // function, so the rows that left the buffer are counted instead.
+        let buffered = writer.size() as u64;
+        let result = writer.append_row(&mut row).await;
+        let done = buffered + 1 - writer.size() as u64;
+        match result {
+            Ok(_) => stats.sent(done),
+            Err(e) => stats.failed(done, &e),
+        }
+
+        if writer.size() == 0 {
+            deadline = None;
+        } else {
+            deadline.get_or_insert_with(|| Instant::now() + throttle);
+        }
+    }
+
+    flush(&mut writer, &stats).await;
+}
+
+async fn flush(writer: &mut TableWriter, stats: &WorkerStats) {
+    let buffered = writer.size() as u64;
+    if buffered == 0 {
+        return;
+    }
+
+    match writer.flush().await {
+        Ok(_) => stats.sent(buffered),
+        Err(e) => stats.failed(buffered, &e),
+    }
+}
@@ -0,0 +1,385 @@
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use futures::future::try_join_all;
+
+use super::Client;
+use crate::{
+    error::{Error, Result},
+    types::*,
+};
+
+// partition types as `schema().partitionType` reports them, COMPO reports a vector of levels.
+const VALUE: i32 = 1;
+const RANGE: i32 = 2;
+const LIST: i32 = 3;
+const HASH: i32 = 5;
+
+// how values of the partition column map to partitions.
+#[derive(Debug)]
+enum Domain {
+    Value,
+    // sorted boundaries, partition i holds [b[i], b[i + 1]).
+    Range(Vec<ScalarImpl>),
+    List(HashMap<ScalarImpl, u64>),
+    Hash(u32),
+}
+
+/// Appends tables into a partitioned DFS table through several connections.
+///
+/// The appender reads the partition scheme of the table once and computes the partition of each
+/// row on the client. Rows of a partition always go through the same connection, so the
+/// connections append in parallel without contending for partitions. For COMPO partitioned
+/// tables the level of `partition_column` is used.
+#[derive(Debug)]
+pub struct PartitionedTableAppender {
+    clients: Vec<Client>,
+    script: String,
+    partition_column: String,
+    scheme: PartitionScheme,
+}
+
+impl PartitionedTableAppender {
+    /// Creates a `PartitionedTableAppender` for the table `table_name` of the database `db_path`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dolphindb::client::{ClientBuilder, PartitionedTableAppender};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut clients = Vec::new();
+    ///     for _ in 0..4 {
+    ///         let mut builder = ClientBuilder::new("127.0.0.1:8848");
+    ///         builder.with_auth(("admin", "123456"));
+    ///         clients.push(builder.connect().await.unwrap());
+    ///     }
+    ///     let mut appender =
+    ///         PartitionedTableAppender::new(clients, "dfs://trades", "pt", "sym")
+    ///             .await
+    ///             .unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `clients` is empty, the table can't be loaded or `partition_column`
+    /// is not a partitioning column of the table.
+    pub async fn new(
+        mut clients: Vec<Client>,
+        db_path: &str,
+        table_name: &str,
+        partition_column: &str,
+    ) -> Result<Self> {
+        let Some(client) = clients.first_mut() else {
+            return Err(Error::ConstraintsViolated(
+                "PartitionedTableAppender needs at least one client".into(),
+            ));
+        };
+
+        let table = format!("loadTable(\"{}\", \"{}\")", db_path, table_name);
+        let scheme = PartitionScheme::load(client, &table, partition_column).await?;
+
+        Ok(Self {
+            clients,
+            script: format!("tableInsert{{{}}}", table),
+            partition_column: partition_column.to_string(),
+            scheme,
+        })
+    }
+
+    /// Appends the rows of `table`, whose columns must match the schema of the partitioned table,
+    /// and returns the number of rows appended.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `table` has no partition column, a row falls outside the partition
+    /// scheme or appending fails on any connection. Rows sent through the other connections
+    /// may have been appended in that case.
+    pub async fn append(&mut self, table: &Table) -> Result<usize> {
+        let column = table
+            .get_columns_by_name(&self.partition_column)
+            .ok_or_else(|| {
+                Error::ConstraintsViolated(format!(
+                    "table has no partition column {}",
+                    self.partition_column
+                ))
+            })?;
+        let column = if column.data_type() == self.scheme.data_type {
+            column.clone()
+        } else {
+            column.cast(self.scheme.data_type)?
+        };
+
+        let mut groups = vec![Vec::new(); self.clients.len()];
+        for i in 0..column.len() {
+            let Some(ConstantImpl::Scalar(value)) = column.get(i) else {
+                unreachable!()
+            };
+            let partition = self.scheme.partition(&value).ok_or_else(|| {
+                Error::ConstraintsViolated(format!(
+                    "row {}: {} is outside the partition scheme",
+                    i, value
+                ))
+            })?;
+            groups[(partition % self.clients.len() as u64) as usize].push(i);
+        }
+
+        let mut batches = Vec::new();
+        for (client, rows) in self.clients.iter_mut().zip(groups) {
+            if !rows.is_empty() {
+                batches.push((client, table.take(&rows)?));
+            }
+        }
+
+        let script = self.script.as_str();
+        let results = try_join_all(batches.into_iter().map(|(client, batch)| async move {
+            let rows = batch.len();
+            client.run_function(script, &[batch.into()]).await?;
+            Ok::<_, Error>(rows)
+        }))
+        .await?;
+
+        Ok(results.into_iter().sum())
+    }
+}
+
+// how the values of the partition column map to partitions.
+#[derive(Debug)]
+pub(super) struct PartitionScheme {
+    // values convert to this type before their partition is computed.
+    pub(super) data_type: DataType,
+    domain: Domain,
+}
+
+impl PartitionScheme {
+    // one partition per value of `data_type`, for tables without a partition scheme.
+    pub(super) fn value(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            domain: Domain::Value,
+        }
+    }
+
+    // reads the scheme of the level of `partition_column` of the DFS table `table`.
+    pub(super) async fn load(
+        client: &mut Client,
+        table: &str,
+        partition_column: &str,
+    ) -> Result<Self> {
+        let scheme = client
+            .run_script(
+                format!(
+                    "s = schema({}); [s.partitionType, s.partitionColumnName, s.partitionSchema, s.partitionColumnType]",
+                    table
+                )
+                .as_str(),
+            )
+            .await?
+            .ok_or_else(|| Error::BadResponse(format!("no schema for table {}", table)))?;
+        let VectorImpl::Any(scheme) = scheme.as_vector()? else {
+            return Err(Error::BadResponse(format!(
+                "unexpected schema for table {}",
+                table
+            )));
+        };
+        let [partition_type, names, schema, column_type] = &scheme[..] else {
+            return Err(Error::BadResponse(format!(
+                "unexpected schema for table {}",
+                table
+            )));
+        };
+
+        let not_partitioned = || {
+            Error::ConstraintsViolated(format!(
+                "table {} is not partitioned by {}",
+                table, partition_column
+            ))
+        };
+        let (partition_type, schema, column_type) = match partition_type.get() {
+            ConstantImpl::Scalar(t) => {
+                if names.get().to_string() != partition_column {
+                    return Err(not_partitioned());
+                }
+                (
+                    int(t)?,
+                    schema.get().clone(),
+                    int(column_type.get().as_scalar()?)?,
+                )
+            }
+            // COMPO partitions report each level.
+            ConstantImpl::Vector(types) => {
+                let level = scalars(names.get().as_vector()?)
+                    .iter()
+                    .position(|c| c.to_string() == partition_column)
+                    .ok_or_else(not_partitioned)?;
+                let unexpected = || Error::BadResponse("unexpected COMPO schema".into());
+                let level_type =
+                    |v: &VectorImpl| scalars(v).get(level).ok_or_else(unexpected).and_then(int);
+                let VectorImpl::Any(levels) = schema.get().as_vector()? else {
+                    return Err(unexpected());
+                };
+                (
+                    level_type(types)?,
+                    levels.get(level).ok_or_else(unexpected)?.get().clone(),
+                    level_type(column_type.get().as_vector()?)?,
+                )
+            }
+            _ => return Err(Error::BadResponse("unexpected partition type".into())),
+        };
+
+        // values convert to the type of the scheme, e.g. timestamps to dates for a DATE scheme.
+        let mut data_type = DataType::try_from(column_type as u8)?;
+        let domain = match partition_type {
+            VALUE => {
+                data_type = schema.as_vector()?.data_type();
+                Domain::Value
+            }
+            RANGE => {
+                data_type = schema.as_vector()?.data_type();
+                Domain::Range(scalars(schema.as_vector()?))
+            }
+            LIST => {
+                let mut values = HashMap::new();
+                let VectorImpl::Any(lists) = schema.as_vector()? else {
+                    return Err(Error::BadResponse("unexpected LIST schema".into()));
+                };
+                for (i, list) in lists.iter().enumerate() {
+                    data_type = list.raw_data_type();
+                    match list.get() {
+                        ConstantImpl::Scalar(s) => {
+                            values.insert(s.clone(), i as u64);
+                        }
+                        ConstantImpl::Vector(v) => {
+                            for s in scalars(v) {
+                                values.insert(s, i as u64);
+                            }
+                        }
+                        _ => return Err(Error::BadResponse("unexpected LIST schema".into())),
+                    }
+                }
+                Domain::List(values)
+            }
+            HASH => Domain::Hash(int(schema.as_scalar()?)? as u32),
+            t => {
+                return Err(Error::Unsupported {
+                    data_form: "partition scheme".into(),
+                    data_type: t.to_string(),
+                })
+            }
+        };
+
+        Ok(Self { data_type, domain })
+    }
+
+    // identifies the partition of `value`, None if no partition holds it.
+    pub(super) fn partition(&self, value: &ScalarImpl) -> Option<u64> {
+        match &self.domain {
+            Domain::Value => {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                Some(hasher.finish())
+            }
+            Domain::Range(boundaries) => {
+                if value.is_null() {
+                    return None;
+                }
+                let i = boundaries.partition_point(|b| b <= value);
+                (i > 0 && i < boundaries.len()).then(|| i as u64 - 1)
+            }
+            Domain::List(values) => values.get(value).copied(),
+            Domain::Hash(buckets) => Some(hash_bucket(value, *buckets) as u64),
+        }
+    }
+}
+
+// DolphinDB `hashBucket`, nulls and values it can't hash land in one extra bucket.
+fn hash_bucket(value: &ScalarImpl, buckets: u32) -> u32 {
+    let bytes = |s: &Option<String>| s.as_deref().map(|s| murmur32(s.as_bytes()));
+    let hash = match value {
+        ScalarImpl::Char(v) => v.into_inner().map(|v| v as i32 as u32),
+        ScalarImpl::Short(v) => v.into_inner().map(|v| v as i32 as u32),
+        ScalarImpl::Int(v) => v.into_inner().map(|v| v as u32),
+        ScalarImpl::Long(v) => {
+            return v
+                .into_inner()
+                .map_or(buckets, |v| (v as u64 % buckets as u64) as u32)
+        }
+        ScalarImpl::String(v) => bytes(v.as_ref()),
+        ScalarImpl::Symbol(v) => bytes(v.as_ref()),
+        ScalarImpl::Date(v) => v.into_inner().map(|_| v.ddb_rep() as u32),
+        ScalarImpl::Month(v) => v.into_inner().map(|_| v.ddb_rep() as u32),
+        ScalarImpl::Time(v) => v.into_inner().map(|_| v.ddb_rep() as u32),
+        ScalarImpl::Minute(v) => v.into_inner().map(|_| v.ddb_rep() as u32),
+        ScalarImpl::Second(v) => v.into_inner().map(|_| v.ddb_rep() as u32),
+        ScalarImpl::DateTime(v) => v.into_inner().map(|_| v.ddb_rep() as u32),
+        ScalarImpl::DateHour(v) => v.into_inner().map(|_| v.ddb_rep() as u32),
+        ScalarImpl::Timestamp(v) => {
+            return v
+                .into_inner()
+                .map_or(buckets, |_| (v.ddb_rep() as u64 % buckets as u64) as u32)
+        }
+        ScalarImpl::NanoTime(v) => {
+            return v
+                .into_inner()
+                .map_or(buckets, |_| (v.ddb_rep() as u64 % buckets as u64) as u32)
+        }
+        ScalarImpl::NanoTimestamp(v) => {
+            return v
+                .into_inner()
+                .map_or(buckets, |_| (v.ddb_rep() as u64 % buckets as u64) as u32)
+        }
+        _ => None,
+    };
+
+    hash.map_or(buckets, |h| h % buckets)
+}
+
+// MurmurHash2 with seed 0, as DolphinDB hashes strings.
+fn murmur32(data: &[u8]) -> u32 {
+    const M: u32 = 0x5bd1e995;
+    let mut h = data.len() as u32;
+
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(M);
+        k ^= k >> 24;
+        k = k.wrapping_mul(M);
+        h = h.wrapping_mul(M) ^ k;
+    }
+
+    let rest = chunks.remainder();
+    if !rest.is_empty() {
+        for (i, b) in rest.iter().enumerate() {
+            h ^= (*b as u32) << (8 * i);
+        }
+        h = h.wrapping_mul(M);
+    }
+
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^ (h >> 15)
+}
+
+fn int(s: &ScalarImpl) -> Result<i32> {
+    match s {
+        ScalarImpl::Int(i) => i
+            .into_inner()
+            .ok_or_else(|| Error::BadResponse("null partition scheme".into())),
+        _ => Err(Error::BadResponse(format!(
+            "unexpected partition scheme {}",
+            s
+        ))),
+    }
+}
+
+fn scalars(v: &VectorImpl) -> Vec<ScalarImpl> {
+    (0..v.len())
+        .filter_map(|i| match v.get(i) {
+            Some(ConstantImpl::Scalar(s)) => Some(s),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,186 @@
+use std::collections::VecDeque;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    codec::{decode, encode},
+    error::{Error, Result},
+    types::{ConstantImpl, Table},
+    Endian,
+};
+
+const EXTENSION: &str = "batch";
+
+/// Options of the local spool of a [`TableWriter`](super::TableWriter).
+#[derive(Debug, Clone)]
+pub struct SpoolOptions {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+impl SpoolOptions {
+    /// Spools batches into `dir`, which is created if needed. The size limit is 1 GiB by
+    /// default.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            max_bytes: 1 << 30,
+        }
+    }
+
+    /// Total size of the batches the spool holds at most.
+    pub fn with_max_bytes(&mut self, max_bytes: u64) -> &mut Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+}
+
+/// Counters of the local spool of a [`TableWriter`](super::TableWriter).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpoolMetrics {
+    /// Batches waiting in the spool, including ones left by a previous process.
+    pub pending_batches: u64,
+    /// Size of the batches waiting in the spool.
+    pub pending_bytes: u64,
+    /// Batches written into the spool.
+    pub spooled_batches: u64,
+    /// Batches the server acknowledged and the spool deleted.
+    pub acknowledged_batches: u64,
+    /// Attempts to send a batch that failed.
+    pub failed_sends: u64,
+}
+
+// a spool file and its size.
+#[derive(Debug)]
+struct Batch {
+    path: PathBuf,
+    size: u64,
+}
+
+/// Batches persisted in the DolphinDB binary format, one file per batch named by its sequence
+/// number so that they replay in order.
+#[derive(Debug)]
+pub(crate) struct Spool {
+    dir: PathBuf,
+    max_bytes: u64,
+    pending: VecDeque<Batch>,
+    next: u64,
+    metrics: SpoolMetrics,
+}
+
+impl Spool {
+    /// Opens the spool in `options.dir`, picking up the batches left there.
+    pub(crate) fn open(options: &SpoolOptions) -> Result<Self> {
+        fs::create_dir_all(&options.dir)?;
+
+        let mut batches = Vec::new();
+        for entry in fs::read_dir(&options.dir)? {
+            let path = entry?.path();
+            let seq = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(|s| s.parse::<u64>().ok());
+            match (seq, path.extension().and_then(|e| e.to_str())) {
+                (Some(seq), Some(EXTENSION)) => {
+                    let size = fs::metadata(&path)?.len();
+                    batches.push((seq, Batch { path, size }));
+                }
+                // a batch whose write was interrupted, it was never acknowledged as spooled.
+                (Some(_), Some("tmp")) => fs::remove_file(&path)?,
+                _ => {}
+            }
+        }
+        batches.sort_by_key(|(seq, _)| *seq);
+
+        let next = batches.last().map_or(0, |(seq, _)| seq + 1);
+        let pending: VecDeque<_> = batches.into_iter().map(|(_, b)| b).collect();
+        let metrics = SpoolMetrics {
+            pending_batches: pending.len() as u64,
+            pending_bytes: pending.iter().map(|b| b.size).sum(),
+            ..Default::default()
+        };
+
+        Ok(Self {
+            dir: options.dir.clone(),
+            max_bytes: options.max_bytes,
+            pending,
+            next,
+            metrics,
+        })
+    }
+
+    /// Persists `table` behind the pending batches.
+    pub(crate) fn push(&mut self, table: &Table) -> Result<()> {
+        let bytes = encode(&ConstantImpl::Table(table.clone()), Endian::Little)?;
+        let size = bytes.len() as u64;
+        if self.metrics.pending_bytes + size > self.max_bytes {
+            return Err(Error::ConstraintsViolated(format!(
+                "spool {} is full, {} of {} bytes used",
+                self.dir.display(),
+                self.metrics.pending_bytes,
+                self.max_bytes
+            )));
+        }
+
+        // written aside and renamed, so that a batch file is always complete.
+        let tmp = self.dir.join(format!("{:020}.tmp", self.next));
+        let path = self.dir.join(format!("{:020}.{}", self.next, EXTENSION));
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(&bytes)?;
+        file.sync_all()?;
+        fs::rename(&tmp, &path)?;
+        // the rename itself is durable once the directory is synced.
+        sync_dir(&self.dir)?;
+
+        self.next += 1;
+        self.pending.push_back(Batch { path, size });
+        self.metrics.pending_batches += 1;
+        self.metrics.pending_bytes += size;
+        self.metrics.spooled_batches += 1;
+        Ok(())
+    }
+
+    /// Reads the oldest pending batch.
+    pub(crate) fn front(&self) -> Result<Option<ConstantImpl>> {
+        match self.pending.front() {
+            Some(batch) => Ok(Some(decode(&fs::read(&batch.path)?, Endian::Little)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Deletes the oldest pending batch once the server acknowledged it.
+    pub(crate) fn pop(&mut self) -> Result<()> {
+        if let Some(batch) = self.pending.pop_front() {
+            fs::remove_file(&batch.path)?;
+            self.metrics.pending_batches -= 1;
+            self.metrics.pending_bytes -= batch.size;
+            self.metrics.acknowledged_batches += 1;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn failed(&mut self) {
+        self.metrics.failed_sends += 1;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub(crate) fn metrics(&self) -> SpoolMetrics {
+        self.metrics.clone()
+    }
+}
+
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> Result<()> {
+    fs::File::open(dir)?.sync_all()?;
+    Ok(())
+}
+
+// directories can't be opened as files elsewhere, renames are left to the file system.
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> Result<()> {
+    Ok(())
+}
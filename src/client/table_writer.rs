@@ -1,14 +1,102 @@
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{ready, Context, Poll};
 use std::vec;
 
-use tokio::{runtime::Handle, task::block_in_place};
+use futures::Sink;
+use tokio::{
+    runtime::{Handle, RuntimeFlavor},
+    task::block_in_place,
+};
 
-use crate::{error::Error, types::*};
+use crate::{
+    error::{Error, Result},
+    types::*,
+};
 
+use super::spool::{Spool, SpoolMetrics, SpoolOptions};
 use super::Client;
 
+/// The table a [`TableWriter`] inserts into.
+///
+/// A `&str` converts into a shared in-memory table and a `(db_path, table_name)` pair into a
+/// table of a DFS database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WriteTarget {
+    /// A shared in-memory table.
+    Shared(String),
+    /// A table of a database, loaded with `loadTable`.
+    Dfs { db_path: String, table_name: String },
+}
+
+impl WriteTarget {
+    /// Returns the name of the table.
+    pub fn table_name(&self) -> &str {
+        match self {
+            WriteTarget::Shared(name) => name,
+            WriteTarget::Dfs { table_name, .. } => table_name,
+        }
+    }
+
+    // script evaluating to the table.
+    pub(super) fn expression(&self) -> String {
+        match self {
+            WriteTarget::Shared(name) => name.clone(),
+            WriteTarget::Dfs {
+                db_path,
+                table_name,
+            } => format!("loadTable(\"{}\", \"{}\")", db_path, table_name),
+        }
+    }
+}
+
+impl From<&str> for WriteTarget {
+    fn from(value: &str) -> Self {
+        WriteTarget::Shared(value.to_string())
+    }
+}
+
+impl From<String> for WriteTarget {
+    fn from(value: String) -> Self {
+        WriteTarget::Shared(value)
+    }
+}
+
+impl From<(&str, &str)> for WriteTarget {
+    fn from((db_path, table_name): (&str, &str)) -> Self {
+        WriteTarget::Dfs {
+            db_path: db_path.to_string(),
+            table_name: table_name.to_string(),
+        }
+    }
+}
+
+/// Counters of a [`TableWriter`], returned by [`TableWriter::close`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WriteSummary {
+    /// Rows passed to the writer, accepted or not.
+    pub rows: u64,
+    /// Rows the server acknowledged.
+    pub inserted_rows: u64,
+    /// Batches the server acknowledged.
+    pub batches: u64,
+}
+
+// opens a new connection for a writer whose connection broke.
+type Connect =
+    Box<dyn Fn() -> Pin<Box<dyn Future<Output = Result<Client>> + Send + Sync>> + Send + Sync>;
+
+// an insert running on its own, it owns the sender until it completes.
+type Inflight = Pin<Box<dyn Future<Output = (Sender, Result<Option<ConstantImpl>>)> + Send + Sync>>;
+
 /// This is a simple buffer for tableInsert
 pub struct TableWriter {
-    client: Client,
+    // None while an insert started by the `Sink` implementation is in flight.
+    sender: Option<Sender>,
+    inflight: Option<Inflight>,
+    // shared with the sender, so that it is reachable while an insert is in flight.
+    spool: Option<Arc<Mutex<Spool>>>,
     table_name: String,
     script: String,
     columns: Vec<VectorImpl>,
@@ -17,16 +105,34 @@ pub struct TableWriter {
     buffer: Vec<VectorImpl>,
     size: u32,
     batch_size: u32,
+    // rows passed to append_row so far, accepted or not.
+    rows: u64,
+    // values of the columns append_map is not given.
+    defaults: Vec<PrimitiveType>,
+}
+
+// the part of a writer that talks to the server.
+struct Sender {
+    client: Client,
+    connect: Option<Connect>,
+    spool: Option<Arc<Mutex<Spool>>>,
+    inserted_rows: u64,
+    batches: u64,
 }
 
 /// TableWriter is a simple buffer for tableInsert.
 ///
-/// Partitioned tables are not supported.
+/// Both shared in-memory tables and partitioned tables of DFS databases are supported, see
+/// [`WriteTarget`].
+///
+/// The writer is also a [`Sink`] of rows and of tables, so a stream can be forwarded into it.
+/// Call [`close`](Self::close) when done to insert the rows left and see the errors.
 impl TableWriter {
     /// Creates a `TableWriter`.
     ///
     /// This function will try to get the table's schema through client,
     /// so the caller needs to make sure table is created before calling this function.
+    /// `table` is either the name of a shared table or a `(db_path, table_name)` pair.
     ///
     /// # Examples
     ///
@@ -37,200 +143,948 @@ impl TableWriter {
     ///     let mut builder = ClientBuilder::new("127.0.0.1:8848");
     ///     builder.with_auth(("admin", "123456"));
     ///     let mut client = builder.connect().await.unwrap();
-    ///     let mut table = TableWriter::new(client, "test_table", 512);
+    ///     let mut table = TableWriter::new(client, "test_table", 512).await.unwrap();
+    /// }
+    /// ```
+    ///
+    /// Writes into a table of a DFS database:
+    ///
+    /// ```no_run
+    /// use dolphindb::client::{ClientBuilder, TableWriter};
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut builder = ClientBuilder::new("127.0.0.1:8848");
+    ///     builder.with_auth(("admin", "123456"));
+    ///     let mut client = builder.connect().await.unwrap();
+    ///     let mut table = TableWriter::new(client, ("dfs://test_db", "pt"), 512)
+    ///         .await
+    ///         .unwrap();
     /// }
     /// ```
-    pub async fn new(mut client: Client, table_name: &str, batch_size: u32) -> Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `batch_size` is zero, the schema can't be fetched or is malformed, or a
+    /// column type is not supported.
+    pub async fn new(
+        mut client: Client,
+        table: impl Into<WriteTarget>,
+        batch_size: u32,
+    ) -> Result<Self> {
+        let table = table.into();
+        let expression = table.expression();
         if batch_size == 0 {
-            panic!("TableWriter: batch_size must be positive.");
-        }
-        let mut columns = vec![];
-        let mut column_types = vec![];
-        let mut column_names: Vec<String> = vec![];
-        let schema = client
-            .run_script(format!("schema({}).colDefs.typeInt", table_name).as_str())
-            .await;
-        if schema.is_err() {
-            panic!("Failed to get schema for table {}", table_name);
-        }
-        if let VectorImpl::Int(columns_ddb) = schema.unwrap().unwrap().as_vector().unwrap() {
-            for i in columns_ddb.iter() {
-                let data_type = DataType::try_from(i.0.unwrap() as u8).unwrap();
-                column_types.push(data_type);
-                let vec = match data_type {
-                    DataType::Bool => BoolVector::new().into(),
-                    DataType::Char => CharVector::new().into(),
-                    DataType::Short => ShortVector::new().into(),
-                    DataType::Int
-                    | DataType::Date
-                    | DataType::Month
-                    | DataType::Time
-                    | DataType::Minute
-                    | DataType::Second
-                    | DataType::DateTime
-                    | DataType::DateHour => IntVector::new().into(),
-                    DataType::Long
-                    | DataType::Timestamp
-                    | DataType::NanoTime
-                    | DataType::NanoTimestamp => LongVector::new().into(),
-                    DataType::Float => FloatVector::new().into(),
-                    DataType::Double => DoubleVector::new().into(),
-                    DataType::String | DataType::Symbol => StringVector::new().into(),
-                    DataType::Decimal32 => Decimal32Vector::new().into(),
-                    DataType::Decimal64 => Decimal64Vector::new().into(),
-                    DataType::Decimal128 => Decimal128Vector::new().into(),
-                    DataType::CharArray => CharArrayVector::new().into(),
-                    DataType::ShortArray => ShortArrayVector::new().into(),
-                    DataType::IntArray => IntArrayVector::new().into(),
-                    DataType::LongArray => LongArrayVector::new().into(),
-                    DataType::FloatArray => FloatArrayVector::new().into(),
-                    DataType::DoubleArray => DoubleArrayVector::new().into(),
-                    _ => unimplemented!(),
-                };
-                columns.push(vec);
-            }
-        }
-        if let VectorImpl::String(column_names_ddb) = client
-            .run_script(format!("schema({}).colDefs.name", table_name).as_str())
-            .await
-            .unwrap()
-            .unwrap()
-            .as_vector()
-            .unwrap()
-        {
-            column_names = column_names_ddb
-                .iter()
-                .map(|b| b.to_string())
-                .collect::<Vec<_>>();
+            return Err(Error::ConstraintsViolated(
+                "TableWriter: batch_size must be positive".into(),
+            ));
+        }
+        let column_types = schema_types(&mut client, &expression).await?;
+        let mut columns = column_types
+            .iter()
+            .map(|t| buffer_column(*t))
+            .collect::<Result<Vec<_>>>()?;
+        let is_decimal = |t: &DataType| {
+            matches!(
+                t,
+                DataType::Decimal32
+                    | DataType::Decimal64
+                    | DataType::Decimal128
+                    | DataType::Decimal32Array
+                    | DataType::Decimal64Array
+                    | DataType::Decimal128Array
+            )
+        };
+        if column_types.iter().any(is_decimal) {
+            // colDefs.extra holds the scale of decimal and decimal array columns.
+            if let VectorImpl::Int(scales) = schema(&mut client, &expression, "extra").await? {
+                for (i, scale) in scales.iter().enumerate() {
+                    let scale = scale.into_inner().and_then(|s| u32::try_from(s).ok());
+                    if let (true, Some(scale)) = (is_decimal(&column_types[i]), scale) {
+                        columns[i].set_decimal_scale(scale)?;
+                    }
+                }
+            }
+        }
+        let column_names = schema_names(&mut client, &expression).await?;
+        if column_names.len() != column_types.len() {
+            return Err(Error::BadResponse(format!(
+                "{} column names for {} column types",
+                column_names.len(),
+                column_types.len()
+            )));
         }
         let buffer = columns.clone();
-        Self {
-            client,
-            table_name: table_name.to_string(),
-            script: format!("tableInsert{{'{}'}}", table_name),
+        let defaults = vec![PrimitiveType::None; column_names.len()];
+        Ok(Self {
+            sender: Some(Sender {
+                client,
+                connect: None,
+                spool: None,
+                inserted_rows: 0,
+                batches: 0,
+            }),
+            inflight: None,
+            spool: None,
+            script: match &table {
+                WriteTarget::Shared(name) => format!("tableInsert{{'{}'}}", name),
+                WriteTarget::Dfs { .. } => format!("tableInsert{{{}}}", expression),
+            },
+            table_name: table.table_name().to_string(),
             columns,
             column_types,
             column_names,
             buffer,
             size: 0,
             batch_size,
-        }
+            rows: 0,
+            defaults,
+        })
+    }
+    /// Inserts batches by calling the server function `function` with the batch as its only
+    /// argument instead of `tableInsert`, e.g. a function view that writes into the table.
+    pub fn with_insert_function(&mut self, function: &str) -> &mut Self {
+        self.script = function.to_string();
+        self
+    }
+
+    /// Sets the value [`append_map`](Self::append_map) uses for `column` when a row doesn't
+    /// have it, null by default.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table has no such column.
+    pub fn with_default(&mut self, column: &str, value: PrimitiveType) -> Result<&mut Self> {
+        let i = self
+            .column_names
+            .iter()
+            .position(|c| c == column)
+            .ok_or_else(|| {
+                Error::ConstraintsViolated(format!(
+                    "table {} has no column {}",
+                    self.table_name, column
+                ))
+            })?;
+        self.defaults[i] = value;
+        Ok(self)
     }
+
     /// Append one row to the TableWriter's buffer.
     ///
     /// When buffer is full, this function will run tableInsert and return the result of the script.
     /// When buffer is not full, None is returned.
     ///
     /// This interface is NOT thread-safe.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRow`] if a value can't be stored in its column, the buffer is left
+    /// as it was before the call. Returns [`Error::FlushFailed`] if the buffer was full and
+    /// inserting it failed, see [`flush`](Self::flush).
     pub async fn append_row(
         &mut self,
         row: &mut Vec<PrimitiveType>,
     ) -> Result<Option<ConstantImpl>, Error> {
+        self.buffer_row(row)?;
+        if self.size >= self.batch_size {
+            return self.flush().await;
+        }
+        Ok(None)
+    }
+
+    // validates `row` and pushes it into the buffer.
+    fn buffer_row(&mut self, row: &mut Vec<PrimitiveType>) -> Result<()> {
+        let index = self.rows;
+        self.rows += 1;
         if self.buffer.len() != row.len() {
-            panic!(
-                "Table {} has {} columns, but {} provided.",
+            return Err(Error::ConstraintsViolated(format!(
+                "row {}: table {} has {} columns, but {} provided",
+                index,
                 self.table_name,
                 self.buffer.len(),
                 row.len()
-            );
-        }
-        let mut res: Result<(), Error> = Ok(());
-        for i in (0..self.buffer.len()).rev() {
-            let data = row.pop().unwrap();
-            match &data {
-                PrimitiveType::NaiveDate(d) => match self.column_types[i] {
-                    DataType::Date => {
-                        _ = self.buffer[i].push_primitive_type(Date::new(*d).ddb_rep().into())
-                    }
-                    DataType::Month => {
-                        _ = self.buffer[i].push_primitive_type(Month::new(*d).ddb_rep().into())
-                    }
-                    t => {
-                        res = Err(Error::InvalidConvert {
-                            from: "NaiveDate".to_string(),
-                            to: t.to_string(),
-                        })
-                    }
-                },
-                PrimitiveType::NaiveTime(t) => match self.column_types[i] {
-                    DataType::Time => {
-                        _ = self.buffer[i].push_primitive_type(Time::new(*t).ddb_rep().into())
-                    }
-                    DataType::Minute => {
-                        _ = self.buffer[i].push_primitive_type(Minute::new(*t).ddb_rep().into())
-                    }
-                    DataType::Second => {
-                        _ = self.buffer[i].push_primitive_type(Second::new(*t).ddb_rep().into())
-                    }
-                    DataType::NanoTime => {
-                        _ = self.buffer[i].push_primitive_type(NanoTime::new(*t).ddb_rep().into())
-                    }
-                    t => {
-                        res = Err(Error::InvalidConvert {
-                            from: "NaiveTime".to_string(),
-                            to: t.to_string(),
-                        })
-                    }
-                },
-                PrimitiveType::NaiveDateTime(dt) => match self.column_types[i] {
-                    DataType::Timestamp => {
-                        _ = self.buffer[i].push_primitive_type(Timestamp::new(*dt).ddb_rep().into())
-                    }
-                    DataType::NanoTimestamp => {
-                        _ = self.buffer[i]
-                            .push_primitive_type(NanoTimestamp::new(*dt).ddb_rep().into())
-                    }
-                    DataType::DateTime => {
-                        _ = self.buffer[i].push_primitive_type(DateTime::new(*dt).ddb_rep().into())
-                    }
-                    DataType::DateHour => {
-                        _ = self.buffer[i].push_primitive_type(DateHour::new(*dt).ddb_rep().into())
+            )));
+        }
+        for (i, data) in std::mem::take(row).into_iter().enumerate() {
+            if let Err(err) = self.push(i, data) {
+                // drop the values already pushed for this row.
+                let size = self.size as usize;
+                for column in self.buffer.iter_mut().filter(|c| c.len() > size) {
+                    column.resize(size);
+                }
+                return Err(Error::InvalidRow {
+                    row: index,
+                    column: self.column_names[i].clone(),
+                    source: Box::new(err),
+                });
+            }
+        }
+        self.size += 1;
+        Ok(())
+    }
+
+    /// Append one row given as `(column name, value)` pairs, e.g. a `HashMap<&str, PrimitiveType>`,
+    /// to the TableWriter's buffer. Columns the row doesn't have take their default, see
+    /// [`with_default`](Self::with_default).
+    ///
+    /// Otherwise the same as [`append_row`](Self::append_row).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRow`] if a column is unknown or given twice.
+    pub async fn append_map<'a>(
+        &mut self,
+        values: impl IntoIterator<Item = (&'a str, PrimitiveType)>,
+    ) -> Result<Option<ConstantImpl>> {
+        let mut row: Vec<Option<PrimitiveType>> = vec![None; self.column_names.len()];
+        for (name, value) in values {
+            let reject = |reason: String| Error::InvalidRow {
+                row: self.rows,
+                column: name.to_string(),
+                source: Box::new(Error::ConstraintsViolated(reason)),
+            };
+            let Some(i) = self.column_names.iter().position(|c| c == name) else {
+                let err = reject(format!("table {} has no column {}", self.table_name, name));
+                self.rows += 1;
+                return Err(err);
+            };
+            if row[i].replace(value).is_some() {
+                let err = reject(format!("column {} is given twice", name));
+                self.rows += 1;
+                return Err(err);
+            }
+        }
+
+        let mut row = row
+            .into_iter()
+            .zip(&self.defaults)
+            .map(|(value, default)| value.unwrap_or_else(|| default.clone()))
+            .collect();
+        self.append_row(&mut row).await
+    }
+
+    /// Appends columns in schema order to the TableWriter's buffer, inserting a batch whenever
+    /// the buffer is full.
+    ///
+    /// Each column must have the type of its table column, string and symbol columns are
+    /// interchangeable. Returns the results of the tableInsert runs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error without buffering anything if the columns don't match the schema.
+    /// Returns [`Error::FlushFailed`] if inserting a batch failed, holding its rows and the
+    /// ones not buffered yet, or [`Error::Spooled`] if they are kept in the spool.
+    pub async fn append_columns(&mut self, columns: Vec<VectorImpl>) -> Result<Vec<ConstantImpl>> {
+        let columns = self.fit_columns(columns)?;
+        let len = columns.first().map_or(0, |c| c.len());
+
+        let mut results = Vec::new();
+        let mut offset = 0;
+        while offset < len {
+            let n = (len - offset).min(self.batch_size.saturating_sub(self.size) as usize);
+            let indices = (offset..offset + n).collect::<Vec<_>>();
+            for (buffer, column) in self.buffer.iter_mut().zip(&columns) {
+                if n == len {
+                    buffer.extend_from(column)?;
+                } else {
+                    buffer.extend_from(&column.take(&indices))?;
+                }
+            }
+            self.size += n as u32;
+            offset += n;
+
+            if self.size >= self.batch_size {
+                match self.flush().await {
+                    Ok(result) => results.extend(result),
+                    Err(Error::FlushFailed { unsent, source }) => {
+                        // the rows after the failed batch were never buffered.
+                        let rest = self.rows_of(&columns, offset..len)?;
+                        return Err(Error::FlushFailed {
+                            unsent: Box::new(unsent.concat(&[rest])?),
+                            source,
+                        });
                     }
-                    t => {
-                        res = Err(Error::InvalidConvert {
-                            from: "NaiveDateTime".to_string(),
-                            to: t.to_string(),
-                        })
+                    Err(Error::Spooled { source, .. }) => {
+                        // keep the rest in the spool as well, without waiting for the server.
+                        let batch_size = self.batch_size as usize;
+                        while offset < len {
+                            let end = len.min(offset + batch_size);
+                            let rest = self.rows_of(&columns, offset..end)?;
+                            let spool = self.spool.as_ref().expect("spooled without a spool");
+                            if let Err(err) = spool.lock().unwrap().push(&rest) {
+                                let rest = self.rows_of(&columns, offset..len)?;
+                                return Err(Error::FlushFailed {
+                                    unsent: Box::new(rest),
+                                    source: Box::new(err),
+                                });
+                            }
+                            offset = end;
+                        }
+                        return Err(Error::Spooled {
+                            batches: self.spooled(),
+                            source,
+                        });
                     }
-                },
-                _ => res = self.buffer[i].push_primitive_type(data),
-            };
-            if let Err(err) = res {
-                panic!(
-                    "Failed to insert into column `{}`: {}.",
-                    self.column_names[i].clone(),
-                    err.to_string()
-                );
+                    Err(e) => return Err(e),
+                }
             }
         }
-        self.size += 1;
-        if self.size == self.batch_size {
-            return self.flush().await;
+        Ok(results)
+    }
+
+    // validates `columns` against the schema and converts them to the representation of the
+    // buffer.
+    fn fit_columns(&mut self, columns: Vec<VectorImpl>) -> Result<Vec<VectorImpl>> {
+        if self.buffer.len() != columns.len() {
+            return Err(Error::ConstraintsViolated(format!(
+                "table {} has {} columns, but {} provided",
+                self.table_name,
+                self.buffer.len(),
+                columns.len()
+            )));
         }
-        Ok(None)
+        let len = columns.first().map_or(0, |c| c.len());
+        if columns.iter().any(|c| c.len() != len) {
+            return Err(Error::ConstraintsViolated(
+                "columns must have the same length".into(),
+            ));
+        }
+        let columns = columns
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| self.fit(i, c))
+            .collect::<Result<Vec<_>>>()?;
+        self.rows += len as u64;
+        Ok(columns)
+    }
+
+    // a table of the given rows of `columns`.
+    fn rows_of(&self, columns: &[VectorImpl], rows: std::ops::Range<usize>) -> Result<Table> {
+        let indices = rows.collect::<Vec<_>>();
+        let mut builder = TableBuilder::new();
+        builder.with_contents(
+            columns.iter().map(|c| c.take(&indices)).collect(),
+            self.column_names.clone(),
+        );
+        builder.build()
+    }
+
+    /// Appends the rows of `table`, whose columns are in schema order, see
+    /// [`append_columns`](Self::append_columns).
+    pub async fn append_table(&mut self, table: &Table) -> Result<Vec<ConstantImpl>> {
+        self.append_columns(table.columns().clone()).await
+    }
+
+    // converts a column to the representation of the buffer.
+    fn fit(&self, i: usize, column: VectorImpl) -> Result<VectorImpl> {
+        let expect = self.column_types[i];
+        let actual = column.data_type();
+        let strings = |t| matches!(t, DataType::String | DataType::Symbol);
+        if actual != expect && !(strings(actual) && strings(expect)) {
+            return Err(Error::InvalidData {
+                expect: format!("{} column `{}`", expect, self.column_names[i]),
+                actual: actual.to_string(),
+            });
+        }
+
+        macro_rules! raw {
+            ($v:expr, $raw:ident) => {
+                $v.iter()
+                    .map(|s| {
+                        if s.is_null() {
+                            $raw::new($raw::NULL_VALUE)
+                        } else {
+                            $raw::new(s.ddb_rep())
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .into()
+            };
+        }
+
+        Ok(match column {
+            VectorImpl::Date(v) => VectorImpl::Int(raw!(v, Int)),
+            VectorImpl::Month(v) => VectorImpl::Int(raw!(v, Int)),
+            VectorImpl::Time(v) => VectorImpl::Int(raw!(v, Int)),
+            VectorImpl::Minute(v) => VectorImpl::Int(raw!(v, Int)),
+            VectorImpl::Second(v) => VectorImpl::Int(raw!(v, Int)),
+            VectorImpl::DateTime(v) => VectorImpl::Int(raw!(v, Int)),
+            VectorImpl::DateHour(v) => VectorImpl::Int(raw!(v, Int)),
+            VectorImpl::Timestamp(v) => VectorImpl::Long(raw!(v, Long)),
+            VectorImpl::NanoTime(v) => VectorImpl::Long(raw!(v, Long)),
+            VectorImpl::NanoTimestamp(v) => VectorImpl::Long(raw!(v, Long)),
+            VectorImpl::Symbol(v) => VectorImpl::String(
+                v.iter()
+                    .map(|s| DolphinString(s.0.clone()))
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+            mut column => {
+                // decimals take the scale of the buffer.
+                if let Some(scale) = self.columns[i].decimal_scale() {
+                    column.set_decimal_scale(scale)?;
+                }
+                column
+            }
+        })
+    }
+
+    fn push(&mut self, i: usize, data: PrimitiveType) -> Result<()> {
+        push_value(&mut self.buffer[i], self.column_types[i], data)
     }
+
     /// Manually flush the buffer.
+    ///
+    /// The buffer is emptied either way. With a spool, the batch is persisted first and sent
+    /// after the batches already spooled, see [`with_spool`](Self::with_spool).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FlushFailed`] holding the rows that were not inserted, or
+    /// [`Error::Spooled`] if they are kept in the spool. An insert the [`Sink`] implementation
+    /// left in flight is completed first, its error is returned before anything is sent.
     pub async fn flush(&mut self) -> Result<Option<ConstantImpl>, Error> {
+        poll_fn(|cx| self.poll_inflight(cx)).await?;
+        let table = self.take_batch(self.size as usize)?;
+        let script = self.script.clone();
+        self.sender_mut()?.send(&script, table).await
+    }
+
+    /// Flushes the buffer and returns the counters of the writer.
+    ///
+    /// Prefer it over dropping the writer, which can only insert the rows left in the buffer on a
+    /// multi-threaded runtime and ignores errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last flush, see [`flush`](Self::flush).
+    pub async fn close(mut self) -> Result<WriteSummary> {
+        if self.size > 0 || self.spooled() > 0 {
+            self.flush().await?;
+        } else {
+            poll_fn(|cx| self.poll_inflight(cx)).await?;
+        }
+        let rows = self.rows;
+        let sender = self.sender_mut()?;
+        Ok(WriteSummary {
+            rows,
+            inserted_rows: sender.inserted_rows,
+            batches: sender.batches,
+        })
+    }
+
+    /// Persists every batch into the local spool described by `options` before it is sent,
+    /// deleting it once the server acknowledged it, so that rows are delivered at least once.
+    ///
+    /// Batches a previous writer left in the spool are sent in order before the new ones, on
+    /// the next flush or [`replay`](Self::replay). Use [`with_reconnect`](Self::with_reconnect)
+    /// to send them over a new connection once the server is back. A spool directory must be
+    /// used by one writer at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the spool directory can't be read or created.
+    pub fn with_spool(&mut self, options: &SpoolOptions) -> Result<&mut Self> {
+        let spool = Arc::new(Mutex::new(Spool::open(options)?));
+        self.sender_mut()?.spool = Some(spool.clone());
+        self.spool = Some(spool);
+        Ok(self)
+    }
+
+    /// Opens a new connection with `connect` when a batch can't be sent because the connection
+    /// broke, and sends the batch again through it. With a spool, the batches spooled while the
+    /// server was unreachable are sent once `connect` succeeds.
+    ///
+    /// A batch may be inserted twice if the connection broke after the server received it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dolphindb::client::{ClientBuilder, TableWriter};
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let connect = || async {
+    ///         let mut builder = ClientBuilder::new("127.0.0.1:8848");
+    ///         builder.with_auth(("admin", "123456"));
+    ///         builder.connect().await
+    ///     };
+    ///     let client = connect().await.unwrap();
+    ///     let mut writer = TableWriter::new(client, "test_table", 512).await.unwrap();
+    ///     writer.with_reconnect(connect).unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an insert started by the [`Sink`] implementation is in flight.
+    pub fn with_reconnect<F, Fut>(&mut self, connect: F) -> Result<&mut Self>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Client>> + Send + Sync + 'static,
+    {
+        self.sender_mut()?.connect = Some(Box::new(move || Box::pin(connect())));
+        Ok(self)
+    }
+
+    /// Sends the spooled batches in order and returns the result of the last one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Spooled`] if sending a batch fails, it stays in the spool with the
+    /// ones after it.
+    pub async fn replay(&mut self) -> Result<Option<ConstantImpl>> {
+        poll_fn(|cx| self.poll_inflight(cx)).await?;
+        let script = self.script.clone();
+        self.sender_mut()?.replay(&script).await
+    }
+
+    /// Returns the counters of the spool, if any.
+    pub fn spool_metrics(&self) -> Option<SpoolMetrics> {
+        self.spool.as_ref().map(|s| s.lock().unwrap().metrics())
+    }
+
+    // batches waiting in the spool.
+    fn spooled(&self) -> usize {
+        self.spool.as_ref().map_or(0, |s| s.lock().unwrap().len())
+    }
+
+    fn sender_mut(&mut self) -> Result<&mut Sender> {
+        self.sender.as_mut().ok_or_else(|| {
+            Error::ConstraintsViolated(format!(
+                "TableWriter: an insert into {} is in flight",
+                self.table_name
+            ))
+        })
+    }
+
+    // takes the first `rows` rows out of the buffer.
+    fn take_batch(&mut self, rows: usize) -> Result<Table> {
+        let size = self.size as usize;
+        let content = if rows == size {
+            std::mem::replace(&mut self.buffer, self.columns.clone())
+        } else {
+            let (head, rest): (Vec<_>, Vec<_>) = (0..size).partition(|i| *i < rows);
+            let content = self.buffer.iter().map(|c| c.take(&head)).collect();
+            self.buffer = self.buffer.iter().map(|c| c.take(&rest)).collect();
+            content
+        };
+        self.size = (size - rows) as u32;
+
         let mut builder = TableBuilder::new();
-        let content = std::mem::take(&mut self.buffer);
-        self.buffer = self.columns.clone();
-        self.size = 0;
         builder.with_contents(content, self.column_names.clone());
-        self.client
-            .run_function(self.script.as_str(), &[builder.build().unwrap().into()])
-            .await
+        builder.build()
+    }
+
+    // starts inserting a batch of at most `batch_size` rows without waiting for it. The batch is
+    // in the spool, if any, before this returns.
+    fn start_batch(&mut self) -> Result<()> {
+        let table = self.take_batch(self.size.min(self.batch_size) as usize)?;
+        let mut sender = self.sender.take().expect("no insert in flight");
+        let table = match sender.persist(table) {
+            Ok(table) => table,
+            Err(err) => {
+                self.sender = Some(sender);
+                return Err(err);
+            }
+        };
+        let script = self.script.clone();
+        self.inflight = Some(Box::pin(async move {
+            let result = match table {
+                Some(table) => sender.insert(&script, table).await,
+                None => sender.replay(&script).await,
+            };
+            (sender, result)
+        }));
+        Ok(())
+    }
+
+    // drives the insert in flight, if any, to completion.
+    fn poll_inflight(&mut self, cx: &mut Context<'_>) -> Poll<Result<Option<ConstantImpl>>> {
+        let Some(inflight) = &mut self.inflight else {
+            return Poll::Ready(Ok(None));
+        };
+        let (sender, result) = ready!(inflight.as_mut().poll(cx));
+        self.inflight = None;
+        self.sender = Some(sender);
+        Poll::Ready(result)
+    }
+
+    // Sink::poll_ready of both items, waits while a full batch can't be sent yet.
+    fn poll_buffer(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        loop {
+            match self.poll_inflight(cx) {
+                Poll::Ready(result) => {
+                    result?;
+                }
+                Poll::Pending if self.size < self.batch_size => return Poll::Ready(Ok(())),
+                Poll::Pending => return Poll::Pending,
+            }
+            if self.size < self.batch_size {
+                return Poll::Ready(Ok(()));
+            }
+            self.start_batch()?;
+        }
+    }
+
+    // Sink::poll_flush of both items, sends the buffer and the spooled batches.
+    fn poll_drain(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        loop {
+            ready!(self.poll_inflight(cx))?;
+            if self.size == 0 && self.spooled() == 0 {
+                return Poll::Ready(Ok(()));
+            }
+            self.start_batch()?;
+        }
     }
+
     /// Returns the number of rows in the buffer.
     pub fn size(&self) -> usize {
         self.buffer[0].len()
     }
+
+    pub(crate) fn column_names(&self) -> &[String] {
+        &self.column_names
+    }
+
+    pub(crate) fn column_types(&self) -> &[DataType] {
+        &self.column_types
+    }
+}
+
+// an empty buffer column for the table column type `data_type`, temporal values are buffered as
+// the elapsed units DolphinDB stores.
+pub(super) fn buffer_column(data_type: DataType) -> Result<VectorImpl> {
+    let column = match data_type {
+        DataType::Bool => BoolVector::new().into(),
+        DataType::Char => CharVector::new().into(),
+        DataType::Short => ShortVector::new().into(),
+        DataType::Int
+        | DataType::Date
+        | DataType::Month
+        | DataType::Time
+        | DataType::Minute
+        | DataType::Second
+        | DataType::DateTime
+        | DataType::DateHour => IntVector::new().into(),
+        DataType::Long | DataType::Timestamp | DataType::NanoTime | DataType::NanoTimestamp => {
+            LongVector::new().into()
+        }
+        DataType::Float => FloatVector::new().into(),
+        DataType::Double => DoubleVector::new().into(),
+        DataType::String | DataType::Symbol => StringVector::new().into(),
+        DataType::Blob => BlobVector::new().into(),
+        DataType::Int128 => Int128Vector::new().into(),
+        DataType::Uuid => UuidVector::new().into(),
+        DataType::IpAddr => IpAddrVector::new().into(),
+        DataType::Decimal32 => Decimal32Vector::new().into(),
+        DataType::Decimal64 => Decimal64Vector::new().into(),
+        DataType::Decimal128 => Decimal128Vector::new().into(),
+        DataType::BoolArray => BoolArrayVector::new().into(),
+        DataType::CharArray => CharArrayVector::new().into(),
+        DataType::ShortArray => ShortArrayVector::new().into(),
+        DataType::IntArray => IntArrayVector::new().into(),
+        DataType::LongArray => LongArrayVector::new().into(),
+        DataType::DateArray => DateArrayVector::new().into(),
+        DataType::MonthArray => MonthArrayVector::new().into(),
+        DataType::TimeArray => TimeArrayVector::new().into(),
+        DataType::MinuteArray => MinuteArrayVector::new().into(),
+        DataType::SecondArray => SecondArrayVector::new().into(),
+        DataType::DateTimeArray => DateTimeArrayVector::new().into(),
+        DataType::TimestampArray => TimestampArrayVector::new().into(),
+        DataType::NanoTimeArray => NanoTimeArrayVector::new().into(),
+        DataType::NanoTimestampArray => NanoTimestampArrayVector::new().into(),
+        DataType::FloatArray => FloatArrayVector::new().into(),
+        DataType::DoubleArray => DoubleArrayVector::new().into(),
+        DataType::DateHourArray => DateHourArrayVector::new().into(),
+        DataType::Decimal32Array => Decimal32ArrayVector::new().into(),
+        DataType::Decimal64Array => Decimal64ArrayVector::new().into(),
+        DataType::Decimal128Array => Decimal128ArrayVector::new().into(),
+        DataType::Int128Array => Int128ArrayVector::new().into(),
+        DataType::UuidArray => UuidArrayVector::new().into(),
+        DataType::IpAddrArray => IpAddrArrayVector::new().into(),
+        t => {
+            return Err(Error::Unsupported {
+                data_form: "TableWriter column".into(),
+                data_type: t.to_string(),
+            })
+        }
+    };
+    Ok(column)
+}
+
+// pushes `data` into `column` of the table column type `data_type`, converting dates and times.
+pub(super) fn push_value(
+    column: &mut VectorImpl,
+    data_type: DataType,
+    data: PrimitiveType,
+) -> Result<()> {
+    let convert_error = |from: &str, to: DataType| {
+        Err(Error::InvalidConvert {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    };
+    match &data {
+        PrimitiveType::NaiveDate(d) => match data_type {
+            DataType::Date => column.push_primitive_type(Date::new(*d).ddb_rep().into()),
+            DataType::Month => column.push_primitive_type(Month::new(*d).ddb_rep().into()),
+            t => convert_error("NaiveDate", t),
+        },
+        PrimitiveType::NaiveTime(t) => match data_type {
+            DataType::Time => column.push_primitive_type(Time::new(*t).ddb_rep().into()),
+            DataType::Minute => column.push_primitive_type(Minute::new(*t).ddb_rep().into()),
+            DataType::Second => column.push_primitive_type(Second::new(*t).ddb_rep().into()),
+            DataType::NanoTime => column.push_primitive_type(NanoTime::new(*t).ddb_rep().into()),
+            t => convert_error("NaiveTime", t),
+        },
+        PrimitiveType::NaiveDateTime(dt) => match data_type {
+            DataType::Timestamp => column.push_primitive_type(Timestamp::new(*dt).ddb_rep().into()),
+            DataType::NanoTimestamp => {
+                column.push_primitive_type(NanoTimestamp::new(*dt).ddb_rep().into())
+            }
+            DataType::DateTime => column.push_primitive_type(DateTime::new(*dt).ddb_rep().into()),
+            DataType::DateHour => column.push_primitive_type(DateHour::new(*dt).ddb_rep().into()),
+            t => convert_error("NaiveDateTime", t),
+        },
+        _ => column.push_primitive_type(data),
+    }
+}
+
+pub(super) async fn schema(client: &mut Client, table: &str, field: &str) -> Result<VectorImpl> {
+    client
+        .run_script(format!("schema({}).colDefs.{}", table, field).as_str())
+        .await?
+        .ok_or_else(|| Error::BadResponse(format!("no schema for table {}", table)))?
+        .as_vector()
+        .cloned()
+}
+
+pub(super) async fn schema_types(client: &mut Client, table: &str) -> Result<Vec<DataType>> {
+    let VectorImpl::Int(types) = schema(client, table, "typeInt").await? else {
+        return Err(Error::BadResponse("unexpected column types".into()));
+    };
+    if types.is_empty() {
+        return Err(Error::BadResponse(format!(
+            "table {} has no columns",
+            table
+        )));
+    }
+    types
+        .iter()
+        .map(|t| {
+            let code = t
+                .into_inner()
+                .ok_or_else(|| Error::BadResponse("null column type".into()))?;
+            let code = u8::try_from(code)
+                .map_err(|_| Error::BadResponse(format!("invalid column type {}", code)))?;
+            DataType::try_from(code)
+        })
+        .collect()
+}
+
+pub(super) async fn schema_names(client: &mut Client, table: &str) -> Result<Vec<String>> {
+    match schema(client, table, "name").await? {
+        VectorImpl::String(names) => Ok(names.iter().map(|n| n.to_string()).collect()),
+        _ => Err(Error::BadResponse("unexpected column names".into())),
+    }
+}
+
+impl Sender {
+    // inserts `table`, through the spool if there is one.
+    async fn send(&mut self, script: &str, table: Table) -> Result<Option<ConstantImpl>> {
+        match self.persist(table)? {
+            Some(table) => self.insert(script, table).await,
+            None => self.replay(script).await,
+        }
+    }
+
+    // persists `table` into the spool, it is given back if there is none.
+    fn persist(&self, table: Table) -> Result<Option<Table>> {
+        let Some(spool) = &self.spool else {
+            return Ok(Some(table));
+        };
+        if !table.is_empty() {
+            if let Err(err) = spool.lock().unwrap().push(&table) {
+                return Err(Error::FlushFailed {
+                    unsent: Box::new(table),
+                    source: Box::new(err),
+                });
+            }
+        }
+        Ok(None)
+    }
+
+    async fn insert(&mut self, script: &str, table: Table) -> Result<Option<ConstantImpl>> {
+        let rows = table.len() as u64;
+        let table: ConstantImpl = table.into();
+        match self.run(script, std::slice::from_ref(&table)).await {
+            Ok(result) => {
+                self.inserted_rows += rows;
+                self.batches += 1;
+                Ok(result)
+            }
+            Err(err) => {
+                let ConstantImpl::Table(unsent) = table else {
+                    unreachable!()
+                };
+                Err(Error::FlushFailed {
+                    unsent: Box::new(unsent),
+                    source: Box::new(err),
+                })
+            }
+        }
+    }
+
+    async fn replay(&mut self, script: &str) -> Result<Option<ConstantImpl>> {
+        let Some(spool) = self.spool.clone() else {
+            return Ok(None);
+        };
+
+        let mut result = None;
+        loop {
+            let batch = spool.lock().unwrap().front()?;
+            let Some(batch) = batch else {
+                return Ok(result);
+            };
+            let rows = match &batch {
+                ConstantImpl::Table(t) => t.len() as u64,
+                _ => 0,
+            };
+            let sent = self.run(script, &[batch]).await;
+            let mut spool = spool.lock().unwrap();
+            match sent {
+                Ok(r) => {
+                    spool.pop()?;
+                    self.inserted_rows += rows;
+                    self.batches += 1;
+                    result = r;
+                }
+                Err(err) => {
+                    spool.failed();
+                    return Err(Error::Spooled {
+                        batches: spool.len(),
+                        source: Box::new(err),
+                    });
+                }
+            }
+        }
+    }
+
+    // runs `script`, reconnecting once if the connection broke.
+    async fn run(&mut self, script: &str, args: &[ConstantImpl]) -> Result<Option<ConstantImpl>> {
+        match self.client.run_function(script, args).await {
+            Err(err) if disconnected(&err) && self.connect.is_some() => {
+                self.reconnect().await?;
+                self.client.run_function(script, args).await
+            }
+            result => result,
+        }
+    }
+
+    async fn reconnect(&mut self) -> Result<()> {
+        let connect = self.connect.as_ref().expect("reconnecting without connect");
+        self.client = connect().await?;
+        Ok(())
+    }
+}
+
+// errors after which the connection can't be used anymore.
+fn disconnected(err: &Error) -> bool {
+    matches!(err, Error::IO(_) | Error::UnexpectedEof)
+}
+
+/// Writes rows given in schema order, see [`TableWriter::append_row`].
+///
+/// A batch is inserted whenever the buffer is full, and the writer takes more rows while it is
+/// in flight until the buffer is full again. [`SinkExt::close`](futures::SinkExt::close) sends
+/// the rows left, see also [`TableWriter::close`].
+impl Sink<Vec<PrimitiveType>> for TableWriter {
+    type Error = Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.get_mut().poll_buffer(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, mut item: Vec<PrimitiveType>) -> Result<()> {
+        self.get_mut().buffer_row(&mut item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.get_mut().poll_drain(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.get_mut().poll_drain(cx)
+    }
+}
+
+/// Writes tables whose columns are in schema order, see [`TableWriter::append_table`].
+///
+/// A table is buffered whole, so the buffer grows past `batch_size` by up to the rows of one
+/// table. They are inserted in batches of at most `batch_size` rows before the next table is
+/// taken, feed large tables in slices to bound the memory held.
+impl Sink<Table> for TableWriter {
+    type Error = Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.get_mut().poll_buffer(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Table) -> Result<()> {
+        let this = self.get_mut();
+        let columns = this.fit_columns(item.columns().clone())?;
+        for (buffer, column) in this.buffer.iter_mut().zip(&columns) {
+            buffer.extend_from(column)?;
+        }
+        this.size += item.len() as u32;
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.get_mut().poll_drain(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.get_mut().poll_drain(cx)
+    }
 }
 
+/// Inserts the rows left in the buffer, ignoring errors, use [`TableWriter::close`] to see them.
+///
+/// With a spool the rows are persisted into it, like a batch the [`Sink`] implementation left in
+/// flight, and sent by the next writer on the spool. Otherwise the insert in flight is completed
+/// and the rows are inserted before the writer is gone on a multi-threaded runtime. On any other
+/// runtime, or outside of one, the rows are dropped, so call [`TableWriter::close`] there.
 impl Drop for TableWriter {
     fn drop(&mut self) {
+        let table = match self.size {
+            0 => None,
+            size => self.take_batch(size as usize).ok(),
+        };
+        if let Some(spool) = &self.spool {
+            if let (Some(table), Ok(mut spool)) = (table, spool.lock()) {
+                let _ = spool.push(&table);
+            }
+            return;
+        }
+        if table.is_none() && self.inflight.is_none() {
+            return;
+        }
+        let handle = match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => handle,
+            _ => return,
+        };
+        let sender = self.sender.take();
+        let inflight = self.inflight.take();
+        let script = std::mem::take(&mut self.script);
         block_in_place(|| {
-            Handle::current().block_on(async move {
-                let _ = self.flush().await;
+            handle.block_on(async move {
+                let mut sender = match inflight {
+                    Some(inflight) => inflight.await.0,
+                    None => sender.expect("no insert in flight"),
+                };
+                if let Some(table) = table {
+                    let _ = sender.send(&script, table).await;
+                }
             });
         });
     }
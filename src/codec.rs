@@ -0,0 +1,350 @@
+//! Encoding and decoding of DolphinDB objects in the binary format used on the wire.
+//!
+//! Objects can be persisted, put on message queues or sent over custom transports without a
+//! [`Client`](crate::client::Client). [`ConstantCodec`] frames a byte stream into objects for
+//! [`tokio_util::codec`].
+
+use std::{
+    cell::Cell,
+    collections::VecDeque,
+    fmt, fs,
+    future::Future,
+    io,
+    path::Path,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use bytes::{Buf, Bytes, BytesMut};
+use futures::{task::noop_waker_ref, FutureExt};
+use tokio::io::{AsyncBufRead, AsyncRead, ReadBuf};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{
+    error::{Error, Result},
+    types::ConstantImpl,
+    Deserialize, Serialize,
+};
+
+pub use crate::Endian;
+
+/// Encodes `value` with its type and form header.
+pub fn encode(value: &ConstantImpl, endian: Endian) -> Result<Bytes> {
+    let mut buffer = BytesMut::new();
+    encode_to(value, endian, &mut buffer)?;
+    Ok(buffer.freeze())
+}
+
+/// Decodes exactly one object from `bytes`.
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedEof`] if `bytes` ends in the middle of the object and
+/// [`Error::InvalidData`] if bytes remain after it.
+pub fn decode(bytes: &[u8], endian: Endian) -> Result<ConstantImpl> {
+    let mut reader = bytes;
+    // every element takes at least a byte, longer vectors can't be complete.
+    let value = with_max_len(bytes.len(), || decode_from(&mut reader, endian))?;
+
+    if !reader.is_empty() {
+        return Err(Error::InvalidData {
+            expect: "end of object".into(),
+            actual: format!("{} trailing bytes", reader.len()),
+        });
+    }
+
+    Ok(value)
+}
+
+fn encode_to(value: &ConstantImpl, endian: Endian, buffer: &mut BytesMut) -> Result<()> {
+    match endian {
+        Endian::Big => value.serialize(buffer)?,
+        Endian::Little => value.serialize_le(buffer)?,
+    };
+    Ok(())
+}
+
+// decodes one object and advances `reader` past it.
+fn decode_from(reader: &mut &[u8], endian: Endian) -> Result<ConstantImpl> {
+    let mut value = ConstantImpl::default();
+
+    // reading from a slice never pends, the future completes on its first poll.
+    async {
+        match endian {
+            Endian::Big => value.deserialize(reader).await,
+            Endian::Little => value.deserialize_le(reader).await,
+        }
+    }
+    .now_or_never()
+    .ok_or(Error::UnexpectedEof)??;
+
+    Ok(value)
+}
+
+thread_local! {
+    // bound of lengths read from the input while decoding untrusted bytes.
+    static MAX_LEN: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+// runs `f` with lengths read from the input bounded by `max_len`.
+fn with_max_len<T>(max_len: usize, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<usize>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            MAX_LEN.set(self.0);
+        }
+    }
+
+    let _restore = Restore(MAX_LEN.replace(Some(max_len)));
+    f()
+}
+
+/// Checks a length read from the input before allocating for it, lengths are unbounded outside
+/// of this module.
+pub(crate) fn check_len(len: usize) -> Result<()> {
+    match MAX_LEN.get() {
+        Some(max_len) if len > max_len => Err(Error::ConstraintsViolated(format!(
+            "length {} exceeds the limit of {}",
+            len, max_len
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn is_incomplete(error: &Error) -> bool {
+    match error {
+        Error::UnexpectedEof => true,
+        Error::IO(e) => e.kind() == std::io::ErrorKind::UnexpectedEof,
+        _ => false,
+    }
+}
+
+impl ConstantImpl {
+    /// Writes this object to `path` in the binary format of [`encode`], with nothing around it.
+    /// This is the layout of the server's `writeObject`, so the server reads the file back with
+    /// `readObject`.
+    ///
+    /// Objects are written in little endian, the byte order of DolphinDB servers on x86.
+    pub fn save_to(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, encode(self, Endian::Little)?)?;
+        Ok(())
+    }
+
+    /// Reads an object saved by [`save_to`](Self::save_to) or by the server's `writeObject`.
+    pub fn load_from(path: impl AsRef<Path>) -> Result<Self> {
+        decode(&fs::read(path)?, Endian::Little)
+    }
+}
+
+/// Frames a byte stream of consecutive DolphinDB objects, e.g. with
+/// [`FramedRead`](tokio_util::codec::FramedRead).
+///
+/// An object split across reads is decoded as its bytes arrive, without parsing them again.
+pub struct ConstantCodec {
+    endian: Endian,
+    max_size: usize,
+    decoding: Option<Decoding>,
+}
+
+impl ConstantCodec {
+    pub fn new(endian: Endian) -> Self {
+        Self {
+            endian,
+            max_size: 256 << 20,
+            decoding: None,
+        }
+    }
+
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Bytes an object may take at most, 256 MiB by default. Lengths read from the stream are
+    /// bounded by it as well, before anything is allocated for them.
+    pub fn with_max_size(&mut self, max_size: usize) -> &mut Self {
+        self.max_size = max_size;
+        self
+    }
+
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+}
+
+impl Default for ConstantCodec {
+    fn default() -> Self {
+        Self::new(Endian::default())
+    }
+}
+
+impl fmt::Debug for ConstantCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConstantCodec")
+            .field("endian", &self.endian)
+            .field("max_size", &self.max_size)
+            .field("decoding", &self.decoding.as_ref().map(|d| d.fed))
+            .finish()
+    }
+}
+
+type DecodeFuture = Pin<Box<dyn Future<Output = Result<(ConstantImpl, FeedReader)>> + Send>>;
+
+// an object whose bytes have not all arrived yet.
+struct Decoding {
+    feed: Arc<Mutex<VecDeque<Bytes>>>,
+    fed: usize,
+    future: DecodeFuture,
+}
+
+impl Decoding {
+    fn new(endian: Endian) -> Self {
+        let feed = Arc::new(Mutex::new(VecDeque::new()));
+        let mut reader = FeedReader {
+            chunk: Bytes::new(),
+            feed: feed.clone(),
+        };
+        let future = Box::pin(async move {
+            let mut value = ConstantImpl::default();
+            match endian {
+                Endian::Big => value.deserialize(&mut reader).await?,
+                Endian::Little => value.deserialize_le(&mut reader).await?,
+            }
+            Ok((value, reader))
+        });
+
+        Self {
+            feed,
+            fed: 0,
+            future,
+        }
+    }
+}
+
+// reads the chunks fed to a decoding, pending when it runs out of them.
+struct FeedReader {
+    chunk: Bytes,
+    feed: Arc<Mutex<VecDeque<Bytes>>>,
+}
+
+impl AsyncRead for FeedReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let chunk = match self.as_mut().poll_fill_buf(cx) {
+            Poll::Ready(Ok(chunk)) => chunk,
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => return Poll::Pending,
+        };
+        let n = chunk.len().min(buf.remaining());
+        buf.put_slice(&chunk[..n]);
+        self.consume(n);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncBufRead for FeedReader {
+    fn poll_fill_buf(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        while this.chunk.is_empty() {
+            match this.feed.lock().unwrap().pop_front() {
+                Some(chunk) => this.chunk = chunk,
+                // the codec polls again once more bytes are fed.
+                None => return Poll::Pending,
+            }
+        }
+        Poll::Ready(Ok(&this.chunk))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().chunk.advance(amt);
+    }
+}
+
+impl Decoder for ConstantCodec {
+    type Item = ConstantImpl;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>> {
+        if self.decoding.is_none() {
+            if src.is_empty() {
+                return Ok(None);
+            }
+
+            // most objects arrive whole, decode them in place.
+            let mut reader = &src[..];
+            match with_max_len(self.max_size, || decode_from(&mut reader, self.endian)) {
+                Ok(value) => {
+                    let consumed = src.len() - reader.len();
+                    src.advance(consumed);
+                    return Ok(Some(value));
+                }
+                Err(e) if is_incomplete(&e) => self.decoding = Some(Decoding::new(self.endian)),
+                Err(e) => return Err(e),
+            }
+        }
+
+        let decoding = self.decoding.as_mut().unwrap();
+        if !src.is_empty() {
+            decoding.fed += src.len();
+            decoding
+                .feed
+                .lock()
+                .unwrap()
+                .push_back(src.split().freeze());
+        }
+
+        let mut cx = Context::from_waker(noop_waker_ref());
+        match with_max_len(self.max_size, || decoding.future.as_mut().poll(&mut cx)) {
+            Poll::Ready(Ok((value, reader))) => {
+                // give back the bytes after the object.
+                src.extend_from_slice(&reader.chunk);
+                for chunk in decoding.feed.lock().unwrap().drain(..) {
+                    src.extend_from_slice(&chunk);
+                }
+                self.decoding = None;
+                Ok(Some(value))
+            }
+            Poll::Ready(Err(e)) => {
+                self.decoding = None;
+                Err(e)
+            }
+            Poll::Pending if decoding.fed > self.max_size => {
+                let fed = decoding.fed;
+                self.decoding = None;
+                Err(Error::ConstraintsViolated(format!(
+                    "object exceeds the limit of {} bytes, {} bytes read",
+                    self.max_size, fed
+                )))
+            }
+            Poll::Pending => Ok(None),
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>> {
+        match self.decode(src)? {
+            Some(value) => Ok(Some(value)),
+            None if self.decoding.take().is_some() => Err(Error::UnexpectedEof),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Encoder<ConstantImpl> for ConstantCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: ConstantImpl, dst: &mut BytesMut) -> Result<()> {
+        encode_to(&item, self.endian, dst)
+    }
+}
+
+impl Encoder<&ConstantImpl> for ConstantCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: &ConstantImpl, dst: &mut BytesMut) -> Result<()> {
+        encode_to(item, self.endian, dst)
+    }
+}
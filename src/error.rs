@@ -4,6 +4,8 @@
 
 use std::string::FromUtf8Error;
 
+use crate::types::Table;
+
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 #[derive(Debug, thiserror::Error)]
@@ -35,6 +37,19 @@ pub enum Error {
     ChannelClosed(String),
     #[error("0")]
     StreamSubscriptionError(String),
+    #[error("row {row} rejected at column `{column}`: {source}")]
+    InvalidRow {
+        row: u64,
+        column: String,
+        source: Box<Error>,
+    },
+    #[error("{} rows not inserted: {source}", .unsent.len())]
+    FlushFailed {
+        unsent: Box<Table>,
+        source: Box<Error>,
+    },
+    #[error("{batches} batches spooled for retry: {source}")]
+    Spooled { batches: usize, source: Box<Error> },
 }
 
 impl<T> From<tokio::sync::mpsc::error::SendError<T>> for Error {
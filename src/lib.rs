@@ -6,6 +6,7 @@ use bytes::BufMut;
 use tokio::io::AsyncBufReadExt;
 
 pub mod client;
+pub mod codec;
 pub mod error;
 mod request;
 mod response;
@@ -44,8 +45,9 @@ pub(crate) trait Deserialize {
     }
 }
 
-#[derive(Clone, Copy, Default, Debug)]
-pub(crate) enum Endian {
+/// Byte order of the DolphinDB binary format.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Debug)]
+pub enum Endian {
     #[default]
     Little,
     Big,
@@ -122,7 +122,7 @@ impl<'a> Request<'a> {
 
         Self {
             header,
-            option: option.clone(),
+            option: *option,
             body,
         }
     }
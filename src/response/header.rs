@@ -50,7 +50,7 @@ impl Deserialize for ResponseHeader {
             if message_buf.last() == Some(&0) {
                 message_buf.pop();
             }
-            let s = String::from_utf8(message_buf).map_err(|e| Error::InvalidUtf8Encoding(e))?;
+            let s = String::from_utf8(message_buf).map_err(Error::InvalidUtf8Encoding)?;
             println!("{}", s);
 
             parts = read_parsed_line(reader).await?;
@@ -60,7 +60,7 @@ impl MessageParser {
                 .collect::<Vector<Any>>();
 
             res.push(Arc::new(Message::new(
-                (msg_id as i64) + (i as i64) as i64,
+                (msg_id as i64) + (i as i64),
                 topic.clone(),
                 VectorImpl::Any(v),
             )));
@@ -1,9 +1,14 @@
-use super::{Constant, ConstantImpl, DataForm, DataType};
+use super::{
+    decimal::*, primitive::*, read_fixed_width, temporal::*, Constant, ConstantImpl, DataForm,
+    DataType, DecimalInterface, FixedWidth, Primitive,
+};
 use crate::{
+    codec::check_len,
     error::{Error, Result},
-    types::VectorImpl,
+    types::{Vector, VectorImpl},
     Deserialize, Serialize,
 };
+use rust_decimal::RoundingStrategy;
 use std::{
     fmt::{self, Display},
     ops::{Index, IndexMut},
@@ -11,69 +16,102 @@ use std::{
 use tokio::io::AsyncBufReadExt;
 use tokio::io::AsyncReadExt;
 
+/// Vector whose elements are variable-length rows of scalars.
+///
+/// Besides null elements inside a row, a whole row may be null. DolphinDB has no null rows on
+/// the wire, so a vector holding null rows can't be serialized, store a row holding a single
+/// null element instead.
 #[derive(Default, Debug, Clone)]
 pub struct ArrayVector<S> {
     data: Vec<S>,
     index: Vec<usize>,
+    nulls: Vec<bool>,
+    // declared scale of decimal elements, None for other types.
+    scale: Option<u32>,
 }
 
 impl<T> Index<usize> for ArrayVector<T> {
     type Output = [T];
 
     fn index(&self, id: usize) -> &Self::Output {
-        let start = if id == 0 { 0 } else { self.index[id - 1] };
-        let end = self.index[id];
-        &self.data[start..end]
+        &self.data[self.range(id)]
     }
 }
 
 impl<T> IndexMut<usize> for ArrayVector<T> {
     fn index_mut(&mut self, id: usize) -> &mut Self::Output {
-        let start = if id == 0 { 0 } else { self.index[id - 1] };
-        let end = self.index[id];
-        &mut self.data[start..end]
+        let range = self.range(id);
+        &mut self.data[range]
     }
 }
 
 impl<S: PartialEq> PartialEq for ArrayVector<S> {
     fn eq(&self, other: &Self) -> bool {
-        self.data == other.data && self.index == other.index
+        self.data == other.data && self.index == other.index && self.nulls == other.nulls
     }
 }
 
 impl<S: PartialEq> Eq for ArrayVector<S> {}
 
-pub type CharArrayVector = ArrayVector<i8>;
-pub type ShortArrayVector = ArrayVector<i16>;
-pub type IntArrayVector = ArrayVector<i32>;
-pub type LongArrayVector = ArrayVector<i64>;
-pub type FloatArrayVector = ArrayVector<f32>;
-pub type DoubleArrayVector = ArrayVector<f64>;
+pub type BoolArrayVector = ArrayVector<Bool>;
+pub type CharArrayVector = ArrayVector<Char>;
+pub type ShortArrayVector = ArrayVector<Short>;
+pub type IntArrayVector = ArrayVector<Int>;
+pub type LongArrayVector = ArrayVector<Long>;
+pub type DateArrayVector = ArrayVector<Date>;
+pub type MonthArrayVector = ArrayVector<Month>;
+pub type TimeArrayVector = ArrayVector<Time>;
+pub type MinuteArrayVector = ArrayVector<Minute>;
+pub type SecondArrayVector = ArrayVector<Second>;
+pub type DateTimeArrayVector = ArrayVector<DateTime>;
+pub type TimestampArrayVector = ArrayVector<Timestamp>;
+pub type NanoTimeArrayVector = ArrayVector<NanoTime>;
+pub type NanoTimestampArrayVector = ArrayVector<NanoTimestamp>;
+pub type FloatArrayVector = ArrayVector<Float>;
+pub type DoubleArrayVector = ArrayVector<Double>;
+pub type DateHourArrayVector = ArrayVector<DateHour>;
+pub type Decimal32ArrayVector = ArrayVector<Decimal32>;
+pub type Decimal64ArrayVector = ArrayVector<Decimal64>;
+pub type Decimal128ArrayVector = ArrayVector<Decimal128>;
+pub type Int128ArrayVector = ArrayVector<Int128>;
+pub type UuidArrayVector = ArrayVector<Uuid>;
+pub type IpAddrArrayVector = ArrayVector<IpAddr>;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ArrayVectorImpl {
+    Bool(BoolArrayVector),
     Char(CharArrayVector),
     Short(ShortArrayVector),
     Int(IntArrayVector),
     Long(LongArrayVector),
+
+    Date(DateArrayVector),
+    Month(MonthArrayVector),
+    Time(TimeArrayVector),
+    Minute(MinuteArrayVector),
+    Second(SecondArrayVector),
+    DateTime(DateTimeArrayVector),
+    Timestamp(TimestampArrayVector),
+    NanoTime(NanoTimeArrayVector),
+    NanoTimestamp(NanoTimestampArrayVector),
+
     Float(FloatArrayVector),
     Double(DoubleArrayVector),
+
+    DateHour(DateHourArrayVector),
+
+    Decimal32(Decimal32ArrayVector),
+    Decimal64(Decimal64ArrayVector),
+    Decimal128(Decimal128ArrayVector),
+
+    Int128(Int128ArrayVector),
+    Uuid(UuidArrayVector),
+    IpAddr(IpAddrArrayVector),
 }
 
 impl ArrayVectorImpl {
     pub const FORM_BYTE: DataForm = DataForm::Vector;
 
-    pub fn data_type(&self) -> DataType {
-        match self {
-            ArrayVectorImpl::Char(_v) => DataType::CharArray,
-            ArrayVectorImpl::Short(_v) => DataType::ShortArray,
-            ArrayVectorImpl::Int(_v) => DataType::IntArray,
-            ArrayVectorImpl::Long(_v) => DataType::LongArray,
-            ArrayVectorImpl::Float(_v) => DataType::FloatArray,
-            ArrayVectorImpl::Double(_v) => DataType::DoubleArray,
-        }
-    }
-
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
@@ -83,23 +121,6 @@ impl ArrayVectorImpl {
     }
 }
 
-macro_rules! vector_interface {
-    ($(($data_type:tt)), *) => {
-        impl ArrayVectorImpl {
-            pub(crate) fn resize(&mut self, new_len: usize)
-            {
-                match self {
-                $(
-                    ArrayVectorImpl::$data_type(v) => v.resize(new_len),
-                )*
-                }
-            }
-        }
-    };
-}
-
-vector_interface!((Char), (Short), (Int), (Long), (Float), (Double));
-
 // blanket ArrayVector implementations for all Scalar instances
 impl<S> ArrayVector<S> {
     /// Constructs a new, empty [`ArrayVector`].
@@ -107,63 +128,248 @@ impl<S> ArrayVector<S> {
         Self {
             data: vec![],
             index: vec![],
+            nulls: vec![],
+            scale: None,
         }
     }
 
-    /// Clears the vector, removing all values.
+    /// Clears the vector, removing all rows.
     pub fn clear(&mut self) {
         self.data.clear();
         self.index.clear();
+        self.nulls.clear();
     }
 
-    /// Returns the number of elements in the vector, also referred to as its 'length'.
+    /// Returns the number of rows in the vector, also referred to as its 'length'.
     pub fn len(&self) -> usize {
         self.index.len()
     }
 
-    /// Returns [`true`] if the vector contains no elements.
+    /// Returns [`true`] if the vector contains no rows.
     pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+        self.index.is_empty()
     }
 
-    /// Appends an element to the back of a collection.
+    /// Appends a row to the back of a collection.
     pub fn push(&mut self, value: Vec<S>) {
         self.data.extend(value);
         self.index.push(self.data.len());
+        self.nulls.push(false);
+    }
+
+    /// Appends a null row to the back of a collection.
+    pub fn push_null(&mut self) {
+        self.index.push(self.data.len());
+        self.nulls.push(true);
+    }
+
+    /// Returns [`true`] if the row at `index` is null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn is_null(&self, index: usize) -> bool {
+        self.nulls[index]
+    }
+
+    /// Returns the row at `index`, `None` if it is out of bounds.
+    ///
+    /// A null row is returned as `Some(None)`.
+    pub fn get(&self, index: usize) -> Option<Option<&[S]>> {
+        if index >= self.len() {
+            return None;
+        }
+
+        if self.nulls[index] {
+            Some(None)
+        } else {
+            Some(Some(&self[index]))
+        }
+    }
+
+    /// Returns an iterator over the rows, null rows are yielded as `None`.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Option<&[S]>> + '_ {
+        (0..self.len()).map(|i| (!self.nulls[i]).then(|| &self[i]))
+    }
+
+    /// Inserts a row at position `index`, shifting all rows after it to the right.
+    /// `None` inserts a null row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: Option<Vec<S>>) {
+        assert!(
+            index <= self.len(),
+            "insertion index (is {index}) should be <= len"
+        );
+
+        let start = self.start(index);
+        let (row, is_null) = match value {
+            Some(row) => (row, false),
+            None => (vec![], true),
+        };
+        let count = row.len();
+
+        self.data.splice(start..start, row);
+        for end in self.index[index..].iter_mut() {
+            *end += count;
+        }
+        self.index.insert(index, start + count);
+        self.nulls.insert(index, is_null);
+    }
+
+    /// Removes and returns the row at position `index`, shifting all rows after it to the left.
+    /// A null row is returned as `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<Vec<S>> {
+        let range = self.range(index);
+        let count = range.len();
+
+        let row: Vec<S> = self.data.drain(range).collect();
+        self.index.remove(index);
+        for end in self.index[index..].iter_mut() {
+            *end -= count;
+        }
+
+        if self.nulls.remove(index) {
+            None
+        } else {
+            Some(row)
+        }
+    }
+
+    fn start(&self, index: usize) -> usize {
+        if index == 0 {
+            0
+        } else {
+            self.index[index - 1]
+        }
+    }
+
+    fn range(&self, index: usize) -> std::ops::Range<usize> {
+        self.start(index)..self.index[index]
+    }
+}
+
+impl<S: Primitive> ArrayVector<S> {
+    /// Appends a row of primitive elements to the back of a collection.
+    /// `None` appends a null row and `None` elements are null elements.
+    pub fn push_raw(&mut self, value: Option<&[Option<S::RawType>]>) {
+        match value {
+            Some(row) => self.push(
+                row.iter()
+                    .map(|v| v.clone().map_or_else(S::default, S::new))
+                    .collect(),
+            ),
+            None => self.push_null(),
+        }
     }
 }
 
 impl<S: Clone> ArrayVector<S> {
+    // pads with null rows.
     pub(crate) fn resize(&mut self, new_len: usize) {
-        let mut index = 0;
-        if !self.is_empty() {
-            index = *self.index.last().unwrap();
+        if new_len <= self.len() {
+            self.data.truncate(self.start(new_len));
+            self.index.truncate(new_len);
+            self.nulls.truncate(new_len);
+        } else {
+            while self.len() < new_len {
+                self.push_null();
+            }
         }
-        self.index.resize(new_len, index);
+    }
+}
+
+impl<S: Clone> ArrayVector<S> {
+    // rows at `indices` in that order, panics if any of them is out of bounds.
+    pub(crate) fn take(&self, indices: &[usize]) -> Self {
+        let mut res = Self::new();
+        res.scale = self.scale;
+        for &i in indices {
+            if self.nulls[i] {
+                res.push_null();
+            } else {
+                res.push(self[i].to_vec());
+            }
+        }
+        res
+    }
+
+    pub(crate) fn extend_from(&mut self, other: &Self) {
+        let offset = self.data.len();
+        self.data.extend_from_slice(&other.data);
+        self.index
+            .extend(other.index.iter().map(|end| end + offset));
+        self.nulls.extend_from_slice(&other.nulls);
+    }
+}
+
+impl<S: DecimalInterface> ArrayVector<S> {
+    /// Returns the declared scale of the elements, if any.
+    pub fn scale(&self) -> Option<u32> {
+        self.scale
+    }
+
+    /// Declares the scale of the elements and rounds existing values to it, see
+    /// [`Vector::set_scale`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the vector unchanged if `scale` is larger than the type
+    /// allows or a value can't be represented at it.
+    pub fn set_scale(&mut self, scale: u32) -> Result<()> {
+        if scale > S::MAX_SCALE {
+            return Err(Error::ConstraintsViolated(format!(
+                "scale of {} must be in [0, {}], got {}",
+                S::data_type(),
+                S::MAX_SCALE,
+                scale
+            )));
+        }
+
+        let data = self
+            .data
+            .iter()
+            .map(|d| d.round(scale, RoundingStrategy::MidpointAwayFromZero))
+            .collect::<Result<Vec<_>>>()?;
+        self.data = data;
+        self.scale = Some(scale);
+        Ok(())
+    }
+
+    fn max_scale(&self) -> u32 {
+        self.data
+            .iter()
+            .filter_map(|d| d.scale())
+            .max()
+            .unwrap_or(0)
     }
 }
 
 impl<S: Display> Display for ArrayVector<S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut s = String::new();
-        let mut i = 0usize;
-        let mut prev_index = 0usize;
-        for index in self.index.iter() {
-            if *index == prev_index {
-                s.push_str("[], ");
-                continue;
-            }
-            s.push_str("[");
-            while i < *index {
-                s.push_str(self.data[i].to_string().as_str());
-                s.push_str(",");
-                i += 1;
-            }
-            if !s.is_empty() {
-                s.pop();
+        for row in self.iter() {
+            match row {
+                None => s.push_str("null"),
+                Some(row) => {
+                    s.push('[');
+                    for v in row {
+                        s.push_str(v.to_string().as_str());
+                        s.push(',');
+                    }
+                    if !row.is_empty() {
+                        s.pop();
+                    }
+                    s.push(']');
+                }
             }
-            s.push_str("], ");
-            prev_index = *index;
+            s.push_str(", ");
         }
         if !s.is_empty() {
             s.pop();
@@ -174,10 +380,111 @@ impl<S: Display> Display for ArrayVector<S> {
     }
 }
 
-macro_rules! serialize {
-    ($(($data_type:tt, $put_le:ident)), *) => {
+// rows per block of the wire format, a block holds its row counts followed by its elements.
+const BLOCK_ROWS: usize = u16::MAX as usize;
+
+impl<S> ArrayVector<S> {
+    // fails if a row can't be transferred.
+    fn check_wire(&self) -> Result<()> {
+        match self.nulls.iter().position(|n| *n) {
+            Some(i) => Err(Error::ConstraintsViolated(format!(
+                "row {} of the array vector is null, which DolphinDB can't transfer",
+                i
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    // splits the rows into the blocks they are serialized in.
+    fn wire_blocks(&self) -> impl Iterator<Item = std::ops::Range<usize>> {
+        let len = self.len();
+        (0..len)
+            .step_by(BLOCK_ROWS)
+            .map(move |start| start..len.min(start + BLOCK_ROWS))
+    }
+
+    // row counts of a block are serialized with 4 bytes per count.
+    fn serialize_index_le<B>(&self, rows: std::ops::Range<usize>, buffer: &mut B)
+    where
+        B: bytes::BufMut,
+    {
+        buffer.put_u16_le(rows.len() as u16); // len
+        buffer.put_u8(4); // sizeof index data
+        buffer.put_i8(0); // no use
+        for i in rows {
+            buffer.put_u32_le(self.range(i).len() as u32);
+        }
+    }
+
+    // visits elements of `rows` in wire order.
+    fn for_each_wire_value(
+        &self,
+        rows: std::ops::Range<usize>,
+        f: impl FnMut(&S) -> Result<()>,
+    ) -> Result<()> {
+        let elements = self.start(rows.start)..self.index[rows.end - 1];
+        self.data[elements].iter().try_for_each(f)
+    }
+    // rebuilds rows from decoded data.
+    fn from_wire(index: Vec<usize>, data: Vec<S>) -> Self {
+        Self {
+            data,
+            nulls: vec![false; index.len()],
+            index,
+            scale: None,
+        }
+    }
+}
+
+// reads row counts of one block into `index`, returns counts of rows and elements in the block.
+async fn deserialize_index_block<R>(
+    reader: &mut R,
+    index: &mut Vec<usize>,
+) -> Result<(usize, usize)>
+where
+    R: AsyncBufReadExt + Unpin,
+{
+    let len = reader.read_u16_le().await? as usize;
+    let size_of_index_data = reader.read_u8().await?;
+    let _ = reader.read_i8().await?;
+
+    let width = match size_of_index_data {
+        1 | 2 | 4 => size_of_index_data as usize,
+        _ => {
+            return Err(Error::InvalidData {
+                expect: "size_of_index_data: 1 2 4".to_string(),
+                actual: format!("{}", size_of_index_data),
+            })
+        }
+    };
+
+    let mut deltas = vec![0usize; len];
+    read_fixed_width(reader, &mut deltas, width, |bytes| {
+        Ok(bytes
+            .iter()
+            .rev()
+            .fold(0usize, |acc, b| (acc << 8) | *b as usize))
+    })
+    .await?;
+
+    let last_index = *index.last().unwrap_or(&0);
+    let mut prev = last_index;
+
+    for delta in deltas {
+        prev = prev.checked_add(delta).ok_or(Error::Unsupported {
+            data_form: "ArrayVector".to_string(),
+            data_type: "Index overflow".to_string(),
+        })?;
+        index.push(prev);
+    }
+
+    Ok((len, prev - last_index))
+}
+
+macro_rules! serialize_scalar {
+    ($($struct_name:ident), *) => {
         $(
-            impl Serialize for ArrayVector<$data_type> {
+            impl Serialize for ArrayVector<$struct_name> {
                 fn serialize<B>(&self, buffer: &mut B) -> Result<usize>
                 where
                     B: bytes::BufMut,
@@ -190,22 +497,78 @@ macro_rules! serialize {
                 where
                     B: bytes::BufMut,
                 {
+                    self.check_wire()?;
                     if self.len() == 0 {
                         return Ok(0);
                     }
-                    // serialize index
-                    buffer.put_u16_le(self.len() as u16); // len
-                    buffer.put_u8(4); // sizeof index data
-                    buffer.put_i8(0); // no use
-                    let mut prev = 0;
-                    for index in self.index.iter() {
-                        let cnt = *index as u32 - prev;
-                        buffer.put_u32_le(cnt);
-                        prev = *index as u32;
+                    for rows in self.wire_blocks() {
+                        self.serialize_index_le(rows.clone(), buffer);
+                        // serialize data
+                        self.for_each_wire_value(rows, |value| {
+                            value.serialize_le(buffer).map(|_| ())
+                        })?;
                     }
-                    // serialize data
-                    for value in self.data.iter() {
-                        buffer.$put_le(*value);
+                    Ok(1)
+                }
+            }
+        )*
+    };
+}
+
+serialize_scalar!(
+    Bool,
+    Char,
+    Short,
+    Int,
+    Long,
+    Date,
+    Month,
+    Time,
+    Minute,
+    Second,
+    DateTime,
+    Timestamp,
+    NanoTime,
+    NanoTimestamp,
+    Float,
+    Double,
+    DateHour,
+    Int128,
+    Uuid,
+    IpAddr
+);
+
+macro_rules! serialize_decimal {
+    ($(($raw_type:tt, $struct_name:ident, $put_le:ident)), *) => {
+        $(
+            impl Serialize for ArrayVector<$struct_name> {
+                fn serialize<B>(&self, buffer: &mut B) -> Result<usize>
+                where
+                    B: bytes::BufMut,
+                {
+                    _ = buffer;
+                    Err(Error::Unsupported { data_form: "ArrayVector".to_owned(), data_type: "ALL".to_owned() })
+                }
+
+                fn serialize_le<B>(&self, buffer: &mut B) -> Result<usize>
+                where
+                    B: bytes::BufMut,
+                {
+                    self.check_wire()?;
+                    // all elements share the same scale, which precedes the blocks.
+                    let scale = self.scale.unwrap_or_else(|| self.max_scale());
+                    buffer.put_u32_le(scale);
+
+                    if self.len() == 0 {
+                        return Ok(0);
+                    }
+                    for rows in self.wire_blocks() {
+                        self.serialize_index_le(rows.clone(), buffer);
+                        // serialize data
+                        self.for_each_wire_value(rows, |value| {
+                            buffer.$put_le(value.wire_mantissa(scale)?);
+                            Ok(())
+                        })?;
                     }
                     Ok(1)
                 }
@@ -214,84 +577,123 @@ macro_rules! serialize {
     };
 }
 
-serialize!(
-    (i8, put_i8),
-    (i16, put_i16_le),
-    (i32, put_i32_le),
-    (i64, put_i64_le),
-    (f32, put_f32_le),
-    (f64, put_f64_le)
+serialize_decimal!(
+    (i32, Decimal32, put_i32_le),
+    (i64, Decimal64, put_i64_le),
+    (i128, Decimal128, put_i128_le)
 );
 
-macro_rules! deserialize_vector {
-    ($read_func:ident, $func_name:ident) => {
+macro_rules! deserialize_scalar {
+    ($struct_name:ident, $func_name:ident, $decode:ident) => {
         async fn $func_name<R>(&mut self, reader: &mut R) -> Result<()>
         where
             R: AsyncBufReadExt + Unpin,
         {
             let mut target_num = self.index.len();
             let mut index = Vec::with_capacity(target_num);
-            let mut prev:usize = 0;
             let mut data = Vec::new();
-            let mut last_index = 0;
-
-            while (target_num > 0) {
-                let len = reader.read_u16_le().await? as usize;
-                let size_of_index_data = reader.read_u8().await?;
-                let _ = reader.read_i8().await?;
-
-                for _ in 0..len {
-                    let delta = match size_of_index_data {
-                        1 => reader.read_u8().await? as usize ,
-                        2 => reader.read_u16_le().await? as usize,
-                        4 => reader.read_u32_le().await? as usize,
-                        _ => return Err(Error::InvalidData {
-                            expect: "size_of_index_data: 1 2 4".to_string(),
-                            actual: format!("{}", size_of_index_data),
-                        }),
-                    };
-                    prev = prev.checked_add(delta).ok_or(Error::Unsupported {
-                        data_form: "ArrayVector".to_string(),
-                        data_type: "Index overflow".to_string(),
-                    })?;
-                    index.push(prev as usize);
-                }
 
-                let cur_last_index = *index.last().unwrap_or(&0);
-                let total_elements = cur_last_index - last_index;
-                last_index = cur_last_index;
+            while target_num > 0 {
+                let (rows, elements) = deserialize_index_block(reader, &mut index).await?;
 
-                for _ in 0..total_elements {
-                    let v = reader.$read_func().await?;
-                    data.push(v);
-                }
-                target_num -= len;
+                let start = data.len();
+                check_len(start + elements)?;
+                data.resize(start + elements, $struct_name::default());
+                read_fixed_width(reader, &mut data[start..], $struct_name::WIDTH, $struct_name::$decode).await?;
+                target_num -= rows;
+            }
+
+            *self = Self::from_wire(index, data);
+
+            Ok(())
+        }
+    };
+
+    ($($struct_name:ident), *) => {
+        $(
+            impl Deserialize for ArrayVector<$struct_name> {
+                deserialize_scalar!($struct_name, deserialize, from_be_slice);
+                deserialize_scalar!($struct_name, deserialize_le, from_le_slice);
+            }
+        )*
+    };
+}
+
+deserialize_scalar!(
+    Bool,
+    Char,
+    Short,
+    Int,
+    Long,
+    Date,
+    Month,
+    Time,
+    Minute,
+    Second,
+    DateTime,
+    Timestamp,
+    NanoTime,
+    NanoTimestamp,
+    Float,
+    Double,
+    DateHour,
+    Int128,
+    Uuid,
+    IpAddr
+);
+
+macro_rules! deserialize_decimal {
+    ($raw_type:tt, $struct_name:ident, $read_scale:ident, $read_func:ident, $func_name:ident) => {
+        async fn $func_name<R>(&mut self, reader: &mut R) -> Result<()>
+        where
+            R: AsyncBufReadExt + Unpin,
+        {
+            let scale = reader.$read_scale().await?;
+
+            let mut target_num = self.index.len();
+            let mut index = Vec::with_capacity(target_num);
+            let mut data = Vec::new();
+
+            while target_num > 0 {
+                let (rows, elements) = deserialize_index_block(reader, &mut index).await?;
+
+                let start = data.len();
+                check_len(start + elements)?;
+                data.resize(start + elements, $struct_name::default());
+                read_fixed_width(reader, &mut data[start..], size_of::<$raw_type>(), |bytes| {
+                    let mantissa = $raw_type::$read_func(bytes.try_into().map_err(|_| Error::UnexpectedEof)?);
+
+                    if mantissa != $raw_type::MIN {
+                        $struct_name::from_raw(mantissa, scale as u32).ok_or(Error::ConstraintsViolated("decimal scale overflow".into()))
+                    } else {
+                        Ok($struct_name::default())
+                    }
+                })
+                .await?;
+                target_num -= rows;
             }
 
-            self.index = index;
-            self.data = data;
+            *self = Self::from_wire(index, data);
+            self.scale = Some(scale as u32);
 
             Ok(())
         }
     };
 
-    ($(($struct_name:ident, $read_func:ident, $read_func_le:ident)), *) => {
+    ($(($raw_type:tt, $struct_name:ident, $read_func:ident, $read_func_le:ident)), *) => {
         $(
-            impl Deserialize for $struct_name {
-                deserialize_vector!($read_func, deserialize);
-                deserialize_vector!($read_func_le, deserialize_le);
+            impl Deserialize for ArrayVector<$struct_name> {
+                deserialize_decimal!($raw_type, $struct_name, read_i32, $read_func, deserialize);
+                deserialize_decimal!($raw_type, $struct_name, read_i32_le, $read_func_le, deserialize_le);
             }
         )*
     };
 }
 
-deserialize_vector!(
-    (CharArrayVector, read_i8, read_i8),
-    (ShortArrayVector, read_i16, read_i16_le),
-    (IntArrayVector, read_i32, read_i32_le),
-    (LongArrayVector, read_i64, read_i64_le),
-    (FloatArrayVector, read_f32, read_f32_le),
-    (DoubleArrayVector, read_f64, read_f64_le)
+deserialize_decimal!(
+    (i32, Decimal32, from_be_bytes, from_le_bytes),
+    (i64, Decimal64, from_be_bytes, from_le_bytes),
+    (i128, Decimal128, from_be_bytes, from_le_bytes)
 );
 
 macro_rules! try_from_impl {
@@ -331,12 +733,29 @@ macro_rules! to_constant_impl {
 macro_rules! for_array_types {
     ($macro:tt) => {
         $macro!(
-            (i8, Char),
-            (i16, Short),
-            (i32, Int),
-            (i64, Long),
-            (f32, Float),
-            (f64, Double)
+            (Bool, Bool),
+            (Char, Char),
+            (Short, Short),
+            (Int, Int),
+            (Long, Long),
+            (Date, Date),
+            (Month, Month),
+            (Time, Time),
+            (Minute, Minute),
+            (Second, Second),
+            (DateTime, DateTime),
+            (Timestamp, Timestamp),
+            (NanoTime, NanoTime),
+            (NanoTimestamp, NanoTimestamp),
+            (Float, Float),
+            (Double, Double),
+            (DateHour, DateHour),
+            (Decimal32, Decimal32),
+            (Decimal64, Decimal64),
+            (Decimal128, Decimal128),
+            (Int128, Int128),
+            (Uuid, Uuid),
+            (IpAddr, IpAddr)
         );
     };
 }
@@ -346,7 +765,7 @@ for_array_types!(try_from_impl);
 for_array_types!(to_constant_impl);
 
 macro_rules! dispatch_display {
-    ($(($enum_name:ident)),*) => {
+    ($(($enum_name:ident, $data_type:ident)),*) => {
         impl Display for ArrayVectorImpl {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 match self {
@@ -360,7 +779,7 @@ macro_rules! dispatch_display {
 }
 
 macro_rules! dispatch_len {
-    ($(($enum_name:ident)),*) => {
+    ($(($enum_name:ident, $data_type:ident)),*) => {
         impl ArrayVectorImpl {
             pub fn len(&self) -> usize {
                 match self {
@@ -374,7 +793,7 @@ macro_rules! dispatch_len {
 }
 
 macro_rules! dispatch_serialize {
-    ($(($enum_name:ident)),*) => {
+    ($(($enum_name:ident, $data_type:ident)),*) => {
         impl ArrayVectorImpl {
             pub(crate) fn serialize_data<B>(&self, buffer: &mut B) -> Result<usize>
             where
@@ -423,12 +842,188 @@ macro_rules! dispatch_serialize {
     };
 }
 
+macro_rules! dispatch_data_type {
+    ($(($enum_name:ident, $data_type:ident)),*) => {
+        impl ArrayVectorImpl {
+            pub fn data_type(&self) -> DataType {
+                match self {
+                    $(
+                        ArrayVectorImpl::$enum_name(_) => DataType::$data_type,
+                    )*
+                }
+            }
+
+            // type of the elements in each row.
+            pub(crate) fn element_type(&self) -> DataType {
+                match self {
+                    $(
+                        ArrayVectorImpl::$enum_name(_) => DataType::$enum_name,
+                    )*
+                }
+            }
+        }
+    };
+}
+
+macro_rules! dispatch_reflect {
+    ($(($enum_name:ident, $data_type:ident)),*) => {
+        impl ArrayVectorImpl {
+            pub(crate) fn from_type(data_type: DataType) -> Option<Self> {
+                match data_type {
+                    $(
+                        DataType::$data_type => Some(Self::$enum_name(ArrayVector::new())),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+macro_rules! dispatch_resize {
+    ($(($enum_name:ident, $data_type:ident)),*) => {
+        impl ArrayVectorImpl {
+            pub(crate) fn resize(&mut self, new_len: usize) {
+                match self {
+                    $(
+                        ArrayVectorImpl::$enum_name(v) => v.resize(new_len),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+macro_rules! dispatch_get {
+    ($(($enum_name:ident, $data_type:ident)),*) => {
+        impl ArrayVectorImpl {
+            /// Returns row `index` as a vector of its elements, `None` if it is out of bounds.
+            /// A null row is returned as a single null element.
+            pub fn get(&self, index: usize) -> Option<VectorImpl> {
+                match self {
+                    $(
+                        ArrayVectorImpl::$enum_name(v) => v.get(index).map(|row| {
+                            let row = row.map_or_else(|| vec![$enum_name::default()], |r| r.to_vec());
+                            Vector::from(row).into()
+                        }),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+macro_rules! dispatch_push_null {
+    ($(($enum_name:ident, $data_type:ident)),*) => {
+        impl ArrayVectorImpl {
+            /// Appends a null row to the back of a collection.
+            pub fn push_null(&mut self) {
+                match self {
+                    $(
+                        ArrayVectorImpl::$enum_name(v) => v.push_null(),
+                    )*
+                }
+            }
+
+            // appends a row holding a single null element, which DolphinDB can transfer.
+            pub(crate) fn push_null_element(&mut self) {
+                match self {
+                    $(
+                        ArrayVectorImpl::$enum_name(v) => v.push(vec![$enum_name::default()]),
+                    )*
+                }
+            }
+
+            // appends the elements of `row` as a row, which must hold the element type.
+            pub(crate) fn push_row(&mut self, row: &VectorImpl) -> Result<()> {
+                match (self, row) {
+                    $(
+                        (ArrayVectorImpl::$enum_name(v), VectorImpl::$enum_name(r)) => v.push(r.to_vec()),
+                    )*
+                    (v, r) => {
+                        return Err(Error::InvalidConvert {
+                            from: r.data_type().to_string(),
+                            to: v.data_type().to_string(),
+                        })
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+macro_rules! dispatch_take {
+    ($(($enum_name:ident, $data_type:ident)),*) => {
+        impl ArrayVectorImpl {
+            pub(crate) fn take(&self, indices: &[usize]) -> Self {
+                match self {
+                    $(
+                        ArrayVectorImpl::$enum_name(v) => ArrayVectorImpl::$enum_name(v.take(indices)),
+                    )*
+                }
+            }
+
+            pub(crate) fn extend_from(&mut self, other: &Self) -> Result<()> {
+                match (self, other) {
+                    $(
+                        (ArrayVectorImpl::$enum_name(v), ArrayVectorImpl::$enum_name(o)) => v.extend_from(o),
+                    )*
+                    (v, o) => {
+                        return Err(Error::InvalidConvert {
+                            from: o.data_type().to_string(),
+                            to: v.data_type().to_string(),
+                        })
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
 macro_rules! for_all_vectors {
     ($macro:tt) => {
-        $macro!((Char), (Short), (Int), (Long), (Float), (Double));
+        $macro!(
+            (Bool, BoolArray),
+            (Char, CharArray),
+            (Short, ShortArray),
+            (Int, IntArray),
+            (Long, LongArray),
+            (Date, DateArray),
+            (Month, MonthArray),
+            (Time, TimeArray),
+            (Minute, MinuteArray),
+            (Second, SecondArray),
+            (DateTime, DateTimeArray),
+            (Timestamp, TimestampArray),
+            (NanoTime, NanoTimeArray),
+            (NanoTimestamp, NanoTimestampArray),
+            (Float, FloatArray),
+            (Double, DoubleArray),
+            (DateHour, DateHourArray),
+            (Decimal32, Decimal32Array),
+            (Decimal64, Decimal64Array),
+            (Decimal128, Decimal128Array),
+            (Int128, Int128Array),
+            (Uuid, UuidArray),
+            (IpAddr, IpAddrArray)
+        );
     };
 }
 
+for_all_vectors!(dispatch_data_type);
+
+for_all_vectors!(dispatch_reflect);
+
+for_all_vectors!(dispatch_resize);
+
+for_all_vectors!(dispatch_get);
+
+for_all_vectors!(dispatch_push_null);
+
+for_all_vectors!(dispatch_take);
+
 for_all_vectors!(dispatch_len);
 
 for_all_vectors!(dispatch_serialize);
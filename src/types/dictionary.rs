@@ -223,7 +223,7 @@ where
         let mut res = Dictionary::new();
 
         let any_vector = values.break_up();
-        for (k, v) in keys.into_iter().zip(any_vector.into_iter()) {
+        for (k, v) in keys.into_iter().zip(any_vector) {
             res.data.insert(k, v);
         }
 
@@ -0,0 +1,161 @@
+use super::DataType;
+use crate::error::Error;
+
+use std::any::type_name;
+use std::fmt::{self, Display};
+
+/// Time unit of a [`Duration`].
+///
+/// Besides the calendar units, DolphinDB accepts trading calendars identified
+/// by a 4-letter exchange code (e.g. `XNYS`), where one unit is a trading day.
+#[derive(Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum DurationUnit {
+    #[default]
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+    BusinessDay,
+    Exchange([u8; 4]),
+}
+
+macro_rules! duration_unit_impl {
+    ($(($enum_name:ident, $code:literal, $suffix:literal)), *) => {
+        impl DurationUnit {
+            /// Makes a [`DurationUnit::Exchange`] from a 4-letter upper case exchange code.
+            pub fn exchange(code: &str) -> Option<Self> {
+                let bytes: [u8; 4] = code.as_bytes().try_into().ok()?;
+
+                if bytes.iter().all(|b| b.is_ascii_uppercase()) {
+                    Some(Self::Exchange(bytes))
+                } else {
+                    None
+                }
+            }
+
+            /// Suffix used by DolphinDB literals, e.g. `m` in `5m`.
+            pub fn suffix(&self) -> &str {
+                match self {
+                    $(
+                        Self::$enum_name => $suffix,
+                    )*
+                    // exchange codes are validated as ascii on construction.
+                    Self::Exchange(code) => std::str::from_utf8(code).unwrap_or_default(),
+                }
+            }
+
+            /// Makes a [`DurationUnit`] from the suffix used by DolphinDB literals.
+            pub fn from_suffix(suffix: &str) -> Option<Self> {
+                match suffix {
+                    $(
+                        $suffix => Some(Self::$enum_name),
+                    )*
+                    _ => Self::exchange(suffix),
+                }
+            }
+
+            /// DolphinDB internal representation
+            pub(crate) fn ddb_rep(&self) -> i32 {
+                match self {
+                    $(
+                        Self::$enum_name => $code,
+                    )*
+                    Self::Exchange(code) => i32::from_be_bytes(*code),
+                }
+            }
+
+            pub(crate) fn from_ddb_rep(code: i32) -> Option<Self> {
+                match code {
+                    $(
+                        $code => Some(Self::$enum_name),
+                    )*
+                    _ => Self::exchange(std::str::from_utf8(&code.to_be_bytes()).ok()?),
+                }
+            }
+        }
+    };
+}
+
+duration_unit_impl!(
+    (Nanosecond, 0, "ns"),
+    (Microsecond, 1, "us"),
+    (Millisecond, 2, "ms"),
+    (Second, 3, "s"),
+    (Minute, 4, "m"),
+    (Hour, 5, "H"),
+    (Day, 6, "d"),
+    (Week, 7, "w"),
+    (Month, 8, "M"),
+    (Year, 9, "y"),
+    (BusinessDay, 10, "B")
+);
+
+impl Display for DurationUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.suffix())
+    }
+}
+
+#[derive(Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Duration(pub(crate) Option<(i32, DurationUnit)>);
+
+impl Duration {
+    pub const DATA_BYTE: DataType = DataType::Duration;
+
+    pub fn new(length: i32, unit: DurationUnit) -> Self {
+        Self(Some((length, unit)))
+    }
+
+    pub const fn data_type() -> DataType {
+        Self::DATA_BYTE
+    }
+
+    pub const fn is_null(&self) -> bool {
+        self.0.is_none()
+    }
+
+    pub fn into_inner(self) -> Option<(i32, DurationUnit)> {
+        self.0
+    }
+}
+
+impl AsRef<Option<(i32, DurationUnit)>> for Duration {
+    fn as_ref(&self) -> &Option<(i32, DurationUnit)> {
+        &self.0
+    }
+}
+
+impl From<(i32, DurationUnit)> for Duration {
+    fn from(value: (i32, DurationUnit)) -> Self {
+        Self(Some(value))
+    }
+}
+
+impl TryFrom<Duration> for (i32, DurationUnit) {
+    type Error = Error;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        match value.into_inner() {
+            Some(value) => Ok(value),
+            _ => Err(Error::InvalidConvert {
+                from: "null".into(),
+                to: type_name::<(i32, DurationUnit)>().to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            None => write!(f, "null"),
+            Some((length, unit)) => write!(f, "{}{}", length, unit),
+        }
+    }
+}
@@ -28,20 +28,41 @@ pub enum DataType {
     Double = 16,
     Symbol = 17,
     String = 18,
+    Uuid = 19,
     Any = 25,
     AnyDictionary = 27,
     DateHour = 28,
+    IpAddr = 30,
+    Int128 = 31,
     Blob = 32,
+    Duration = 36,
     Decimal32 = 37,
     Decimal64 = 38,
     Decimal128 = 39,
 
+    BoolArray = 65,
     CharArray = 66,
     ShortArray = 67,
     IntArray = 68,
     LongArray = 69,
+    DateArray = 70,
+    MonthArray = 71,
+    TimeArray = 72,
+    MinuteArray = 73,
+    SecondArray = 74,
+    DateTimeArray = 75,
+    TimestampArray = 76,
+    NanoTimeArray = 77,
+    NanoTimestampArray = 78,
     FloatArray = 79,
     DoubleArray = 80,
+    UuidArray = 83,
+    DateHourArray = 92,
+    IpAddrArray = 94,
+    Int128Array = 95,
+    Decimal32Array = 101,
+    Decimal64Array = 102,
+    Decimal128Array = 103,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -109,19 +130,40 @@ macro_rules! for_all_types {
             (Double, 16),
             (Symbol, 17),
             (String, 18),
+            (Uuid, 19),
             (Any, 25),
             (AnyDictionary, 27),
             (DateHour, 28),
+            (IpAddr, 30),
+            (Int128, 31),
             (Blob, 32),
+            (Duration, 36),
             (Decimal32, 37),
             (Decimal64, 38),
             (Decimal128, 39),
+            (BoolArray, 65),
             (CharArray, 66),
             (ShortArray, 67),
             (IntArray, 68),
             (LongArray, 69),
+            (DateArray, 70),
+            (MonthArray, 71),
+            (TimeArray, 72),
+            (MinuteArray, 73),
+            (SecondArray, 74),
+            (DateTimeArray, 75),
+            (TimestampArray, 76),
+            (NanoTimeArray, 77),
+            (NanoTimestampArray, 78),
             (FloatArray, 79),
-            (DoubleArray, 80)
+            (DoubleArray, 80),
+            (UuidArray, 83),
+            (DateHourArray, 92),
+            (IpAddrArray, 94),
+            (Int128Array, 95),
+            (Decimal32Array, 101),
+            (Decimal64Array, 102),
+            (Decimal128Array, 103)
         );
     };
 }
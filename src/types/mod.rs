@@ -1,12 +1,23 @@
 //! DolphinDB type wrappers to make our lives easier when interacting with the Dlang type system.
 //!
 //! See [DolphinDB docs](https://docs.dolphindb.cn/zh/rustdoc/chap2_dataobjects_landingpage.html) for more information.
+//!
+//! # Numeric scalars
+//!
+//! [`Char`], [`Short`], [`Int`], [`Long`], [`Float`] and [`Double`] hold the native value, with
+//! `NULL_VALUE` standing for null, so their vectors are contiguous native arrays. This is a
+//! breaking change: they no longer implement `AsRef<Option<_>>` and `AsMut<Option<_>>`, since
+//! there is no `Option` to lend out. The inherent `as_ref` and `as_mut` return the
+//! [`OptionRef`] and [`OptionMut`] guards instead, which dereference to an `Option`, so method
+//! calls like `value.as_ref().is_some()` keep working. Generic code bound on the traits should
+//! use `into_inner`, `native` or `native_mut`.
 
 mod any;
 mod array_vector;
 mod constant;
 mod decimal;
 mod dictionary;
+mod duration;
 mod enums;
 mod pair;
 mod primitive;
@@ -25,6 +36,7 @@ pub use array_vector::*;
 pub use constant::*;
 pub use decimal::*;
 pub use dictionary::*;
+pub use duration::*;
 pub use enums::*;
 pub use pair::*;
 pub use primitive::*;
@@ -75,6 +87,28 @@ macro_rules! display_impl {
 
     (Char) => {};
 
+    (Short) => { display_impl!(@native Short); };
+
+    (Int) => { display_impl!(@native Int); };
+
+    (Long) => { display_impl!(@native Long); };
+
+    (Float) => { display_impl!(@native Float); };
+
+    (Double) => { display_impl!(@native Double); };
+
+    (@native $struct_name:ident) => {
+        impl Display for $struct_name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if let Some(val) = self.into_inner() {
+                    write!(f, "{}", val)
+                } else {
+                    write!(f, "null")
+                }
+            }
+        }
+    };
+
     ($struct_name:ident) => {
         impl Display for $struct_name {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -110,10 +144,39 @@ impl Display for Blob {
     }
 }
 
-impl Display for Char {
+impl Display for Int128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(val) = self.as_ref() {
+            write!(f, "{:032x}", *val as u128)
+        } else {
+            write!(f, "null")
+        }
+    }
+}
+
+impl Display for Uuid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(val) = self.as_ref() {
-            write!(f, "{}", *val as u8 as char)
+            let hex = format!("{:032x}", val);
+            write!(
+                f,
+                "{}-{}-{}-{}-{}",
+                &hex[0..8],
+                &hex[8..12],
+                &hex[12..16],
+                &hex[16..20],
+                &hex[20..32]
+            )
+        } else {
+            write!(f, "null")
+        }
+    }
+}
+
+impl Display for Char {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(val) = self.into_inner() {
+            write!(f, "{}", val as u8 as char)
         } else {
             write!(f, "null")
         }
@@ -128,7 +191,7 @@ macro_rules! scalar_trait_impl {
             }
 
             fn is_null(&self) -> bool {
-                self.0.is_none()
+                $struct_name::is_null(self)
             }
         }
     };
@@ -232,6 +295,7 @@ macro_rules! for_all_types {
             (String, DolphinString),
             (U8Vec, Blob),
             (NaiveDateTime, DateHour),
+            ((i32, DurationUnit), Duration),
             (Decimal, Decimal32),
             (Decimal, Decimal64),
             (Decimal, Decimal128),
@@ -240,7 +304,10 @@ macro_rules! for_all_types {
             (i32, Int),
             (i64, Long),
             (f32, Float),
-            (f64, Double)
+            (f64, Double),
+            (i128, Int128),
+            (u128, Uuid),
+            (NetAddr, IpAddr)
         );
     };
 }
@@ -274,7 +341,8 @@ macro_rules! for_all_display_types {
             (i32, Int),
             (i64, Long),
             (f32, Float),
-            (f64, Double)
+            (f64, Double),
+            (NetAddr, IpAddr)
         );
     };
 }
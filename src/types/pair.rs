@@ -9,7 +9,8 @@ use crate::{
 };
 
 use super::{
-    decimal::*, primitive::*, temporal::*, Constant, DataForm, DataType, Vector, VectorImpl,
+    decimal::*, duration::*, primitive::*, temporal::*, Constant, DataForm, DataType, Vector,
+    VectorImpl,
 };
 
 use tokio::io::AsyncBufReadExt;
@@ -104,6 +105,8 @@ pub enum PairImpl {
 
     DateHour(Pair<DateHour>),
 
+    Duration(Pair<Duration>),
+
     Decimal32(Pair<Decimal32>),
     Decimal64(Pair<Decimal64>),
     Decimal128(Pair<Decimal128>),
@@ -139,6 +142,7 @@ macro_rules! for_all_pairs {
             (Symbol, Symbol),
             (String, DolphinString),
             (DateHour, DateHour),
+            (Duration, Duration),
             (Decimal32, Decimal32),
             (Decimal64, Decimal64),
             (Decimal128, Decimal128),
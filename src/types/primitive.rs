@@ -5,6 +5,7 @@ use std::any::type_name;
 use std::{
     cmp::Ordering,
     hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
 };
 
 pub trait Primitive:
@@ -21,6 +22,8 @@ pub trait Primitive:
 
 type U8Vec = Vec<u8>;
 
+type NetAddr = std::net::IpAddr;
+
 macro_rules! integer_impl {
     ($(($raw_type:tt, $struct_name:ident, $enum_name:ident)), *) => {
         $(
@@ -33,6 +36,24 @@ macro_rules! integer_impl {
 integer_impl!(
     ((), Void, Void),
     (bool, Bool, Bool),
+    (i128, Int128, Int128),
+    (u128, Uuid, Uuid),
+    (NetAddr, IpAddr, IpAddr)
+);
+
+// Numeric scalars are stored as their native value, where DolphinDB's null sentinel (the minimum
+// value of the type) stands for null, so that vectors of them are contiguous native arrays.
+macro_rules! native_integer_impl {
+    ($(($raw_type:tt, $struct_name:ident, $enum_name:ident)), *) => {
+        $(
+            #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+            #[repr(transparent)]
+            pub struct $struct_name(pub(crate) $raw_type);
+        )*
+    };
+}
+
+native_integer_impl!(
     (i8, Char, Char),
     (i16, Short, Short),
     (i32, Int, Int),
@@ -42,8 +63,9 @@ integer_impl!(
 macro_rules! float_impl {
     ($(($raw_type:tt, $struct_name:ident, $enum_name:ident)), *) => {
         $(
-            #[derive(Default, Clone, Copy, Debug)]
-            pub struct $struct_name(pub(crate) Option<$raw_type>);
+            #[derive(Clone, Copy, Debug)]
+            #[repr(transparent)]
+            pub struct $struct_name(pub(crate) $raw_type);
         )*
     };
 }
@@ -55,7 +77,7 @@ macro_rules! eq_ord_hash_impl {
         impl PartialEq for $struct_name {
             #[inline]
             fn eq(&self, other: &$struct_name) -> bool {
-                match (self.0, other.0) {
+                match (self.into_inner(), other.into_inner()) {
                     (None, None) => true,
                     (Some(a), Some(b)) => {
                         if a.is_nan() {
@@ -99,7 +121,7 @@ macro_rules! eq_ord_hash_impl {
                 // self >= other is correct. If other is also not NaN it is trivially
                 // correct, and if it is we note that nothing can be greater or
                 // equal to NaN except NaN itself, which we already handled earlier.
-                match (self.0, other.0) {
+                match (self.into_inner(), other.into_inner()) {
                     (None, None) => true,
                     (None, Some(_)) => false,
                     (Some(_), None) => true,
@@ -122,7 +144,7 @@ macro_rules! eq_ord_hash_impl {
 
         impl Hash for $struct_name {
             fn hash<H: Hasher>(&self, state: &mut H) {
-                let bits = if let Some(f) = self.0 {
+                let bits = if let Some(f) = self.into_inner() {
                     if f.is_nan() {
                         0x7ff8000000000000u64
                     } else {
@@ -191,6 +213,126 @@ macro_rules! common_impl {
     };
 }
 
+macro_rules! native_common_impl {
+    ($raw_type:tt, $struct_name:ident, $enum_name:ident) => {
+        impl $struct_name {
+            pub const DATA_BYTE: DataType = DataType::$enum_name;
+
+            /// Native value standing for null.
+            pub const NULL_VALUE: $raw_type = $raw_type::MIN;
+
+            /// Makes a new scalar, `NULL_VALUE` makes a null scalar like DolphinDB does.
+            pub const fn new(val: $raw_type) -> Self {
+                Self(val)
+            }
+
+            pub const fn data_type() -> DataType {
+                Self::DATA_BYTE
+            }
+
+            pub const fn is_null(&self) -> bool {
+                self.0 == Self::NULL_VALUE
+            }
+
+            pub const fn into_inner(self) -> Option<$raw_type> {
+                if self.is_null() {
+                    None
+                } else {
+                    Some(self.0)
+                }
+            }
+
+            /// Returns the native value, which is `NULL_VALUE` if it is null.
+            pub const fn native(self) -> $raw_type {
+                self.0
+            }
+        }
+
+        impl Default for $struct_name {
+            fn default() -> Self {
+                Self(Self::NULL_VALUE)
+            }
+        }
+
+        // stands in for `AsRef<Option<_>>` and `AsMut<Option<_>>`, which the native storage
+        // can't lend out.
+        #[allow(clippy::should_implement_trait)]
+        impl $struct_name {
+            /// Returns the value as an `Option`, `None` if it is null.
+            ///
+            /// This replaces the former `AsRef<Option<_>>` implementation, see the
+            /// [module docs](crate::types#numeric-scalars).
+            pub fn as_ref(&self) -> OptionRef<$raw_type> {
+                OptionRef(self.into_inner())
+            }
+
+            /// Returns the value as a mutable `Option`, written back when the guard is dropped.
+            ///
+            /// This replaces the former `AsMut<Option<_>>` implementation, see the
+            /// [module docs](crate::types#numeric-scalars).
+            pub fn as_mut(&mut self) -> OptionMut<'_, $raw_type> {
+                OptionMut {
+                    value: self.into_inner(),
+                    slot: &mut self.0,
+                    null: Self::NULL_VALUE,
+                }
+            }
+
+            /// Returns the native value mutably, `NULL_VALUE` stands for null.
+            pub fn native_mut(&mut self) -> &mut $raw_type {
+                &mut self.0
+            }
+        }
+    };
+
+    ($(($raw_type:tt, $struct_name:ident, $enum_name:ident)), *) => {
+        $(
+            native_common_impl!($raw_type, $struct_name, $enum_name);
+        )*
+    };
+}
+
+/// A numeric scalar viewed as an `Option`, see [`Int::as_ref`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OptionRef<T>(Option<T>);
+
+impl<T> Deref for OptionRef<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Option<T> {
+        &self.0
+    }
+}
+
+/// A numeric scalar viewed as a mutable `Option`, see [`Int::as_mut`]. The value is written back
+/// when the guard is dropped, `None` as the null value.
+#[derive(Debug)]
+pub struct OptionMut<'a, T: Copy> {
+    slot: &'a mut T,
+    null: T,
+    value: Option<T>,
+}
+
+impl<T: Copy> Deref for OptionMut<'_, T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Option<T> {
+        &self.value
+    }
+}
+
+impl<T: Copy> DerefMut for OptionMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut Option<T> {
+        &mut self.value
+    }
+}
+
+impl<T: Copy> Drop for OptionMut<'_, T> {
+    fn drop(&mut self) {
+        *self.slot = self.value.unwrap_or(self.null);
+    }
+}
+
 macro_rules! as_ref_impl {
     ($raw_type:tt, $struct_name:ident, $enum_name:ident) => {
         impl AsRef<Option<$raw_type>> for $struct_name {
@@ -346,16 +488,51 @@ macro_rules! for_all_types {
             (f64, Double, Double),
             (String, Symbol, Symbol),
             (String, DolphinString, String),
-            (U8Vec, Blob, Blob)
+            (U8Vec, Blob, Blob),
+            (i128, Int128, Int128),
+            (u128, Uuid, Uuid),
+            (NetAddr, IpAddr, IpAddr)
+        );
+    };
+}
+
+macro_rules! for_option_types {
+    ($macro:tt) => {
+        $macro!(
+            ((), Void, Void),
+            (bool, Bool, Bool),
+            (String, Symbol, Symbol),
+            (String, DolphinString, String),
+            (U8Vec, Blob, Blob),
+            (i128, Int128, Int128),
+            (u128, Uuid, Uuid),
+            (NetAddr, IpAddr, IpAddr)
+        );
+    };
+}
+
+macro_rules! for_native_types {
+    ($macro:tt) => {
+        $macro!(
+            (i8, Char, Char),
+            (i16, Short, Short),
+            (i32, Int, Int),
+            (i64, Long, Long),
+            (f32, Float, Float),
+            (f64, Double, Double)
         );
     };
 }
 
-for_all_types!(common_impl);
+pub(crate) use for_native_types;
+
+for_option_types!(common_impl);
+
+for_native_types!(native_common_impl);
 
-for_all_types!(as_ref_impl);
+for_option_types!(as_ref_impl);
 
-for_all_types!(as_mut_impl);
+for_option_types!(as_mut_impl);
 
 for_all_types!(from_raw_impl);
 
@@ -1,11 +1,15 @@
+mod cast;
 mod decimal;
 mod deserialize;
+mod duration;
+mod int128;
+mod parse;
 mod serialize;
 mod temporal;
 
 use super::{
-    decimal::*, primitive::*, temporal::*, Any, Constant, ConstantImpl, DataForm, DataType, Vector,
-    VectorImpl,
+    decimal::*, duration::*, primitive::*, temporal::*, Any, Constant, ConstantImpl, DataForm,
+    DataType, Vector, VectorImpl,
 };
 
 use crate::{
@@ -22,6 +26,7 @@ use tokio::io::AsyncBufReadExt;
 use paste::paste;
 
 pub use decimal::DecimalInterface;
+pub(crate) use deserialize::{read_fixed_width, read_literals, FixedWidth};
 
 pub trait Scalar: Send + Sync + Clone + Debug + Default + PartialEq + PartialOrd + Hash {
     fn data_type() -> DataType;
@@ -56,11 +61,17 @@ pub enum ScalarImpl {
 
     DateHour(DateHour),
 
+    Duration(Duration),
+
     Blob(Blob),
 
     Decimal32(Decimal32),
     Decimal64(Decimal64),
     Decimal128(Decimal128),
+
+    Int128(Int128),
+    Uuid(Uuid),
+    IpAddr(IpAddr),
 }
 
 impl Default for ScalarImpl {
@@ -172,6 +183,25 @@ macro_rules! dispatch_reflect {
     };
 }
 
+macro_rules! dispatch_parse {
+    ($(($enum_name:ident, $struct_name:ident)),*) => {
+        impl ScalarImpl {
+            /// Parses a DolphinDB literal of `data_type`, in the format [`Display`] emits.
+            pub fn parse(data_type: DataType, s: &str) -> Result<Self> {
+                match data_type {
+                    $(
+                        $struct_name::DATA_BYTE => s.parse().map(Self::$enum_name),
+                    )*
+                    _ => Err(Error::Unsupported {
+                        data_form: DataForm::Scalar.to_string(),
+                        data_type: data_type.to_string(),
+                    }),
+                }
+            }
+        }
+    };
+}
+
 macro_rules! dispatch_is_null {
     ($(($enum_name:ident, $struct_name:ident)),*) => {
         impl ScalarImpl {
@@ -235,7 +265,7 @@ macro_rules! dispatch_get {
                     $(
                         VectorImpl::$enum_name(val) => Some(val[index].to_owned().into()),
                     )*
-                    VectorImpl::ArrayVector(_v) => None, // Unsupported now
+                    VectorImpl::ArrayVector(v) => v.get(index).map(Into::into),
                     VectorImpl::Any(v) => Some(v[index].0.clone())
                 }
             }
@@ -290,6 +320,7 @@ macro_rules! for_all_scalars {
             (Symbol, Symbol),
             (String, DolphinString),
             (DateHour, DateHour),
+            (Duration, Duration),
             (Decimal32, Decimal32),
             (Decimal64, Decimal64),
             (Decimal128, Decimal128),
@@ -299,7 +330,10 @@ macro_rules! for_all_scalars {
             (Long, Long),
             (Float, Float),
             (Double, Double),
-            (Blob, Blob)
+            (Blob, Blob),
+            (Int128, Int128),
+            (Uuid, Uuid),
+            (IpAddr, IpAddr)
         );
     };
 }
@@ -314,6 +348,8 @@ for_all_scalars!(dispatch_display);
 
 for_all_scalars!(dispatch_reflect);
 
+for_all_scalars!(dispatch_parse);
+
 for_all_scalars!(dispatch_is_null);
 
 for_all_scalars!(dispatch_push_vector);
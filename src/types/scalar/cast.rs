@@ -0,0 +1,354 @@
+//! Conversions between DolphinDB types mirroring the server side `cast`.
+
+use chrono::{
+    naive::{NaiveDateTime, NaiveTime},
+    Datelike, Timelike,
+};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal, RoundingStrategy,
+};
+use std::str::FromStr;
+
+use super::super::*;
+use crate::error::{Error, Result};
+
+// numeric value of a scalar.
+enum Numeric {
+    Integral(i128),
+    Floating(f64),
+    Decimal(Decimal),
+}
+
+// a point in time, or a time of day.
+enum Moment {
+    DateTime(NaiveDateTime),
+    Time(NaiveTime),
+}
+
+impl ScalarImpl {
+    /// Converts to `data_type` following DolphinDB `cast`. Null converts to null.
+    ///
+    /// * floating point values round to the nearest integer, decimals and strings truncate.
+    /// * values out of the range of the target type become null.
+    /// * temporal values change granularity, e.g. [`Timestamp`] to [`Date`] drops the time and
+    ///   [`Date`] to [`Timestamp`] is midnight. Integers are read as the elapsed units DolphinDB
+    ///   stores.
+    /// * every type converts to [`DolphinString`] and [`Symbol`] as it displays, and strings
+    ///   convert back as in [`ScalarImpl::parse`].
+    ///
+    /// Decimal targets keep the scale of a decimal source and use scale 0 otherwise, see
+    /// [`cast_decimal`](Self::cast_decimal) for an explicit scale.
+    pub fn cast(&self, data_type: DataType) -> Result<ScalarImpl> {
+        let scale = match self {
+            ScalarImpl::Decimal32(d) => d.scale(),
+            ScalarImpl::Decimal64(d) => d.scale(),
+            ScalarImpl::Decimal128(d) => d.scale(),
+            _ => None,
+        };
+
+        self.cast_with_scale(data_type, scale.unwrap_or(0))
+    }
+
+    /// Converts to the decimal type `data_type` with `scale`, rounding half away from zero.
+    pub fn cast_decimal(&self, data_type: DataType, scale: u32) -> Result<ScalarImpl> {
+        let max_scale = match data_type {
+            DataType::Decimal32 => 9,
+            DataType::Decimal64 => 18,
+            DataType::Decimal128 => 28,
+            _ => return Err(self.invalid_cast(data_type)),
+        };
+
+        if scale > max_scale {
+            return Err(Error::ConstraintsViolated(format!(
+                "scale of {} must be in [0, {}], got {}",
+                data_type, max_scale, scale
+            )));
+        }
+
+        self.cast_with_scale(data_type, scale)
+    }
+
+    fn cast_with_scale(&self, data_type: DataType, scale: u32) -> Result<ScalarImpl> {
+        let null = ScalarImpl::from_type(data_type).ok_or(self.invalid_cast(data_type))?;
+
+        if self.is_null() {
+            return Ok(null);
+        }
+
+        // strings convert to the other types as literals, invalid ones become null.
+        if let ScalarImpl::String(DolphinString(Some(s))) | ScalarImpl::Symbol(Symbol(Some(s))) =
+            self
+        {
+            if !is_numeric(data_type)
+                && !matches!(
+                    data_type,
+                    DataType::String | DataType::Symbol | DataType::Blob
+                )
+            {
+                return Ok(ScalarImpl::parse(data_type, s).unwrap_or(null));
+            }
+        }
+
+        let res = match data_type {
+            DataType::String => Some(ScalarImpl::String(DolphinString::new(self.to_string()))),
+            DataType::Symbol => Some(ScalarImpl::Symbol(Symbol::new(self.to_string()))),
+            DataType::Blob => match self {
+                ScalarImpl::String(s) => s.clone().into_inner().map(|s| s.into_bytes()),
+                ScalarImpl::Symbol(s) => s.clone().into_inner().map(|s| s.into_bytes()),
+                ScalarImpl::Blob(b) => b.clone().into_inner(),
+                _ => return Err(self.invalid_cast(data_type)),
+            }
+            .map(|b| ScalarImpl::Blob(Blob::new(b))),
+            _ if is_numeric(data_type) => {
+                let numeric = self.numeric().ok_or(self.invalid_cast(data_type))?;
+                numeric.into_scalar(data_type, scale)
+            }
+            DataType::Date
+            | DataType::Month
+            | DataType::Time
+            | DataType::Minute
+            | DataType::Second
+            | DataType::DateTime
+            | DataType::Timestamp
+            | DataType::NanoTime
+            | DataType::NanoTimestamp
+            | DataType::DateHour => {
+                if let Some(moment) = self.moment() {
+                    // a time of day has no date.
+                    if matches!(moment, Moment::Time(_)) && !is_time_of_day(data_type) {
+                        return Err(self.invalid_cast(data_type));
+                    }
+
+                    moment.into_scalar(data_type)
+                } else if let Some(Numeric::Integral(raw)) = self.numeric() {
+                    from_elapsed(data_type, raw)
+                } else {
+                    return Err(self.invalid_cast(data_type));
+                }
+            }
+            _ if self.data_type() == data_type => Some(self.clone()),
+            _ => return Err(self.invalid_cast(data_type)),
+        };
+
+        Ok(res.unwrap_or(null))
+    }
+
+    fn invalid_cast(&self, data_type: DataType) -> Error {
+        Error::InvalidConvert {
+            from: self.data_type().to_string(),
+            to: data_type.to_string(),
+        }
+    }
+
+    // must not be null.
+    fn numeric(&self) -> Option<Numeric> {
+        let integral = |v: i64| Some(Numeric::Integral(v as i128));
+
+        match self {
+            ScalarImpl::Bool(v) => integral(v.into_inner()? as i64),
+            ScalarImpl::Char(v) => integral(v.into_inner()? as i64),
+            ScalarImpl::Short(v) => integral(v.into_inner()? as i64),
+            ScalarImpl::Int(v) => integral(v.into_inner()? as i64),
+            ScalarImpl::Long(v) => integral(v.into_inner()?),
+            ScalarImpl::Float(v) => Some(Numeric::Floating(v.into_inner()? as f64)),
+            ScalarImpl::Double(v) => Some(Numeric::Floating(v.into_inner()?)),
+            ScalarImpl::Decimal32(v) => Some(Numeric::Decimal(v.into_inner()?)),
+            ScalarImpl::Decimal64(v) => Some(Numeric::Decimal(v.into_inner()?)),
+            ScalarImpl::Decimal128(v) => Some(Numeric::Decimal(v.into_inner()?)),
+            ScalarImpl::Date(v) => integral(v.ddb_rep() as i64),
+            ScalarImpl::Month(v) => integral(v.ddb_rep() as i64),
+            ScalarImpl::Time(v) => integral(v.ddb_rep() as i64),
+            ScalarImpl::Minute(v) => integral(v.ddb_rep() as i64),
+            ScalarImpl::Second(v) => integral(v.ddb_rep() as i64),
+            ScalarImpl::DateTime(v) => integral(v.ddb_rep() as i64),
+            ScalarImpl::Timestamp(v) => integral(v.ddb_rep()),
+            ScalarImpl::NanoTime(v) => integral(v.ddb_rep()),
+            ScalarImpl::NanoTimestamp(v) => integral(v.ddb_rep()),
+            ScalarImpl::DateHour(v) => integral(v.ddb_rep() as i64),
+            ScalarImpl::String(v) => parse_numeric(v.as_ref().as_deref()?),
+            ScalarImpl::Symbol(v) => parse_numeric(v.as_ref().as_deref()?),
+            _ => None,
+        }
+    }
+
+    fn moment(&self) -> Option<Moment> {
+        let date_time = |d: chrono::NaiveDate| Some(Moment::DateTime(d.and_time(NaiveTime::MIN)));
+
+        match self {
+            ScalarImpl::Date(v) => date_time(v.into_inner()?),
+            ScalarImpl::Month(v) => date_time(v.into_inner()?),
+            ScalarImpl::DateTime(v) => Some(Moment::DateTime(v.into_inner()?)),
+            ScalarImpl::Timestamp(v) => Some(Moment::DateTime(v.into_inner()?)),
+            ScalarImpl::NanoTimestamp(v) => Some(Moment::DateTime(v.into_inner()?)),
+            ScalarImpl::DateHour(v) => Some(Moment::DateTime(v.into_inner()?)),
+            ScalarImpl::Time(v) => Some(Moment::Time(v.into_inner()?)),
+            ScalarImpl::Minute(v) => Some(Moment::Time(v.into_inner()?)),
+            ScalarImpl::Second(v) => Some(Moment::Time(v.into_inner()?)),
+            ScalarImpl::NanoTime(v) => Some(Moment::Time(v.into_inner()?)),
+            _ => None,
+        }
+    }
+}
+
+// strings truncate like decimals, e.g. "3.7" is 3 as INT.
+fn parse_numeric(s: &str) -> Option<Numeric> {
+    let s = s.trim();
+
+    if let Ok(v) = s.parse::<i128>() {
+        Some(Numeric::Integral(v))
+    } else if let Ok(v) = Decimal::from_str(s) {
+        Some(Numeric::Decimal(v))
+    } else {
+        s.parse::<f64>().ok().map(Numeric::Floating)
+    }
+}
+
+fn is_numeric(data_type: DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Bool
+            | DataType::Char
+            | DataType::Short
+            | DataType::Int
+            | DataType::Long
+            | DataType::Float
+            | DataType::Double
+            | DataType::Decimal32
+            | DataType::Decimal64
+            | DataType::Decimal128
+    )
+}
+
+fn is_time_of_day(data_type: DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Time | DataType::Minute | DataType::Second | DataType::NanoTime
+    )
+}
+
+// `None` means null, i.e. out of range.
+fn from_elapsed(data_type: DataType, raw: i128) -> Option<ScalarImpl> {
+    let raw = i64::try_from(raw).ok()?;
+
+    match data_type {
+        DataType::Date => Date::from_raw(raw).map(ScalarImpl::Date),
+        DataType::Month => Month::from_raw(i32::try_from(raw - 23640).ok()?).map(ScalarImpl::Month),
+        DataType::Time => Time::from_raw(u32::try_from(raw).ok()?).map(ScalarImpl::Time),
+        DataType::Minute => Minute::from_raw(u32::try_from(raw).ok()?).map(ScalarImpl::Minute),
+        DataType::Second => Second::from_raw(u32::try_from(raw).ok()?).map(ScalarImpl::Second),
+        DataType::DateTime => {
+            DateTime::from_raw(i32::try_from(raw).ok()?).map(ScalarImpl::DateTime)
+        }
+        DataType::Timestamp => Timestamp::from_raw(raw).map(ScalarImpl::Timestamp),
+        DataType::NanoTime => {
+            NanoTime::from_raw(u64::try_from(raw).ok()?).map(ScalarImpl::NanoTime)
+        }
+        DataType::NanoTimestamp => NanoTimestamp::from_raw(raw).map(ScalarImpl::NanoTimestamp),
+        DataType::DateHour => DateHour::from_raw(raw).map(ScalarImpl::DateHour),
+        _ => None,
+    }
+}
+
+impl Numeric {
+    fn into_scalar(self, data_type: DataType, scale: u32) -> Option<ScalarImpl> {
+        macro_rules! integral {
+            ($struct_name:ident, $raw_type:tt) => {{
+                let v = match self {
+                    Numeric::Integral(v) => v,
+                    Numeric::Floating(v) if v.is_finite() => v.round() as i128,
+                    Numeric::Floating(_) => return None,
+                    Numeric::Decimal(v) => v.trunc().to_i128()?,
+                };
+                // the minimum is the null sentinel.
+                match $raw_type::try_from(v) {
+                    Ok(v) if v != $raw_type::MIN => {
+                        Some(ScalarImpl::$struct_name($struct_name::new(v)))
+                    }
+                    _ => None,
+                }
+            }};
+        }
+
+        macro_rules! decimal {
+            ($struct_name:ident, $raw_type:tt, $max_scale:expr) => {{
+                if scale > $max_scale {
+                    return None;
+                }
+
+                let mut v = match self {
+                    Numeric::Integral(v) => Decimal::from_i128_with_scale(v, 0),
+                    Numeric::Floating(v) => Decimal::from_f64(v)?,
+                    Numeric::Decimal(v) => v,
+                };
+                v = v.round_dp_with_strategy(scale, RoundingStrategy::MidpointAwayFromZero);
+                v.rescale(scale);
+
+                // rescale saturates when the mantissa doesn't fit.
+                if v.scale() != scale || $raw_type::try_from(v.mantissa()).is_err() {
+                    return None;
+                }
+
+                Some(ScalarImpl::$struct_name($struct_name(Some(v))))
+            }};
+        }
+
+        let floating = match self {
+            Numeric::Integral(v) => v as f64,
+            Numeric::Floating(v) => v,
+            Numeric::Decimal(v) => v.to_f64()?,
+        };
+
+        match data_type {
+            DataType::Bool => Some(ScalarImpl::Bool(Bool::new(floating != 0.0))),
+            DataType::Char => integral!(Char, i8),
+            DataType::Short => integral!(Short, i16),
+            DataType::Int => integral!(Int, i32),
+            DataType::Long => integral!(Long, i64),
+            DataType::Float => Some(ScalarImpl::Float(Float::new(floating as f32))),
+            DataType::Double => Some(ScalarImpl::Double(Double::new(floating))),
+            DataType::Decimal32 => decimal!(Decimal32, i32, 9),
+            DataType::Decimal64 => decimal!(Decimal64, i64, 18),
+            DataType::Decimal128 => decimal!(Decimal128, i128, 28),
+            _ => None,
+        }
+    }
+}
+
+impl Moment {
+    fn into_scalar(self, data_type: DataType) -> Option<ScalarImpl> {
+        let time = match self {
+            Moment::DateTime(dt) => dt.time(),
+            Moment::Time(t) => t,
+        };
+        let seconds = time.num_seconds_from_midnight();
+        let nanos = seconds as u64 * 1_000_000_000 + time.nanosecond() as u64;
+
+        match (data_type, self) {
+            (DataType::Time, _) => Time::from_raw((nanos / 1_000_000) as u32).map(ScalarImpl::Time),
+            (DataType::Minute, _) => Minute::from_raw(seconds / 60).map(ScalarImpl::Minute),
+            (DataType::Second, _) => Second::from_raw(seconds).map(ScalarImpl::Second),
+            (DataType::NanoTime, _) => NanoTime::from_raw(nanos).map(ScalarImpl::NanoTime),
+            (DataType::Date, Moment::DateTime(dt)) => Some(ScalarImpl::Date(dt.date().into())),
+            (DataType::Month, Moment::DateTime(dt)) => {
+                Month::from_ym(dt.year(), dt.month()).map(ScalarImpl::Month)
+            }
+            (DataType::DateTime, Moment::DateTime(dt)) => {
+                DateTime::from_raw(i32::try_from(dt.and_utc().timestamp()).ok()?)
+                    .map(ScalarImpl::DateTime)
+            }
+            (DataType::Timestamp, Moment::DateTime(dt)) => {
+                Timestamp::from_raw(dt.and_utc().timestamp_millis()).map(ScalarImpl::Timestamp)
+            }
+            (DataType::NanoTimestamp, Moment::DateTime(dt)) => {
+                NanoTimestamp::from_raw(dt.and_utc().timestamp_nanos_opt()?)
+                    .map(ScalarImpl::NanoTimestamp)
+            }
+            (DataType::DateHour, Moment::DateTime(dt)) => {
+                DateHour::from_raw(dt.and_utc().timestamp().div_euclid(3600))
+                    .map(ScalarImpl::DateHour)
+            }
+            _ => None,
+        }
+    }
+}
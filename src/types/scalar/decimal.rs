@@ -1,16 +1,19 @@
 //! Decimal type interface compatible to [`rust_decimal`]
 
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::FromPrimitive, Decimal, RoundingStrategy};
 
 use super::{
     super::{Decimal128, Decimal32, Decimal64},
     Scalar,
 };
+use crate::error::{Error, Result};
 
 /// Helper interface for `Vector`.
-pub trait DecimalInterface: Scalar {
+pub trait DecimalInterface: Scalar + Copy {
     type LiteralValue;
 
+    const MAX_SCALE: u32;
+
     fn from_raw(num: Self::LiteralValue, scale: u32) -> Option<Self>;
 
     fn scale(&self) -> Option<u32>;
@@ -18,6 +21,8 @@ pub trait DecimalInterface: Scalar {
     fn mantissa(&self) -> Option<Self::LiteralValue>;
 
     fn rescale(&mut self, scale: u32);
+
+    fn round(&self, scale: u32, mode: RoundingStrategy) -> Result<Self>;
 }
 
 impl Decimal32 {
@@ -118,6 +123,8 @@ impl Decimal128 {
 impl DecimalInterface for Decimal32 {
     type LiteralValue = i32;
 
+    const MAX_SCALE: u32 = Self::MAX_SCALE;
+
     fn from_raw(num: Self::LiteralValue, scale: u32) -> Option<Self> {
         Self::from_raw(num, scale)
     }
@@ -133,11 +140,17 @@ impl DecimalInterface for Decimal32 {
     fn rescale(&mut self, scale: u32) {
         self.rescale(scale)
     }
+
+    fn round(&self, scale: u32, mode: RoundingStrategy) -> Result<Self> {
+        self.round(scale, mode)
+    }
 }
 
 impl DecimalInterface for Decimal64 {
     type LiteralValue = i64;
 
+    const MAX_SCALE: u32 = Self::MAX_SCALE;
+
     fn from_raw(num: Self::LiteralValue, scale: u32) -> Option<Self> {
         Self::from_raw(num, scale)
     }
@@ -153,11 +166,17 @@ impl DecimalInterface for Decimal64 {
     fn rescale(&mut self, scale: u32) {
         self.rescale(scale)
     }
+
+    fn round(&self, scale: u32, mode: RoundingStrategy) -> Result<Self> {
+        self.round(scale, mode)
+    }
 }
 
 impl DecimalInterface for Decimal128 {
     type LiteralValue = i128;
 
+    const MAX_SCALE: u32 = Self::MAX_SCALE;
+
     fn from_raw(num: Self::LiteralValue, scale: u32) -> Option<Self> {
         Self::from_raw(num, scale)
     }
@@ -173,4 +192,187 @@ impl DecimalInterface for Decimal128 {
     fn rescale(&mut self, scale: u32) {
         self.rescale(scale)
     }
+
+    fn round(&self, scale: u32, mode: RoundingStrategy) -> Result<Self> {
+        self.round(scale, mode)
+    }
 }
+
+macro_rules! decimal_arithmetic_impl {
+    ($struct_name:ident, $raw_type:tt, $max_scale:expr) => {
+        impl $struct_name {
+            /// The largest scale the type can hold.
+            pub const MAX_SCALE: u32 = $max_scale;
+
+            /// Makes a new decimal from `value` rounded to `scale` with `mode`.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if `value` is not finite or can't be represented at `scale`.
+            pub fn from_f64(value: f64, scale: u32, mode: RoundingStrategy) -> Result<Self> {
+                let d = Decimal::from_f64(value).ok_or_else(|| {
+                    Error::ConstraintsViolated(format!(
+                        "{} cannot be represented as {}",
+                        value,
+                        Self::DATA_BYTE
+                    ))
+                })?;
+                Self::represent(d, scale, mode)
+            }
+
+            /// Rounds to `scale` with `mode`, a larger `scale` pads zeros. Null stays null.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the value can't be represented at `scale`.
+            pub fn round(&self, scale: u32, mode: RoundingStrategy) -> Result<Self> {
+                match self.0 {
+                    None => Ok(Self::default()),
+                    Some(d) => Self::represent(d, scale, mode),
+                }
+            }
+
+            /// Like [`rescale`](Self::rescale), but fails instead of dropping digits or
+            /// overflowing.
+            pub fn checked_rescale(&mut self, scale: u32) -> Result<()> {
+                if let Some(d) = self.0 {
+                    if d.round_dp(scale) != d {
+                        return Err(self.overflow(scale));
+                    }
+                    *self = Self::represent(d, scale, RoundingStrategy::ToZero)?;
+                }
+                Ok(())
+            }
+
+            /// Adds at the larger scale of both operands. Null if any operand is null.
+            pub fn checked_add(&self, rhs: &Self) -> Result<Self> {
+                self.binary(rhs, None, RoundingStrategy::ToZero, Decimal::checked_add)
+            }
+
+            /// Subtracts at the larger scale of both operands. Null if any operand is null.
+            pub fn checked_sub(&self, rhs: &Self) -> Result<Self> {
+                self.binary(rhs, None, RoundingStrategy::ToZero, Decimal::checked_sub)
+            }
+
+            /// Multiplies and rounds the product to `scale` with `mode`.
+            /// Null if any operand is null.
+            pub fn checked_mul(
+                &self,
+                rhs: &Self,
+                scale: u32,
+                mode: RoundingStrategy,
+            ) -> Result<Self> {
+                self.binary(rhs, Some(scale), mode, Decimal::checked_mul)
+            }
+
+            /// Divides and rounds the quotient to `scale` with `mode`.
+            /// Null if any operand is null, an error if `rhs` is zero.
+            pub fn checked_div(
+                &self,
+                rhs: &Self,
+                scale: u32,
+                mode: RoundingStrategy,
+            ) -> Result<Self> {
+                if rhs.0.is_some_and(|d| d.is_zero()) {
+                    return Err(Error::ConstraintsViolated(
+                        "decimal division by zero".into(),
+                    ));
+                }
+                self.binary(rhs, Some(scale), mode, Decimal::checked_div)
+            }
+
+            /// Mantissa at `scale` as written on the wire, null is the minimum value.
+            pub(crate) fn wire_mantissa(&self, scale: u32) -> Result<$raw_type> {
+                let mut d = *self;
+                d.checked_rescale(scale)?;
+                Ok(d.mantissa().unwrap_or($raw_type::MIN))
+            }
+
+            fn binary(
+                &self,
+                rhs: &Self,
+                scale: Option<u32>,
+                mode: RoundingStrategy,
+                op: impl Fn(Decimal, Decimal) -> Option<Decimal>,
+            ) -> Result<Self> {
+                let (Some(a), Some(b)) = (self.0, rhs.0) else {
+                    return Ok(Self::default());
+                };
+                let scale = scale.unwrap_or(a.scale().max(b.scale()));
+                let d = op(a, b).ok_or_else(|| self.overflow(scale))?;
+                Self::represent(d, scale, mode)
+            }
+
+            fn represent(d: Decimal, scale: u32, mode: RoundingStrategy) -> Result<Self> {
+                let overflow = || {
+                    Error::ConstraintsViolated(format!(
+                        "{} cannot be represented as {} with scale {}",
+                        d,
+                        Self::DATA_BYTE,
+                        scale
+                    ))
+                };
+                if scale > $max_scale {
+                    return Err(overflow());
+                }
+
+                let mut res = d.round_dp_with_strategy(scale, mode);
+                res.rescale(scale);
+
+                // rescale saturates when the mantissa doesn't fit.
+                if res.scale() != scale || $raw_type::try_from(res.mantissa()).is_err() {
+                    return Err(overflow());
+                }
+                Ok(Self(Some(res)))
+            }
+
+            fn overflow(&self, scale: u32) -> Error {
+                Error::ConstraintsViolated(format!(
+                    "{} cannot be represented as {} with scale {}",
+                    self,
+                    Self::DATA_BYTE,
+                    scale
+                ))
+            }
+        }
+
+        impl TryFrom<Decimal> for $struct_name {
+            type Error = Error;
+
+            /// Exact conversion keeping the scale of `value`.
+            fn try_from(value: Decimal) -> Result<Self> {
+                Self::represent(value, value.scale(), RoundingStrategy::ToZero)
+            }
+        }
+    };
+}
+
+decimal_arithmetic_impl!(Decimal32, i32, 9);
+decimal_arithmetic_impl!(Decimal64, i64, 18);
+decimal_arithmetic_impl!(Decimal128, i128, 28);
+
+macro_rules! decimal_widen_impl {
+    ($narrow:ident, $wide:ident) => {
+        impl From<$narrow> for $wide {
+            fn from(value: $narrow) -> Self {
+                Self(value.0)
+            }
+        }
+
+        impl TryFrom<$wide> for $narrow {
+            type Error = Error;
+
+            /// Fails if the value overflows the narrower type.
+            fn try_from(value: $wide) -> Result<Self> {
+                match value.0 {
+                    None => Ok(Self::default()),
+                    Some(d) => d.try_into(),
+                }
+            }
+        }
+    };
+}
+
+decimal_widen_impl!(Decimal32, Decimal64);
+decimal_widen_impl!(Decimal32, Decimal128);
+decimal_widen_impl!(Decimal64, Decimal128);
@@ -1,10 +1,11 @@
 use crate::{
+    codec::check_len,
     error::{Error, Result},
     Deserialize,
 };
 use tokio::io::{AsyncBufReadExt, AsyncReadExt};
 
-use crate::types::{any::Any, decimal::*, primitive::*, temporal::*};
+use crate::types::{any::Any, decimal::*, duration::*, primitive::*, temporal::*};
 
 impl Deserialize for Void {
     async fn deserialize<R>(&mut self, reader: &mut R) -> Result<()>
@@ -38,13 +39,8 @@ impl Deserialize for Char {
     where
         R: AsyncBufReadExt + Unpin,
     {
-        let val = reader.read_i8().await?;
-
-        if val == i8::MIN {
-            self.0 = None;
-        } else {
-            self.0 = Some(val as i8);
-        }
+        // null is kept as the sentinel.
+        self.0 = reader.read_i8().await?;
 
         Ok(())
     }
@@ -98,13 +94,8 @@ macro_rules! deserialize_primitive {
         where
             R: AsyncBufReadExt + Unpin,
         {
-            let val = reader.$read_func().await?;
-
-            if val == $raw_type::MIN {
-                self.0 = None;
-            } else {
-                self.0 = Some(val.into());
-            }
+            // null is kept as the sentinel.
+            self.0 = reader.$read_func().await?;
 
             Ok(())
         }
@@ -137,7 +128,7 @@ macro_rules! deserialize_i32_temporal {
             let mut int = Int::default();
             int.$func_name(reader).await?;
 
-            if let Some(elapsed) = int.as_ref().map(|v| $elapsed_type::try_from(v).ok()).flatten() {
+            if let Some(elapsed) = int.into_inner().and_then(|v| $elapsed_type::try_from(v).ok()) {
                 *self = Self::from_raw(elapsed - $offset)
                         .ok_or(Error::ConstraintsViolated(
                             "time elapsed out of bound".into(),
@@ -169,7 +160,7 @@ macro_rules! deserialize_i64_temporal {
             let mut long = Long::default();
             long.$func_name(reader).await?;
 
-            if let Some(elapsed) = long.as_ref().map(|v| $elapsed_type::try_from(v).ok()).flatten() {
+            if let Some(elapsed) = long.into_inner().and_then(|v| $elapsed_type::try_from(v).ok()) {
                 *self = Self::from_raw(elapsed)
                         .ok_or(Error::ConstraintsViolated(
                             "time elapsed out of bound".into(),
@@ -239,6 +230,29 @@ deserialize_decimal!(
     (i128, Decimal128, read_i128, read_i128_le)
 );
 
+macro_rules! deserialize_128bit {
+    ($read_func:ident, $func_name:ident) => {
+        async fn $func_name<R>(&mut self, reader: &mut R) -> Result<()>
+        where
+            R: AsyncBufReadExt + Unpin,
+        {
+            *self = Self::from_ddb_rep(reader.$read_func().await?);
+            Ok(())
+        }
+    };
+
+    ($($struct_name:ident), *) => {
+        $(
+            impl Deserialize for $struct_name {
+                deserialize_128bit!(read_u128, deserialize);
+                deserialize_128bit!(read_u128_le, deserialize_le);
+            }
+        )*
+    };
+}
+
+deserialize_128bit!(Int128, Uuid, IpAddr);
+
 impl Deserialize for Any {
     async fn deserialize<R>(&mut self, reader: &mut R) -> Result<()>
     where
@@ -261,6 +275,7 @@ impl Deserialize for Blob {
         R: AsyncBufReadExt + Unpin,
     {
         let len = reader.read_u32().await?;
+        check_len(len as usize)?;
         let mut data = vec![0u8; len as usize];
         reader.read_exact(&mut data).await?;
 
@@ -278,6 +293,7 @@ impl Deserialize for Blob {
         R: AsyncBufReadExt + Unpin,
     {
         let len = reader.read_u32_le().await?;
+        check_len(len as usize)?;
         let mut data = vec![0u8; len as usize];
         reader.read_exact(&mut data).await?;
 
@@ -290,3 +306,274 @@ impl Deserialize for Blob {
         Ok(())
     }
 }
+
+macro_rules! deserialize_duration {
+    ($read_func:ident, $func_name:ident) => {
+        async fn $func_name<R>(&mut self, reader: &mut R) -> Result<()>
+        where
+            R: AsyncBufReadExt + Unpin,
+        {
+            let length = reader.$read_func().await?;
+            let unit = reader.$read_func().await?;
+
+            *self = Self::from_parts(length, unit)?;
+
+            Ok(())
+        }
+    };
+}
+
+impl Deserialize for Duration {
+    deserialize_duration!(read_i32, deserialize);
+    deserialize_duration!(read_i32_le, deserialize_le);
+}
+
+/// Scalars encoded in a fixed number of bytes, which vectors decode in bulk.
+pub(crate) trait FixedWidth: Sized {
+    const WIDTH: usize;
+
+    fn from_be_slice(bytes: &[u8]) -> Result<Self>;
+
+    fn from_le_slice(bytes: &[u8]) -> Result<Self>;
+}
+
+// slices handed to `FixedWidth` are cut by `chunks_exact`, the length always matches.
+macro_rules! to_array {
+    ($bytes:expr) => {
+        $bytes.try_into().map_err(|_| Error::UnexpectedEof)?
+    };
+}
+
+macro_rules! fixed_width_impl {
+    ($struct_name:ident, $width:expr, |$raw:ident: $raw_type:tt| $convert:expr) => {
+        impl FixedWidth for $struct_name {
+            const WIDTH: usize = $width;
+
+            fn from_be_slice(bytes: &[u8]) -> Result<Self> {
+                let $raw = $raw_type::from_be_bytes(to_array!(bytes));
+                $convert
+            }
+
+            fn from_le_slice(bytes: &[u8]) -> Result<Self> {
+                let $raw = $raw_type::from_le_bytes(to_array!(bytes));
+                $convert
+            }
+        }
+    };
+}
+
+fixed_width_impl!(Void, 1, |_raw: i8| Ok(Void::default()));
+
+fixed_width_impl!(Bool, 1, |raw: i8| Ok(match raw {
+    0 => Bool(Some(false)),
+    i8::MIN => Bool(None),
+    _ => Bool(Some(true)),
+}));
+
+macro_rules! fixed_width_native {
+    ($(($raw_type:tt, $struct_name:ident)), *) => {
+        $(
+            // null is kept as the sentinel.
+            fixed_width_impl!($struct_name, size_of::<$raw_type>(), |raw: $raw_type| Ok($struct_name(raw)));
+        )*
+    };
+}
+
+fixed_width_native!(
+    (i8, Char),
+    (i16, Short),
+    (i32, Int),
+    (i64, Long),
+    (f32, Float),
+    (f64, Double)
+);
+
+macro_rules! fixed_width_temporal {
+    ($(($struct_name:ident, $raw_type:tt, $elapsed_type:tt, $offset:expr)), *) => {
+        $(
+            fixed_width_impl!($struct_name, size_of::<$raw_type>(), |raw: $raw_type| {
+                if raw == $raw_type::MIN {
+                    return Ok($struct_name(None));
+                }
+
+                match $elapsed_type::try_from(raw) {
+                    Ok(elapsed) => $struct_name::from_raw(elapsed - $offset).ok_or(
+                        Error::ConstraintsViolated("time elapsed out of bound".into()),
+                    ),
+                    Err(_) => Ok($struct_name(None)),
+                }
+            });
+        )*
+    };
+}
+
+fixed_width_temporal!(
+    (Date, i32, i64, 0),
+    (Month, i32, i32, 23640),
+    (Time, i32, u32, 0),
+    (Minute, i32, u32, 0),
+    (Second, i32, u32, 0),
+    (DateTime, i32, i32, 0),
+    (DateHour, i32, i64, 0),
+    (Timestamp, i64, i64, 0),
+    (NanoTime, i64, u64, 0),
+    (NanoTimestamp, i64, i64, 0)
+);
+
+macro_rules! fixed_width_128bit {
+    ($($struct_name:ident), *) => {
+        $(
+            fixed_width_impl!($struct_name, 16, |raw: u128| Ok($struct_name::from_ddb_rep(raw)));
+        )*
+    };
+}
+
+fixed_width_128bit!(Int128, Uuid, IpAddr);
+
+impl FixedWidth for Duration {
+    const WIDTH: usize = 8;
+
+    fn from_be_slice(bytes: &[u8]) -> Result<Self> {
+        Self::from_parts(
+            i32::from_be_bytes(to_array!(&bytes[..4])),
+            i32::from_be_bytes(to_array!(&bytes[4..])),
+        )
+    }
+
+    fn from_le_slice(bytes: &[u8]) -> Result<Self> {
+        Self::from_parts(
+            i32::from_le_bytes(to_array!(&bytes[..4])),
+            i32::from_le_bytes(to_array!(&bytes[4..])),
+        )
+    }
+}
+
+impl Duration {
+    fn from_parts(length: i32, unit: i32) -> Result<Self> {
+        if length == i32::MIN {
+            return Ok(Self(None));
+        }
+
+        let unit = DurationUnit::from_ddb_rep(unit).ok_or(Error::InvalidData {
+            expect: "duration unit".into(),
+            actual: unit.to_string(),
+        })?;
+
+        Ok(Self(Some((length, unit))))
+    }
+}
+
+/// Fills `slots` with values of `width` bytes each, decoding every chunk the reader has buffered
+/// synchronously instead of awaiting one read per value.
+pub(crate) async fn read_fixed_width<R, T, F>(
+    reader: &mut R,
+    slots: &mut [T],
+    width: usize,
+    mut decode: F,
+) -> Result<()>
+where
+    R: AsyncBufReadExt + Unpin,
+    F: FnMut(&[u8]) -> Result<T>,
+{
+    let mut carry = vec![0u8; width];
+    let mut decoded = 0;
+
+    while decoded < slots.len() {
+        let buf = reader.fill_buf().await?;
+
+        if buf.is_empty() {
+            return Err(Error::UnexpectedEof);
+        }
+
+        let n = (buf.len() / width).min(slots.len() - decoded);
+
+        if n == 0 {
+            // the next value straddles the end of the buffered chunk.
+            reader.read_exact(&mut carry).await?;
+            slots[decoded] = decode(&carry)?;
+            decoded += 1;
+            continue;
+        }
+
+        for (slot, bytes) in slots[decoded..decoded + n]
+            .iter_mut()
+            .zip(buf.chunks_exact(width))
+        {
+            *slot = decode(bytes)?;
+        }
+
+        reader.consume(n * width);
+        decoded += n;
+    }
+
+    Ok(())
+}
+
+/// Fills `slots` with zero terminated literals, splitting every buffered chunk synchronously.
+pub(crate) async fn read_literals<R, T, F>(
+    reader: &mut R,
+    slots: &mut [T],
+    mut decode: F,
+) -> Result<()>
+where
+    R: AsyncBufReadExt + Unpin,
+    F: FnMut(&[u8]) -> Result<T>,
+{
+    // bytes of a literal split across chunks.
+    let mut pending = Vec::new();
+    let mut decoded = 0;
+
+    while decoded < slots.len() {
+        let buf = reader.fill_buf().await?;
+
+        if buf.is_empty() {
+            return Err(Error::UnexpectedEof);
+        }
+
+        let mut consumed = 0;
+
+        while decoded < slots.len() {
+            let Some(pos) = buf[consumed..].iter().position(|b| *b == b'\0') else {
+                pending.extend_from_slice(&buf[consumed..]);
+                consumed = buf.len();
+                break;
+            };
+
+            let end = consumed + pos;
+
+            slots[decoded] = if pending.is_empty() {
+                decode(&buf[consumed..end])?
+            } else {
+                pending.extend_from_slice(&buf[consumed..end]);
+                let value = decode(&pending)?;
+                pending.clear();
+                value
+            };
+
+            consumed = end + 1;
+            decoded += 1;
+        }
+
+        reader.consume(consumed);
+    }
+
+    Ok(())
+}
+
+macro_rules! literal_from_bytes {
+    ($($struct_name:ident), *) => {
+        $(
+            impl $struct_name {
+                pub(crate) fn from_wire_bytes(bytes: &[u8]) -> Result<Self> {
+                    if bytes.is_empty() {
+                        Ok(Self(None))
+                    } else {
+                        Ok(Self(Some(String::from_utf8(bytes.to_vec())?)))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+literal_from_bytes!(DolphinString, Symbol);
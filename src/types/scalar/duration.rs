@@ -0,0 +1,126 @@
+//! Duration type interface compatible to [`std::time`] and [`chrono`]
+
+use std::any::type_name;
+
+use super::super::{Duration, DurationUnit};
+use crate::error::Error;
+
+// fixed length units from the largest to the smallest, in nanoseconds.
+const FIXED_UNITS: [(DurationUnit, i128); 8] = [
+    (DurationUnit::Week, 7 * 24 * 60 * 60 * 1_000_000_000),
+    (DurationUnit::Day, 24 * 60 * 60 * 1_000_000_000),
+    (DurationUnit::Hour, 60 * 60 * 1_000_000_000),
+    (DurationUnit::Minute, 60 * 1_000_000_000),
+    (DurationUnit::Second, 1_000_000_000),
+    (DurationUnit::Millisecond, 1_000_000),
+    (DurationUnit::Microsecond, 1_000),
+    (DurationUnit::Nanosecond, 1),
+];
+
+impl DurationUnit {
+    /// Returns the length of one unit in nanoseconds.
+    ///
+    /// Calendar based units (month, year, business day and exchange calendars) have no fixed length
+    /// and return `None`.
+    pub fn nanoseconds(&self) -> Option<i64> {
+        FIXED_UNITS
+            .iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, nanos)| *nanos as i64)
+    }
+}
+
+impl Duration {
+    /// Makes a new [`Duration`] counting trading days of the exchange calendar `code`, e.g. `1XNYS`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `None` if `code` is not a 4-letter upper case exchange code.
+    pub fn from_exchange(length: i32, code: &str) -> Option<Self> {
+        DurationUnit::exchange(code).map(|unit| Self::new(length, unit))
+    }
+
+    /// Counts of units in this duration.
+    pub fn length(&self) -> Option<i32> {
+        self.0.map(|(length, _)| length)
+    }
+
+    /// Time unit of this duration.
+    pub fn unit(&self) -> Option<DurationUnit> {
+        self.0.map(|(_, unit)| unit)
+    }
+
+    /// Total nanoseconds of this duration, `None` if it is null or its unit has no fixed length.
+    pub fn num_nanoseconds(&self) -> Option<i128> {
+        self.0
+            .and_then(|(length, unit)| unit.nanoseconds().map(|n| length as i128 * n as i128))
+    }
+
+    // picks the largest unit which represents `nanos` exactly.
+    fn from_nanoseconds(nanos: i128) -> Option<Self> {
+        FIXED_UNITS.iter().find_map(|(unit, n)| {
+            if nanos % n != 0 {
+                return None;
+            }
+
+            match i32::try_from(nanos / n) {
+                Ok(length) if length != i32::MIN => Some(Self::new(length, *unit)),
+                _ => None,
+            }
+        })
+    }
+}
+
+impl TryFrom<std::time::Duration> for Duration {
+    type Error = Error;
+
+    fn try_from(value: std::time::Duration) -> Result<Self, Self::Error> {
+        Self::from_nanoseconds(value.as_nanos() as i128).ok_or(Error::InvalidConvert {
+            from: type_name::<std::time::Duration>().to_string(),
+            to: type_name::<Self>().to_string(),
+        })
+    }
+}
+
+impl TryFrom<chrono::Duration> for Duration {
+    type Error = Error;
+
+    fn try_from(value: chrono::Duration) -> Result<Self, Self::Error> {
+        let nanos = value.num_seconds() as i128 * 1_000_000_000 + value.subsec_nanos() as i128;
+
+        Self::from_nanoseconds(nanos).ok_or(Error::InvalidConvert {
+            from: type_name::<chrono::Duration>().to_string(),
+            to: type_name::<Self>().to_string(),
+        })
+    }
+}
+
+impl TryFrom<Duration> for std::time::Duration {
+    type Error = Error;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        value
+            .num_nanoseconds()
+            .and_then(|nanos| u64::try_from(nanos).ok())
+            .map(std::time::Duration::from_nanos)
+            .ok_or(Error::InvalidConvert {
+                from: value.to_string(),
+                to: type_name::<std::time::Duration>().to_string(),
+            })
+    }
+}
+
+impl TryFrom<Duration> for chrono::Duration {
+    type Error = Error;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        value
+            .num_nanoseconds()
+            .and_then(|nanos| i64::try_from(nanos).ok())
+            .map(chrono::Duration::nanoseconds)
+            .ok_or(Error::InvalidConvert {
+                from: value.to_string(),
+                to: type_name::<chrono::Duration>().to_string(),
+            })
+    }
+}
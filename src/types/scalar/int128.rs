@@ -0,0 +1,58 @@
+//! 128-bit type interface compatible to [`std::net`]
+
+use std::net::{IpAddr as NetAddr, Ipv4Addr, Ipv6Addr};
+
+use super::super::*;
+
+impl Int128 {
+    /// DolphinDB internal representation, zero is reserved for null.
+    pub(crate) fn ddb_rep(&self) -> u128 {
+        self.0.unwrap_or(0) as u128
+    }
+
+    pub(crate) fn from_ddb_rep(rep: u128) -> Self {
+        Self((rep != 0).then_some(rep as i128))
+    }
+}
+
+impl Uuid {
+    /// Makes a new [`Uuid`] from its 16 bytes in big-endian order.
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self::new(u128::from_be_bytes(bytes))
+    }
+
+    /// Returns the 16 bytes of the [`Uuid`] in big-endian order.
+    pub fn to_bytes(&self) -> Option<[u8; 16]> {
+        self.0.map(u128::to_be_bytes)
+    }
+
+    /// DolphinDB internal representation, zero is reserved for null.
+    pub(crate) fn ddb_rep(&self) -> u128 {
+        self.0.unwrap_or(0)
+    }
+
+    pub(crate) fn from_ddb_rep(rep: u128) -> Self {
+        Self((rep != 0).then_some(rep))
+    }
+}
+
+impl IpAddr {
+    /// DolphinDB internal representation, IPv4 addresses occupy the lowest 32 bits.
+    pub(crate) fn ddb_rep(&self) -> u128 {
+        match self.0 {
+            None => 0,
+            Some(NetAddr::V4(ip)) => u32::from(ip) as u128,
+            Some(NetAddr::V6(ip)) => u128::from(ip),
+        }
+    }
+
+    pub(crate) fn from_ddb_rep(rep: u128) -> Self {
+        if rep == 0 {
+            Self(None)
+        } else if let Ok(v4) = u32::try_from(rep) {
+            Self::new(NetAddr::V4(Ipv4Addr::from(v4)))
+        } else {
+            Self::new(NetAddr::V6(Ipv6Addr::from(rep)))
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! Parsing of DolphinDB literals, the inverse of [`Display`](std::fmt::Display).
+//!
+//! `null` and the empty string parse to null for every type but [`DolphinString`] and [`Symbol`].
+
+use chrono::{
+    naive::{NaiveDate, NaiveDateTime, NaiveTime},
+    Timelike,
+};
+use rust_decimal::Decimal;
+use std::{net::IpAddr as NetAddr, str::FromStr};
+
+use super::super::*;
+use crate::error::{Error, Result};
+
+fn invalid(data_type: DataType, s: &str) -> Error {
+    Error::InvalidData {
+        expect: format!("{} literal", data_type),
+        actual: s.to_string(),
+    }
+}
+
+fn is_null_literal(s: &str) -> bool {
+    s.is_empty() || s == "null"
+}
+
+macro_rules! from_str_impl {
+    ($struct_name:ident, |$s:ident| $parse:expr) => {
+        impl FromStr for $struct_name {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                let trimmed = s.trim();
+                if is_null_literal(trimmed) {
+                    return Ok(Self::default());
+                }
+
+                let parse = |$s: &str| -> Option<Self> { $parse };
+                parse(trimmed).ok_or_else(|| invalid(Self::DATA_BYTE, s))
+            }
+        }
+    };
+}
+
+from_str_impl!(Void, |s| (s == "void").then_some(Void::default()));
+
+from_str_impl!(Bool, |s| match s {
+    "true" | "1" => Some(Bool::new(true)),
+    "false" | "0" => Some(Bool::new(false)),
+    _ => None,
+});
+
+// chars display as the character of their byte.
+from_str_impl!(Char, |s| {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if (c as u32) < 256 => Some(Char::new(c as u32 as u8 as i8)),
+        _ => s.parse().ok().map(Char::new),
+    }
+});
+
+from_str_impl!(Short, |s| s.parse().ok().map(Short::new));
+
+from_str_impl!(Int, |s| s.parse().ok().map(Int::new));
+
+from_str_impl!(Long, |s| s.parse().ok().map(Long::new));
+
+from_str_impl!(Float, |s| s.parse().ok().map(Float::new));
+
+from_str_impl!(Double, |s| s.parse().ok().map(Double::new));
+
+from_str_impl!(Date, |s| NaiveDate::parse_from_str(s, "%Y.%m.%d")
+    .ok()
+    .map(Date::new));
+
+from_str_impl!(Month, |s| {
+    let (year, month) = s.strip_suffix('M')?.split_once('.')?;
+    Month::from_ym(year.parse().ok()?, month.parse().ok()?)
+});
+
+// fractions longer than the precision of the type are truncated.
+from_str_impl!(Time, |s| {
+    let t = NaiveTime::parse_from_str(s, "%H:%M:%S%.f").ok()?;
+    Time::from_raw(t.num_seconds_from_midnight() * 1_000 + t.nanosecond() / 1_000_000)
+});
+
+from_str_impl!(Minute, |s| {
+    let s = s.strip_suffix('m').unwrap_or(s);
+    NaiveTime::parse_from_str(s, "%H:%M")
+        .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M:%S"))
+        .ok()
+        .and_then(|t| Minute::from_hm(t.hour(), t.minute()))
+});
+
+from_str_impl!(Second, |s| NaiveTime::parse_from_str(s, "%H:%M:%S")
+    .ok()
+    .map(Second::new));
+
+from_str_impl!(NanoTime, |s| NaiveTime::parse_from_str(s, "%H:%M:%S%.f")
+    .ok()
+    .map(NanoTime::new));
+
+from_str_impl!(DateTime, |s| parse_date_time(s, "%Y.%m.%dT%H:%M:%S")
+    .map(DateTime::new));
+
+from_str_impl!(Timestamp, |s| {
+    let dt = parse_date_time(s, "%Y.%m.%dT%H:%M:%S%.f")?;
+    Timestamp::from_raw(dt.and_utc().timestamp_millis())
+});
+
+from_str_impl!(NanoTimestamp, |s| parse_date_time(
+    s,
+    "%Y.%m.%dT%H:%M:%S%.f"
+)
+.map(NanoTimestamp::new));
+
+from_str_impl!(DateHour, |s| {
+    let (date, hour) = s.split_once(['T', ' '])?;
+    let date = NaiveDate::parse_from_str(date, "%Y.%m.%d").ok()?;
+    date.and_hms_opt(hour.parse().ok()?, 0, 0)
+        .map(DateHour::new)
+});
+
+from_str_impl!(Duration, |s| {
+    let split = s
+        .char_indices()
+        .find(|&(i, c)| !(c.is_ascii_digit() || (i == 0 && c == '-')))?
+        .0;
+    let unit = DurationUnit::from_suffix(&s[split..])?;
+    s[..split]
+        .parse()
+        .ok()
+        .map(|length| Duration::new(length, unit))
+});
+
+macro_rules! decimal_from_str {
+    ($struct_name:ident, $raw_type:tt, $max_scale:expr) => {
+        from_str_impl!($struct_name, |s| {
+            let d = Decimal::from_str(s)
+                .or_else(|_| Decimal::from_scientific(s))
+                .ok()?;
+            (d.scale() <= $max_scale && $raw_type::try_from(d.mantissa()).is_ok())
+                .then_some($struct_name(Some(d)))
+        });
+    };
+}
+
+decimal_from_str!(Decimal32, i32, 9);
+decimal_from_str!(Decimal64, i64, 18);
+decimal_from_str!(Decimal128, i128, 28);
+
+// blobs display as the debug list of their bytes.
+from_str_impl!(Blob, |s| {
+    let s = s.strip_prefix('[')?.strip_suffix(']')?;
+    if s.trim().is_empty() {
+        return Some(Blob::new(Vec::new()));
+    }
+    s.split(',')
+        .map(|b| b.trim().parse::<u8>().ok())
+        .collect::<Option<Vec<_>>>()
+        .map(Blob::new)
+});
+
+from_str_impl!(Int128, |s| (s.len() == 32)
+    .then(|| u128::from_str_radix(s, 16).ok())
+    .flatten()
+    .map(|v| Int128::new(v as i128)));
+
+from_str_impl!(Uuid, |s| {
+    let hex = s.replace('-', "");
+    let grouped = s.split('-').map(str::len).eq([8, 4, 4, 4, 12]);
+    (grouped && hex.len() == 32)
+        .then(|| u128::from_str_radix(&hex, 16).ok())
+        .flatten()
+        .map(Uuid::new)
+});
+
+from_str_impl!(IpAddr, |s| NetAddr::from_str(s).ok().map(IpAddr::new));
+
+// strings are taken verbatim.
+macro_rules! literal_from_str {
+    ($struct_name:ident) => {
+        impl FromStr for $struct_name {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                Ok(Self::new(s.to_string()))
+            }
+        }
+    };
+}
+
+literal_from_str!(DolphinString);
+literal_from_str!(Symbol);
+
+// also accepts a space between the date and the time.
+fn parse_date_time(s: &str, format: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(&s.replacen(' ', "T", 1), format).ok()
+}
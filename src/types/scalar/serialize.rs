@@ -6,7 +6,7 @@ use bytes::BufMut;
 
 use byteorder::{WriteBytesExt, BE, LE};
 
-use crate::types::{any::Any, decimal::*, primitive::*, temporal::*};
+use crate::types::{any::Any, decimal::*, duration::*, primitive::*, temporal::*};
 
 impl Serialize for Void {
     fn serialize<B>(&self, buffer: &mut B) -> Result<usize>
@@ -34,7 +34,7 @@ impl Serialize for Char {
     where
         B: bytes::BufMut,
     {
-        buffer.put_i8(self.as_ref().unwrap_or(i8::MIN));
+        buffer.put_i8(self.0);
         Ok(0)
     }
 }
@@ -70,7 +70,7 @@ macro_rules! serialize_primitive {
         {
             let mut writer = buffer.writer();
             writer
-                .$write_func::<$endian>(self.into_inner().unwrap_or($raw_type::MIN.into()).into())
+                .$write_func::<$endian>(self.0)
                 .unwrap();
             Ok(0)
         }
@@ -157,7 +157,7 @@ macro_rules! serialize_decimal {
                 .write_i32::<$endian>(self.scale().unwrap_or(0) as i32)
                 .unwrap();
             writer
-                .$write_func::<$endian>(self.mantissa().unwrap_or($raw_type::MIN))
+                .$write_func::<$endian>(self.wire_mantissa(self.scale().unwrap_or(0))?)
                 .unwrap();
             Ok(0)
         }
@@ -179,6 +179,30 @@ serialize_decimal!(
     (i128, Decimal128, write_i128)
 );
 
+macro_rules! serialize_128bit {
+    ($func_name:ident, $endian:tt) => {
+        fn $func_name<B>(&self, buffer: &mut B) -> Result<usize>
+        where
+            B: BufMut,
+        {
+            let mut writer = buffer.writer();
+            writer.write_u128::<$endian>(self.ddb_rep()).unwrap();
+            Ok(0)
+        }
+    };
+
+    ($($struct_name:ident), *) => {
+        $(
+            impl Serialize for $struct_name {
+                serialize_128bit!(serialize, BE);
+                serialize_128bit!(serialize_le, LE);
+            }
+        )*
+    };
+}
+
+serialize_128bit!(Int128, Uuid, IpAddr);
+
 impl Serialize for Any {
     fn serialize<B>(&self, buffer: &mut B) -> Result<usize>
     where
@@ -226,3 +250,28 @@ impl Serialize for Blob {
         Ok(0)
     }
 }
+
+macro_rules! serialize_duration {
+    ($func_name:ident, $endian:tt) => {
+        fn $func_name<B>(&self, buffer: &mut B) -> Result<usize>
+        where
+            B: BufMut,
+        {
+            let mut writer = buffer.writer();
+
+            let (length, unit) = self
+                .as_ref()
+                .map(|(length, unit)| (length, unit.ddb_rep()))
+                .unwrap_or((i32::MIN, 0));
+
+            writer.write_i32::<$endian>(length).unwrap();
+            writer.write_i32::<$endian>(unit).unwrap();
+            Ok(0)
+        }
+    };
+}
+
+impl Serialize for Duration {
+    serialize_duration!(serialize, BE);
+    serialize_duration!(serialize_le, LE);
+}
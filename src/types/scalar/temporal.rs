@@ -2,10 +2,11 @@
 
 use chrono::{
     naive::{NaiveDate, NaiveDateTime, NaiveTime},
-    Datelike, Days, Months,
+    Datelike, Days, Months, TimeZone, Utc,
 };
 
 use super::super::*;
+use crate::error::{Error, Result};
 use core::fmt::Display;
 
 impl Date {
@@ -365,3 +366,122 @@ impl Display for DateHour {
         }
     }
 }
+
+// DolphinDB stores wall clock time without a zone, the conversions below attach one explicitly.
+macro_rules! zoned_impl {
+    ($struct_name:ident, |$naive:ident| $truncate:expr) => {
+        impl $struct_name {
+            /// Interprets the wall clock time as local time in `tz`.
+            ///
+            /// Returns `None` if null or if `tz` skips the time, e.g. in a daylight saving
+            /// transition. Ambiguous times resolve to the earlier instant.
+            pub fn to_zoned<Tz: TimeZone>(&self, tz: &Tz) -> Option<chrono::DateTime<Tz>> {
+                self.0.and_then(|dt| tz.from_local_datetime(&dt).earliest())
+            }
+
+            /// Makes a new value from the wall clock time of `dt` in `tz`, truncated to the
+            /// precision of the type.
+            ///
+            /// Returns `None` if out of the range of the type.
+            pub fn from_zoned<Tz: TimeZone, Z: TimeZone>(
+                dt: &chrono::DateTime<Z>,
+                tz: &Tz,
+            ) -> Option<Self> {
+                let $naive = dt.with_timezone(tz).naive_local();
+                $truncate
+            }
+
+            /// Interprets the wall clock time as UTC.
+            pub fn to_utc(&self) -> Option<chrono::DateTime<Utc>> {
+                self.to_zoned(&Utc)
+            }
+
+            /// Makes a new value from the UTC wall clock time of `dt`.
+            pub fn from_utc<Z: TimeZone>(dt: &chrono::DateTime<Z>) -> Option<Self> {
+                Self::from_zoned(dt, &Utc)
+            }
+        }
+
+        impl Vector<$struct_name> {
+            #[doc = concat!("Converts each element with [`", stringify!($struct_name), "::to_zoned`].")]
+            pub fn to_zoned<Tz: TimeZone>(&self, tz: &Tz) -> Vec<Option<chrono::DateTime<Tz>>> {
+                self.iter().map(|v| v.to_zoned(tz)).collect()
+            }
+
+            #[doc = concat!("Converts each element with [`", stringify!($struct_name), "::from_zoned`], ")]
+            /// values out of range become null.
+            pub fn from_zoned<'a, Tz: TimeZone, Z: TimeZone + 'a>(
+                iter: impl IntoIterator<Item = &'a chrono::DateTime<Z>>,
+                tz: &Tz,
+            ) -> Self {
+                iter.into_iter()
+                    .map(|dt| $struct_name::from_zoned(dt, tz).unwrap_or_default())
+                    .collect()
+            }
+        }
+    };
+}
+
+zoned_impl!(DateTime, |naive| DateTime::from_raw(
+    i32::try_from(naive.and_utc().timestamp()).ok()?
+));
+
+zoned_impl!(Timestamp, |naive| Timestamp::from_raw(
+    naive.and_utc().timestamp_millis()
+));
+
+zoned_impl!(NanoTimestamp, |naive| NanoTimestamp::from_raw(
+    naive.and_utc().timestamp_nanos_opt()?
+));
+
+zoned_impl!(DateHour, |naive| DateHour::from_raw(
+    naive.and_utc().timestamp().div_euclid(3600)
+));
+
+macro_rules! dispatch_zoned {
+    ($($struct_name:ident),*) => {
+        impl ScalarImpl {
+            /// Interprets a [`DateTime`], [`Timestamp`], [`NanoTimestamp`] or [`DateHour`] as wall
+            /// clock time in `tz`, see [`Timestamp::to_zoned`].
+            ///
+            /// # Errors
+            ///
+            /// Returns an error for values of other types.
+            pub fn to_zoned<Tz: TimeZone>(&self, tz: &Tz) -> Result<Option<chrono::DateTime<Tz>>> {
+                match self {
+                    $(
+                        ScalarImpl::$struct_name(v) => Ok(v.to_zoned(tz)),
+                    )*
+                    _ => Err(Error::InvalidConvert {
+                        from: self.data_type().to_string(),
+                        to: "zoned date time".into(),
+                    }),
+                }
+            }
+        }
+
+        impl VectorImpl {
+            /// Converts each element with [`ScalarImpl::to_zoned`].
+            ///
+            /// # Errors
+            ///
+            /// Returns an error for vectors of other types.
+            pub fn to_zoned<Tz: TimeZone>(
+                &self,
+                tz: &Tz,
+            ) -> Result<Vec<Option<chrono::DateTime<Tz>>>> {
+                match self {
+                    $(
+                        VectorImpl::$struct_name(v) => Ok(v.to_zoned(tz)),
+                    )*
+                    _ => Err(Error::InvalidConvert {
+                        from: self.data_type().to_string(),
+                        to: "zoned date time".into(),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+dispatch_zoned!(DateTime, Timestamp, NanoTimestamp, DateHour);
@@ -1,8 +1,11 @@
+mod group_by;
+
 use std::fmt::Display;
 use std::hash::Hash;
 use std::{
     collections::{HashMap, HashSet},
     io::ErrorKind,
+    ops::{Bound, RangeBounds},
 };
 
 use tokio::io::{AsyncBufReadExt, AsyncReadExt};
@@ -16,15 +19,18 @@ use crate::{
 };
 
 use super::{
-    deserialize_vector, deserialize_vector_le, Constant, DataForm, DataType, Dictionary,
-    DictionaryImpl, VectorImpl,
+    deserialize_vector, deserialize_vector_le, Constant, ConstantImpl, DataForm, DataType,
+    Dictionary, DictionaryImpl, VectorImpl,
 };
 
+pub use group_by::{Aggregation, GroupBy};
+
 #[derive(Debug, Clone, Default)]
 pub struct TableBuilder {
     name: String,
     columns: Vec<VectorImpl>,
     column_names: Vec<String>,
+    scales: Vec<(String, u32)>,
 }
 
 impl TableBuilder {
@@ -48,7 +54,16 @@ impl TableBuilder {
         self
     }
 
-    pub fn build(self) -> Result<Table> {
+    /// Declares the scale of the decimal column `column_name`, e.g. 4 for `DECIMAL64(4)`.
+    ///
+    /// Values are rounded to it on [`build`](Self::build), see
+    /// [`Vector::set_scale`](super::Vector::set_scale).
+    pub fn with_scale(&mut self, column_name: &str, scale: u32) -> &mut Self {
+        self.scales.push((column_name.to_string(), scale));
+        self
+    }
+
+    pub fn build(mut self) -> Result<Table> {
         if self.columns.len() != self.column_names.len() {
             return Err(Error::ConstraintsViolated(
                 "mismatch columns and column names size".into(),
@@ -75,6 +90,15 @@ impl TableBuilder {
             ));
         }
 
+        for (name, scale) in self.scales.iter() {
+            let index = self
+                .column_names
+                .iter()
+                .position(|n| n == name)
+                .ok_or_else(|| Error::ConstraintsViolated(format!("unknown column {}", name)))?;
+            self.columns[index].set_decimal_scale(*scale)?;
+        }
+
         Ok(Table {
             name: self.name,
             columns: self.columns,
@@ -165,6 +189,306 @@ impl Table {
 
         Ok(())
     }
+
+    /// Removes and returns the column named `column_name`.
+    pub fn remove_column(&mut self, column_name: &str) -> Result<VectorImpl> {
+        let index = self.column_index(column_name)?;
+        self.column_names.remove(index);
+        Ok(self.columns.remove(index))
+    }
+
+    /// Renames the column `from` to `to`.
+    pub fn rename_column(&mut self, from: &str, to: String) -> Result<()> {
+        let index = self.column_index(from)?;
+
+        if to.is_empty() {
+            return Err(Error::ConstraintsViolated(
+                "empty column name is not allowed".into(),
+            ));
+        }
+
+        if self.column_names.iter().any(|n| n == &to) && self.column_names[index] != to {
+            return Err(Error::ConstraintsViolated("duplicated column name".into()));
+        }
+
+        self.column_names[index] = to;
+        Ok(())
+    }
+
+    /// Replaces the column named `column_name` and returns the old one, or appends `column` if
+    /// no such column exists.
+    pub fn set_column(
+        &mut self,
+        column: VectorImpl,
+        column_name: String,
+    ) -> Result<Option<VectorImpl>> {
+        match self.column_names.iter().position(|n| n == &column_name) {
+            Some(index) => {
+                if column.len() != self.len() {
+                    return Err(Error::ConstraintsViolated("mismatch column size".into()));
+                }
+
+                Ok(Some(std::mem::replace(&mut self.columns[index], column)))
+            }
+            // the first column decides the length of the table.
+            None if self.columns.is_empty() => {
+                if column_name.is_empty() {
+                    return Err(Error::ConstraintsViolated(
+                        "empty column name is not allowed".into(),
+                    ));
+                }
+
+                self.columns.push(column);
+                self.column_names.push(column_name);
+                Ok(None)
+            }
+            None => {
+                self.insert_column(column, column_name, self.columns.len())?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns a view of the row at `index`, `None` if it is out of bounds.
+    pub fn row(&self, index: usize) -> Option<Row<'_>> {
+        (index < self.len()).then_some(Row { table: self, index })
+    }
+
+    /// Returns an iterator over the rows.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = Row<'_>> + '_ {
+        (0..self.len()).map(|index| Row { table: self, index })
+    }
+
+    /// Appends a row holding one value per column, in the order of the columns.
+    ///
+    /// Array vector columns take a vector of their element type, other columns take a scalar.
+    /// The table is left unchanged if any value doesn't match the type of its column or a
+    /// decimal can't be represented at the scale of its column.
+    pub fn append_row(&mut self, row: Vec<ConstantImpl>) -> Result<()> {
+        if row.len() != self.columns.len() {
+            return Err(Error::ConstraintsViolated("mismatch row size".into()));
+        }
+
+        let mut fitted = Vec::with_capacity(row.len());
+        for (column, value) in self.columns.iter().zip(row) {
+            let matched = match column {
+                VectorImpl::Any(_) => true,
+                VectorImpl::ArrayVector(a) => {
+                    value.data_form() == DataForm::Vector && value.data_type() == a.element_type()
+                }
+                _ => {
+                    value.data_form() == DataForm::Scalar && value.data_type() == column.data_type()
+                }
+            };
+
+            if !matched {
+                return Err(Error::InvalidConvert {
+                    from: value.data_type().to_string(),
+                    to: column.data_type().to_string(),
+                });
+            }
+
+            fitted.push(match value {
+                ConstantImpl::Scalar(s) => column.fit_scale(s)?.into(),
+                // rows of a decimal array vector take its declared scale.
+                ConstantImpl::Vector(mut row) if matches!(column, VectorImpl::ArrayVector(_)) => {
+                    if let Some(scale) = column.decimal_scale() {
+                        row.set_decimal_scale(scale)?;
+                    }
+                    row.into()
+                }
+                value => value,
+            });
+        }
+
+        for (column, value) in self.columns.iter_mut().zip(fitted) {
+            column.push_unchecked(value);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the rows in `range`.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Result<Table> {
+        let overflow = || Error::ConstraintsViolated("index overflow".into());
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i.checked_add(1).ok_or_else(overflow)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i.checked_add(1).ok_or_else(overflow)?,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => self.len(),
+        };
+
+        if start > end || end > self.len() {
+            return Err(overflow());
+        }
+
+        Ok(self.take_unchecked(&(start..end).collect::<Vec<_>>()))
+    }
+
+    /// Returns the first `n` rows, or all rows if there are fewer.
+    pub fn head(&self, n: usize) -> Table {
+        self.take_unchecked(&(0..n.min(self.len())).collect::<Vec<_>>())
+    }
+
+    /// Returns the last `n` rows, or all rows if there are fewer.
+    pub fn tail(&self, n: usize) -> Table {
+        self.take_unchecked(&(self.len().saturating_sub(n)..self.len()).collect::<Vec<_>>())
+    }
+
+    /// Returns the rows at `indices` in that order, an index may appear more than once.
+    pub fn take(&self, indices: &[usize]) -> Result<Table> {
+        if indices.iter().any(|&i| i >= self.len()) {
+            return Err(Error::ConstraintsViolated("index overflow".into()));
+        }
+
+        Ok(self.take_unchecked(indices))
+    }
+
+    /// Returns the rows whose `mask` entry is `true`.
+    pub fn filter(&self, mask: &[bool]) -> Result<Table> {
+        if mask.len() != self.len() {
+            return Err(Error::ConstraintsViolated("mismatch mask size".into()));
+        }
+
+        let indices: Vec<usize> = mask
+            .iter()
+            .enumerate()
+            .filter_map(|(i, keep)| keep.then_some(i))
+            .collect();
+
+        Ok(self.take_unchecked(&indices))
+    }
+
+    /// Returns a table holding only the columns named in `column_names`, in that order.
+    pub fn select(&self, column_names: &[&str]) -> Result<Table> {
+        if !no_duplicates(column_names) {
+            return Err(Error::ConstraintsViolated("duplicated column name".into()));
+        }
+
+        let mut columns = Vec::with_capacity(column_names.len());
+        for name in column_names {
+            columns.push(self.columns[self.column_index(name)?].clone());
+        }
+
+        Ok(Table {
+            name: self.name.clone(),
+            columns,
+            column_names: column_names.iter().map(|n| n.to_string()).collect(),
+        })
+    }
+
+    /// Returns the rows of this table followed by the rows of `others`.
+    ///
+    /// All tables must have the same column names and column types in the same order.
+    /// Decimals are rounded to the scale of the columns of this table.
+    pub fn concat(&self, others: &[Table]) -> Result<Table> {
+        let mut res = self.clone();
+
+        for other in others {
+            let same_schema = other.column_names == self.column_names
+                && other
+                    .columns
+                    .iter()
+                    .zip(self.columns.iter())
+                    .all(|(a, b)| a.data_type() == b.data_type());
+
+            if !same_schema {
+                return Err(Error::ConstraintsViolated("mismatch table schema".into()));
+            }
+
+            for (column, other) in res.columns.iter_mut().zip(other.columns.iter()) {
+                column.extend_from(other)?;
+            }
+        }
+
+        Ok(res)
+    }
+
+    fn column_index(&self, column_name: &str) -> Result<usize> {
+        self.column_names
+            .iter()
+            .position(|name| name == column_name)
+            .ok_or(Error::ConstraintsViolated(format!(
+                "column {} doesn't exist",
+                column_name
+            )))
+    }
+
+    // `indices` must be in bounds.
+    fn take_unchecked(&self, indices: &[usize]) -> Table {
+        Table {
+            name: self.name.clone(),
+            columns: self.columns.iter().map(|c| c.take(indices)).collect(),
+            column_names: self.column_names.clone(),
+        }
+    }
+}
+
+/// Borrowed view of a row in a [`Table`].
+#[derive(Debug, Clone, Copy)]
+pub struct Row<'a> {
+    table: &'a Table,
+    index: usize,
+}
+
+impl<'a> Row<'a> {
+    /// Position of this row in the table.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the number of columns.
+    pub fn len(&self) -> usize {
+        self.table.columns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value in column `column`, `None` if it is out of bounds.
+    /// Values of array vector columns are vectors, see [`ArrayVectorImpl::get`](super::ArrayVectorImpl::get).
+    pub fn get(&self, column: usize) -> Option<ConstantImpl> {
+        self.table
+            .columns
+            .get(column)
+            .and_then(|c| c.get(self.index))
+    }
+
+    /// Returns the value in the column named `column_name`.
+    pub fn get_by_name(&self, column_name: &str) -> Option<ConstantImpl> {
+        self.table
+            .column_index(column_name)
+            .ok()
+            .and_then(|i| self.get(i))
+    }
+
+    /// Returns the value in the column named `column_name` converted to `T`.
+    pub fn get_as<T>(&self, column_name: &str) -> Result<T>
+    where
+        T: TryFrom<ConstantImpl, Error = Error>,
+    {
+        let value = self
+            .get_by_name(column_name)
+            .ok_or(Error::ConstraintsViolated(format!(
+                "column {} doesn't exist",
+                column_name
+            )))?;
+        T::try_from(value)
+    }
+
+    /// Returns an iterator over column names and values.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, Option<ConstantImpl>)> + '_ {
+        self.table
+            .column_names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), self.get(i)))
+    }
 }
 
 fn no_duplicates<T>(elements: &[T]) -> bool
@@ -181,7 +505,7 @@ impl From<Table> for DictionaryImpl {
         let columns = value.columns;
         let column_names = value.column_names;
 
-        for (k, v) in column_names.into_iter().zip(columns.into_iter()) {
+        for (k, v) in column_names.into_iter().zip(columns) {
             dict.insert_any(k.into(), v.into());
         }
 
@@ -222,8 +546,9 @@ impl Display for Table {
             .collect::<Vec<_>>();
 
         for j in 0..self.len() {
-            let row = (0..self.columns.len())
-                .map(|i| Cell::new(columns[i][j].as_str()))
+            let row = columns
+                .iter()
+                .map(|column| Cell::new(column[j].as_str()))
                 .collect::<Vec<_>>();
 
             table.add_row(row.into());
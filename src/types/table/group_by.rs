@@ -0,0 +1,221 @@
+//! Client side sorting and grouping of [`Table`].
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+
+use super::{Table, VectorImpl};
+use crate::{
+    error::{Error, Result},
+    types::{Int, Vector},
+};
+
+/// Aggregate function applied to each group by [`GroupBy::agg`].
+///
+/// Nulls are ignored except by [`First`](Aggregation::First) and [`Last`](Aggregation::Last).
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Aggregation {
+    /// Sum as LONG for integers, DOUBLE for floats and a wider decimal for decimals.
+    Sum,
+    /// Mean as DOUBLE.
+    Avg,
+    /// Number of non-null values as INT.
+    Count,
+    Min,
+    Max,
+    First,
+    Last,
+}
+
+impl Display for Aggregation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Aggregation::Sum => "sum",
+            Aggregation::Avg => "avg",
+            Aggregation::Count => "count",
+            Aggregation::Min => "min",
+            Aggregation::Max => "max",
+            Aggregation::First => "first",
+            Aggregation::Last => "last",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+/// Rows of a [`Table`] grouped by key columns, see [`Table::group_by`].
+#[derive(Debug, Clone)]
+pub struct GroupBy<'a> {
+    table: &'a Table,
+    keys: Vec<usize>,
+    // row indices of each group, groups are sorted by keys.
+    groups: Vec<Vec<usize>>,
+}
+
+impl Table {
+    /// Sorts rows by `columns`, with a direction per column or one for all columns.
+    ///
+    /// Nulls are smaller than any other value as in DolphinDB, the sort is stable.
+    pub fn sort_by(&self, columns: &[&str], ascending: &[bool]) -> Result<Table> {
+        if ascending.len() != 1 && ascending.len() != columns.len() {
+            return Err(Error::ConstraintsViolated(
+                "mismatch sort directions size".into(),
+            ));
+        }
+
+        let keys = columns
+            .iter()
+            .map(|name| self.column_index(name))
+            .collect::<Result<Vec<_>>>()?;
+
+        let indices = self.sorted_indices(&keys, |k| ascending[k.min(ascending.len() - 1)])?;
+
+        Ok(self.take_unchecked(&indices))
+    }
+
+    /// Groups rows by the `keys` columns.
+    pub fn group_by(&self, keys: &[&str]) -> Result<GroupBy<'_>> {
+        let keys = keys
+            .iter()
+            .map(|name| self.column_index(name))
+            .collect::<Result<Vec<_>>>()?;
+
+        let sorted = self.sorted_indices(&keys, |_| true)?;
+
+        let comparators = keys
+            .iter()
+            .map(|&k| self.columns[k].comparator())
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for i in sorted {
+            match groups.last_mut() {
+                Some(group) if comparators.iter().all(|c| c(group[0], i).is_eq()) => group.push(i),
+                _ => groups.push(vec![i]),
+            }
+        }
+
+        Ok(GroupBy {
+            table: self,
+            keys,
+            groups,
+        })
+    }
+
+    fn sorted_indices(
+        &self,
+        keys: &[usize],
+        ascending: impl Fn(usize) -> bool,
+    ) -> Result<Vec<usize>> {
+        let comparators = keys
+            .iter()
+            .map(|&k| self.columns[k].comparator())
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_by(|&a, &b| {
+            comparators
+                .iter()
+                .enumerate()
+                .map(|(k, cmp)| {
+                    let ord = cmp(a, b);
+                    if ascending(k) {
+                        ord
+                    } else {
+                        ord.reverse()
+                    }
+                })
+                .find(|ord| ord.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+
+        Ok(indices)
+    }
+}
+
+impl GroupBy<'_> {
+    /// Returns the number of groups.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Aggregates each group into a row, returning the key columns followed by a column per
+    /// `(column, aggregation)` named like DolphinDB, e.g. `sum_price`.
+    pub fn agg(&self, aggregations: &[(&str, Aggregation)]) -> Result<Table> {
+        let firsts: Vec<usize> = self.groups.iter().map(|g| g[0]).collect();
+
+        let mut columns: Vec<VectorImpl> = self
+            .keys
+            .iter()
+            .map(|&k| self.table.columns[k].take(&firsts))
+            .collect();
+        let mut column_names: Vec<String> = self
+            .keys
+            .iter()
+            .map(|&k| self.table.column_names[k].clone())
+            .collect();
+
+        for (name, aggregation) in aggregations {
+            let column = &self.table.columns[self.table.column_index(name)?];
+            columns.push(self.aggregate(column, *aggregation)?);
+            column_names.push(format!("{}_{}", aggregation, name));
+        }
+
+        let mut builder = super::TableBuilder::new();
+        builder
+            .with_name(self.table.name.clone())
+            .with_contents(columns, column_names);
+        builder.build()
+    }
+
+    fn aggregate(&self, column: &VectorImpl, aggregation: Aggregation) -> Result<VectorImpl> {
+        let res = match aggregation {
+            Aggregation::Sum => {
+                let mut res = VectorImpl::from_type(column.sum_of(&[])?.data_type())
+                    .ok_or(column.unsupported())?;
+                for group in self.groups.iter() {
+                    res.push_scalar(column.sum_of(group)?);
+                }
+                res
+            }
+            Aggregation::Avg => {
+                let mut res = Vector::new();
+                for group in self.groups.iter() {
+                    res.push(column.avg_of(group)?);
+                }
+                res.into()
+            }
+            Aggregation::Count => {
+                let mut res = Vector::new();
+                for group in self.groups.iter() {
+                    res.push(Int::new(column.count_of(group)? as i32));
+                }
+                res.into()
+            }
+            Aggregation::Min | Aggregation::Max => {
+                let mut indices = Vec::with_capacity(self.groups.len());
+                for group in self.groups.iter() {
+                    // groups are never empty.
+                    let i = column.extreme_of(group, aggregation == Aggregation::Max)?;
+                    indices.push(i.unwrap_or(group[0]));
+                }
+                column.take(&indices)
+            }
+            Aggregation::First => {
+                column.take(&self.groups.iter().map(|g| g[0]).collect::<Vec<_>>())
+            }
+            Aggregation::Last => column.take(
+                &self
+                    .groups
+                    .iter()
+                    .map(|g| g[g.len() - 1])
+                    .collect::<Vec<_>>(),
+            ),
+        };
+
+        Ok(res)
+    }
+}
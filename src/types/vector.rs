@@ -1,14 +1,20 @@
+mod cast;
+mod compute;
+
 use super::{
-    any::Any, array_vector::*, decimal::*, for_all_types, primitive::*, temporal::*, Constant,
-    ConstantImpl, DataForm, DataType, DecimalInterface, NotDecimal, ScalarImpl,
+    any::Any, array_vector::*, decimal::*, duration::*, for_all_types, for_native_types,
+    primitive::*, read_fixed_width, read_literals, temporal::*, Constant, ConstantImpl, DataForm,
+    DataType, DecimalInterface, FixedWidth, NotDecimal, ScalarImpl,
 };
 use crate::{
+    codec::check_len,
     error::{Error, Result},
     Deserialize, Serialize,
 };
 use byteorder::{WriteBytesExt, BE, LE};
 use bytes::BufMut;
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use rust_decimal::RoundingStrategy;
 use std::{
     any::type_name,
     collections::HashMap,
@@ -19,9 +25,13 @@ use std::{
 };
 use tokio::io::{AsyncBufReadExt, AsyncReadExt};
 
+pub use compute::Operand;
+
 #[derive(Default, Debug, Clone)]
 pub struct Vector<S> {
     data: Vec<S>,
+    // declared scale of decimal vectors, e.g. 4 for a DECIMAL64(4) column.
+    scale: Option<u32>,
 }
 
 pub type VoidVector = Vector<Void>;
@@ -46,9 +56,13 @@ pub type StringVector = Vector<DolphinString>;
 pub type AnyVector = Vector<Any>;
 pub type DateHourVector = Vector<DateHour>;
 pub type BlobVector = Vector<Blob>;
+pub type DurationVector = Vector<Duration>;
 pub type Decimal32Vector = Vector<Decimal32>;
 pub type Decimal64Vector = Vector<Decimal64>;
 pub type Decimal128Vector = Vector<Decimal128>;
+pub type Int128Vector = Vector<Int128>;
+pub type UuidVector = Vector<Uuid>;
+pub type IpAddrVector = Vector<IpAddr>;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VectorImpl {
@@ -78,12 +92,18 @@ pub enum VectorImpl {
     Any(Vector<Any>),
     DateHour(Vector<DateHour>),
 
+    Duration(Vector<Duration>),
+
     Blob(Vector<Blob>),
 
     Decimal32(Vector<Decimal32>),
     Decimal64(Vector<Decimal64>),
     Decimal128(Vector<Decimal128>),
 
+    Int128(Vector<Int128>),
+    Uuid(Vector<Uuid>),
+    IpAddr(Vector<IpAddr>),
+
     ArrayVector(ArrayVectorImpl),
 }
 
@@ -138,7 +158,7 @@ where
 impl<T> FromIterator<T> for Vector<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let data = Vec::from_iter(iter);
-        Self { data }
+        Self { data, scale: None }
     }
 }
 
@@ -162,7 +182,10 @@ impl<S: Eq> Eq for Vector<S> {}
 
 impl<S> From<Vec<S>> for Vector<S> {
     fn from(value: Vec<S>) -> Self {
-        Self { data: value }
+        Self {
+            data: value,
+            scale: None,
+        }
     }
 }
 
@@ -170,13 +193,17 @@ impl<S> From<Vec<S>> for Vector<S> {
 impl<S> Vector<S> {
     /// Constructs a new, empty [`Vector`].
     pub fn new() -> Self {
-        Self { data: vec![] }
+        Self {
+            data: vec![],
+            scale: None,
+        }
     }
 
     /// Constructs a new, empty [`Vector`] with at least the specified capacity.
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             data: Vec::with_capacity(capacity),
+            scale: None,
         }
     }
 
@@ -278,7 +305,7 @@ impl<S: Primitive> Vector<S> {
             data.push(S::new(S::to_owned(*val)));
         }
 
-        Self { data }
+        Self { data, scale: None }
     }
 
     /// Appends a primitive element to the back of a collection.
@@ -298,6 +325,56 @@ impl Vector<Any> {
     }
 }
 
+macro_rules! native_vector_impl {
+    ($(($raw_type:tt, $struct_name:ident, $enum_name:ident)), *) => {
+        $(
+            impl Vector<$struct_name> {
+                /// Returns the elements as a contiguous slice of native values,
+                /// where null elements are `NULL_VALUE`.
+                pub fn as_slice(&self) -> &[$raw_type] {
+                    // SAFETY: the scalar is a `repr(transparent)` wrapper of its native value.
+                    unsafe {
+                        std::slice::from_raw_parts(self.data.as_ptr() as *const $raw_type, self.data.len())
+                    }
+                }
+
+                /// Returns the elements as a contiguous mutable slice of native values.
+                pub fn as_mut_slice(&mut self) -> &mut [$raw_type] {
+                    // SAFETY: the scalar is a `repr(transparent)` wrapper of its native value.
+                    unsafe {
+                        std::slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut $raw_type, self.data.len())
+                    }
+                }
+
+                /// Appends native values, `NULL_VALUE` is appended as null.
+                pub fn extend_from_slice(&mut self, other: &[$raw_type]) {
+                    self.data.extend(other.iter().map(|v| $struct_name(*v)));
+                }
+
+                /// Returns the validity bitmap with one bit per element, least significant bit first.
+                ///
+                /// A set bit marks a non-null element, as in the Arrow columnar format.
+                pub fn validity(&self) -> Vec<u8> {
+                    let mut bitmap = vec![0u8; self.data.len().div_ceil(8)];
+                    for (i, v) in self.data.iter().enumerate() {
+                        if !v.is_null() {
+                            bitmap[i / 8] |= 1 << (i % 8);
+                        }
+                    }
+                    bitmap
+                }
+
+                /// Returns the number of null elements.
+                pub fn null_count(&self) -> usize {
+                    self.data.iter().filter(|v| v.is_null()).count()
+                }
+            }
+        )*
+    };
+}
+
+for_native_types!(native_vector_impl);
+
 impl<S: Display> Display for Vector<S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut s = String::new();
@@ -369,13 +446,13 @@ macro_rules! serialize_decimal {
         {
             let mut writer = buffer.writer();
 
-            let mut replica = self.clone();
-            replica.scale_to_same();
-
-            let _ = writer.write_u32::<$endian>(replica.max_scale());
+            // all elements share the declared or else the largest scale, values that can't be
+            // represented at it fail.
+            let scale = self.scale.unwrap_or_else(|| self.max_scale());
+            let _ = writer.write_u32::<$endian>(scale);
 
-            for mantissa in replica.iter().map(|d| d.mantissa().unwrap_or($raw_type::MIN)) {
-                let _ = writer.$write_func::<$endian>(mantissa);
+            for d in self.iter() {
+                let _ = writer.$write_func::<$endian>(d.wire_mantissa(scale)?);
             }
 
             Ok(0)
@@ -400,29 +477,70 @@ serialize_decimal!(
 
 impl<S> Deserialize for Vector<S>
 where
-    S: Deserialize + NotDecimal,
+    S: FixedWidth,
 {
     async fn deserialize<R>(&mut self, reader: &mut R) -> Result<()>
     where
         R: AsyncBufReadExt + Unpin,
     {
-        for slot in self.iter_mut() {
-            slot.deserialize(reader).await?;
-        }
-        Ok(())
+        read_fixed_width(reader, self, S::WIDTH, S::from_be_slice).await
     }
 
     async fn deserialize_le<R>(&mut self, reader: &mut R) -> Result<()>
     where
         R: AsyncBufReadExt + Unpin,
     {
-        for slot in self.iter_mut() {
-            slot.deserialize_le(reader).await?;
-        }
-        Ok(())
+        read_fixed_width(reader, self, S::WIDTH, S::from_le_slice).await
     }
 }
 
+macro_rules! deserialize_literal {
+    ($($struct_name:ident), *) => {
+        $(
+            impl Deserialize for Vector<$struct_name> {
+                async fn deserialize<R>(&mut self, reader: &mut R) -> Result<()>
+                where
+                    R: AsyncBufReadExt + Unpin,
+                {
+                    read_literals(reader, self, $struct_name::from_wire_bytes).await
+                }
+            }
+        )*
+    };
+}
+
+deserialize_literal!(DolphinString, Symbol);
+
+macro_rules! deserialize_each {
+    ($($struct_name:ident), *) => {
+        $(
+            impl Deserialize for Vector<$struct_name> {
+                async fn deserialize<R>(&mut self, reader: &mut R) -> Result<()>
+                where
+                    R: AsyncBufReadExt + Unpin,
+                {
+                    for slot in self.iter_mut() {
+                        slot.deserialize(reader).await?;
+                    }
+                    Ok(())
+                }
+
+                async fn deserialize_le<R>(&mut self, reader: &mut R) -> Result<()>
+                where
+                    R: AsyncBufReadExt + Unpin,
+                {
+                    for slot in self.iter_mut() {
+                        slot.deserialize_le(reader).await?;
+                    }
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+deserialize_each!(Blob, Any);
+
 macro_rules! deserialize_decimal {
     ($struct_name:ident, $raw_type:tt, $read_scale:ident, $read_func:ident, $func_name:ident) => {
         async fn $func_name<R>(&mut self, reader: &mut R) -> Result<()>
@@ -430,17 +548,18 @@ macro_rules! deserialize_decimal {
             R: AsyncBufReadExt + Unpin,
         {
             let scale = reader.$read_scale().await?;
+            self.scale = Some(scale as u32);
 
-            for slot in self.iter_mut() {
-                let mantissa = reader.$read_func().await?;
-                *slot = if mantissa != $raw_type::MIN {
-                    $struct_name::from_raw(mantissa, scale as u32).ok_or(Error::ConstraintsViolated("decimal scale overflow".into()))?
-                } else {
-                    $struct_name::default()
-                };
-            }
+            read_fixed_width(reader, self, size_of::<$raw_type>(), |bytes| {
+                let mantissa = $raw_type::$read_func(bytes.try_into().map_err(|_| Error::UnexpectedEof)?);
 
-            Ok(())
+                if mantissa != $raw_type::MIN {
+                    $struct_name::from_raw(mantissa, scale as u32).ok_or(Error::ConstraintsViolated("decimal scale overflow".into()))
+                } else {
+                    Ok($struct_name::default())
+                }
+            })
+            .await
         }
     };
 
@@ -455,9 +574,9 @@ macro_rules! deserialize_decimal {
 }
 
 deserialize_decimal!(
-    (i32, Decimal32, read_i32, read_i32_le),
-    (i64, Decimal64, read_i64, read_i64_le),
-    (i128, Decimal128, read_i128, read_i128_le)
+    (i32, Decimal32, from_be_bytes, from_le_bytes),
+    (i64, Decimal64, from_be_bytes, from_le_bytes),
+    (i128, Decimal128, from_be_bytes, from_le_bytes)
 );
 
 macro_rules! try_from_impl {
@@ -566,6 +685,50 @@ macro_rules! dispatch_resize {
     };
 }
 
+macro_rules! dispatch_take {
+    ($(($enum_name:ident, $struct_name:ident)),*) => {
+        impl VectorImpl {
+            // elements at `indices` in that order, panics if any of them is out of bounds.
+            pub(crate) fn take(&self, indices: &[usize]) -> Self {
+                match self {
+                    $(
+                        VectorImpl::$enum_name(v) => VectorImpl::$enum_name(Vector {
+                            data: indices.iter().map(|&i| v[i].clone()).collect(),
+                            scale: v.scale,
+                        }),
+                    )*
+                    VectorImpl::ArrayVector(v) => VectorImpl::ArrayVector(v.take(indices)),
+                }
+            }
+
+            pub(crate) fn extend_from(&mut self, other: &Self) -> Result<()> {
+                // decimals take the declared scale of `self`.
+                if let Some(scale) = self.decimal_scale() {
+                    if other.data_type() == self.data_type() && other.decimal_scale() != Some(scale) {
+                        let mut other = other.clone();
+                        other.set_decimal_scale(scale)?;
+                        return self.extend_from(&other);
+                    }
+                }
+
+                match (self, other) {
+                    $(
+                        (VectorImpl::$enum_name(v), VectorImpl::$enum_name(o)) => v.data.extend_from_slice(o),
+                    )*
+                    (VectorImpl::ArrayVector(v), VectorImpl::ArrayVector(o)) => v.extend_from(o)?,
+                    (v, o) => {
+                        return Err(Error::InvalidConvert {
+                            from: o.data_type().to_string(),
+                            to: v.data_type().to_string(),
+                        })
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
 macro_rules! dispatch_serialize {
     ($(($enum_name:ident, $struct_name:ident)),*) => {
         impl VectorImpl {
@@ -634,13 +797,7 @@ macro_rules! dispatch_reflect {
                     $(
                         $struct_name::DATA_BYTE => Some(Self::$enum_name(Vector::new())),
                     )*
-                    DataType::CharArray => Some(Self::ArrayVector(ArrayVectorImpl::Char(CharArrayVector::new()))),
-                    DataType::ShortArray => Some(Self::ArrayVector(ArrayVectorImpl::Short(ShortArrayVector::new()))),
-                    DataType::IntArray => Some(Self::ArrayVector(ArrayVectorImpl::Int(IntArrayVector::new()))),
-                    DataType::LongArray => Some(Self::ArrayVector(ArrayVectorImpl::Long(LongArrayVector::new()))),
-                    DataType::FloatArray => Some(Self::ArrayVector(ArrayVectorImpl::Float(FloatArrayVector::new()))),
-                    DataType::DoubleArray => Some(Self::ArrayVector(ArrayVectorImpl::Double(DoubleArrayVector::new()))),
-                    _ => panic!("Unsupported data type"),
+                    _ => ArrayVectorImpl::from_type(data_type).map(Self::ArrayVector),
                 }
             }
         }
@@ -680,6 +837,7 @@ macro_rules! for_all_vectors {
             (String, DolphinString),
             (Any, Any),
             (DateHour, DateHour),
+            (Duration, Duration),
             (Decimal32, Decimal32),
             (Decimal64, Decimal64),
             (Decimal128, Decimal128),
@@ -689,7 +847,10 @@ macro_rules! for_all_vectors {
             (Long, Long),
             (Float, Float),
             (Double, Double),
-            (Blob, Blob)
+            (Blob, Blob),
+            (Int128, Int128),
+            (Uuid, Uuid),
+            (IpAddr, IpAddr)
         );
     };
 }
@@ -700,6 +861,8 @@ for_all_vectors!(dispatch_len);
 
 for_all_vectors!(dispatch_resize);
 
+for_all_vectors!(dispatch_take);
+
 for_all_vectors!(dispatch_serialize);
 
 for_all_vectors!(dispatch_deserialize);
@@ -760,6 +923,7 @@ impl Deserialize for VectorImpl {
 
         let _cols = reader.read_i32().await?;
 
+        check_len(len)?;
         self.resize(len);
 
         self.deserialize_data(reader).await
@@ -774,6 +938,7 @@ impl Deserialize for VectorImpl {
 
         let _cols = reader.read_i32_le().await?;
 
+        check_len(len)?;
         self.resize(len);
 
         self.deserialize_data_le(reader).await
@@ -797,6 +962,7 @@ macro_rules! deserialize_symbol_with_base {
                 .map_err(|e| Error::InvalidNumeric(e.to_string()))?;
 
             let mut symbol_base_vec = Vector::<Symbol>::new();
+            check_len(symbol_base_size)?;
             symbol_base_vec.resize(symbol_base_size, Symbol::default());
             symbol_base_vec.$read_func(reader).await?;
 
@@ -838,6 +1004,7 @@ macro_rules! deserialize_symbol_with_base {
             };
 
             let mut symbol_ids = Vector::<Int>::new();
+            check_len(len)?;
             symbol_ids.resize(len, Int::default());
             symbol_ids.$read_func(reader).await?;
 
@@ -884,17 +1051,73 @@ impl<S> Vector<S>
 where
     S: DecimalInterface,
 {
-    pub(crate) fn max_scale(&self) -> u32 {
-        self.iter().filter_map(|d| d.scale()).max().unwrap_or(0)
+    /// Constructs a new, empty decimal [`Vector`] of a column with `scale`, e.g. 4 for
+    /// `DECIMAL64(4)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scale` is larger than the type allows.
+    pub fn with_scale(scale: u32) -> Result<Self> {
+        let mut res = Self::new();
+        res.set_scale(scale)?;
+        Ok(res)
     }
 
-    pub(crate) fn scale_to_same(&mut self) {
-        let max_scale = self.iter().filter_map(|d| d.scale()).max();
+    /// Returns the declared scale, `None` if the scale follows the values.
+    pub fn scale(&self) -> Option<u32> {
+        self.scale
+    }
 
-        if let Some(new_scale) = max_scale {
-            self.iter_mut().for_each(|s| s.rescale(new_scale));
+    /// Declares the scale of the vector and rounds existing values to it.
+    ///
+    /// Vectors without a declared scale serialize with the largest scale of their values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the vector unchanged if `scale` is larger than the type
+    /// allows or a value can't be represented at it.
+    pub fn set_scale(&mut self, scale: u32) -> Result<()> {
+        if scale > S::MAX_SCALE {
+            return Err(Error::ConstraintsViolated(format!(
+                "scale of {} must be in [0, {}], got {}",
+                S::data_type(),
+                S::MAX_SCALE,
+                scale
+            )));
+        }
+
+        let data = self
+            .data
+            .iter()
+            .map(|d| d.round(scale, RoundingStrategy::MidpointAwayFromZero))
+            .collect::<Result<Vec<_>>>()?;
+        self.data = data;
+        self.scale = Some(scale);
+        Ok(())
+    }
+
+    /// Appends `value` rounded half away from zero to the declared scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` can't be represented at the declared scale.
+    pub fn try_push(&mut self, value: S) -> Result<()> {
+        let value = self.fit(value)?;
+        self.data.push(value);
+        Ok(())
+    }
+
+    // rounds to the declared scale, if any.
+    pub(crate) fn fit(&self, value: S) -> Result<S> {
+        match self.scale {
+            Some(scale) => value.round(scale, RoundingStrategy::MidpointAwayFromZero),
+            None => Ok(value),
         }
     }
+
+    pub(crate) fn max_scale(&self) -> u32 {
+        self.iter().filter_map(|d| d.scale()).max().unwrap_or(0)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -908,6 +1131,12 @@ pub enum PrimitiveType {
     F32(f32),
     F64(f64),
     String(String),
+    Symbol(String),
+    Decimal(rust_decimal::Decimal),
+    Blob(Vec<u8>),
+    I128(i128),
+    U128(u128),
+    IpAddr(std::net::IpAddr),
     VecI8(Vec<i8>),
     VecI16(Vec<i16>),
     VecI32(Vec<i32>),
@@ -917,6 +1146,14 @@ pub enum PrimitiveType {
     NaiveDateTime(NaiveDateTime),
     NaiveDate(NaiveDate),
     NaiveTime(NaiveTime),
+    VecBool(Vec<bool>),
+    VecNaiveDateTime(Vec<NaiveDateTime>),
+    VecNaiveDate(Vec<NaiveDate>),
+    VecNaiveTime(Vec<NaiveTime>),
+    VecDecimal(Vec<rust_decimal::Decimal>),
+    VecI128(Vec<i128>),
+    VecU128(Vec<u128>),
+    VecIpAddr(Vec<std::net::IpAddr>),
 }
 
 impl Display for PrimitiveType {
@@ -930,6 +1167,12 @@ impl Display for PrimitiveType {
             PrimitiveType::F32(_) => "f32(Float)",
             PrimitiveType::F64(_) => "f64(Double)",
             PrimitiveType::String(_) => "String(String)",
+            PrimitiveType::Symbol(_) => "String(Symbol)",
+            PrimitiveType::Decimal(_) => "Decimal(Decimal32/Decimal64/Decimal128)",
+            PrimitiveType::Blob(_) => "Vec<u8>(Blob)",
+            PrimitiveType::I128(_) => "i128(Int128)",
+            PrimitiveType::U128(_) => "u128(Uuid)",
+            PrimitiveType::IpAddr(_) => "IpAddr(IpAddr)",
             PrimitiveType::VecI8(_) => "Vec<i8>(Char[])",
             PrimitiveType::VecI16(_) => "Vec<i16>(Short[])",
             PrimitiveType::VecI32(_) => "Vec<i32>(Int[])",
@@ -938,7 +1181,15 @@ impl Display for PrimitiveType {
             PrimitiveType::VecF64(_) => "Vec<f64>(Double[])",
             PrimitiveType::NaiveDateTime(_) => "NaiveDateTime",
             PrimitiveType::NaiveDate(_) => "NaiveDate",
-            PrimitiveType::NaiveTime(_) => "NaiveDate",
+            PrimitiveType::NaiveTime(_) => "NaiveTime",
+            PrimitiveType::VecBool(_) => "Vec<bool>(Bool[])",
+            PrimitiveType::VecNaiveDateTime(_) => "Vec<NaiveDateTime>",
+            PrimitiveType::VecNaiveDate(_) => "Vec<NaiveDate>",
+            PrimitiveType::VecNaiveTime(_) => "Vec<NaiveTime>",
+            PrimitiveType::VecDecimal(_) => "Vec<Decimal>",
+            PrimitiveType::VecI128(_) => "Vec<i128>(Int128[])",
+            PrimitiveType::VecU128(_) => "Vec<u128>(Uuid[])",
+            PrimitiveType::VecIpAddr(_) => "Vec<IpAddr>(IpAddr[])",
             PrimitiveType::None => "None",
         };
         write!(f, "{type_name}")
@@ -978,6 +1229,31 @@ impl From<()> for PrimitiveType {
     }
 }
 
+impl<T: Into<PrimitiveType>> From<Option<T>> for PrimitiveType {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::None, Into::into)
+    }
+}
+
+impl From<u8> for PrimitiveType {
+    fn from(value: u8) -> Self {
+        Self::I8(value as i8)
+    }
+}
+
+// (rust type, PrimitiveType name) of scalar only types
+macro_rules! for_scalar_types {
+    ($macro:tt) => {
+        $macro!(
+            (rust_decimal::Decimal, Decimal),
+            (Vec<u8>, Blob),
+            (i128, I128),
+            (u128, U128),
+            (std::net::IpAddr, IpAddr)
+        );
+    };
+}
+
 macro_rules! from_for_primitive_type {
     ($type_name:ident, $enum_name:ident) => {
         impl From<$type_name> for PrimitiveType {
@@ -1017,8 +1293,50 @@ macro_rules! from_for_array_type {
     };
 }
 
+// (rust type, PrimitiveType name) of array vector only types
+macro_rules! for_vec_types {
+    ($macro:tt) => {
+        $macro!(
+            (bool, VecBool),
+            (NaiveDateTime, VecNaiveDateTime),
+            (NaiveDate, VecNaiveDate),
+            (NaiveTime, VecNaiveTime),
+            (rust_decimal::Decimal, VecDecimal),
+            (i128, VecI128),
+            (u128, VecU128),
+            (std::net::IpAddr, VecIpAddr)
+        );
+    };
+}
+
+macro_rules! from_for_vec_type {
+    ($(($type_name:ty, $enum_name:ident)), *) => {
+        $(
+            impl From<Vec<$type_name>> for PrimitiveType {
+                fn from(value: Vec<$type_name>) -> Self {
+                    Self::$enum_name(value)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! from_for_scalar_type {
+    ($(($type_name:ty, $enum_name:ident)), *) => {
+        $(
+            impl From<$type_name> for PrimitiveType {
+                fn from(value: $type_name) -> Self {
+                    Self::$enum_name(value)
+                }
+            }
+        )*
+    };
+}
+
 for_primitive_types!(from_for_primitive_type);
 for_array_types!(from_for_array_type);
+for_vec_types!(from_for_vec_type);
+for_scalar_types!(from_for_scalar_type);
 
 impl VectorImpl {
     pub fn push(&mut self, value: ConstantImpl) -> Result<(), String> {
@@ -1040,6 +1358,7 @@ impl VectorImpl {
             _ => unreachable!(),
         };
 
+        let s = self.fit_scale(s).map_err(|e| e.to_string())?;
         self.push_scalar(s);
         Ok(())
     }
@@ -1077,7 +1396,7 @@ impl VectorImpl {
                 }
                 if let VectorImpl::ArrayVector(ArrayVectorImpl::$ddb_type(a)) = self {
                     if let PrimitiveType::$array_enum_name(tmp) = value {
-                        a.push(tmp);
+                        a.push(tmp.into_iter().map(Into::into).collect());
                         return Ok(());
                     }
                 }
@@ -1090,13 +1409,113 @@ impl VectorImpl {
             };
         }
 
+        // converts each element with `$convert`, `None` means it is out of range.
+        macro_rules! push_vec_type {
+            ($(($ddb_type:ident, $enum_name:ident, $convert:expr)), *) => {
+                $(
+                    if let VectorImpl::ArrayVector(ArrayVectorImpl::$ddb_type(a)) = self {
+                        if let PrimitiveType::$enum_name(tmp) = &value {
+                            if let Some(row) = tmp.iter().map($convert).collect::<Option<Vec<_>>>() {
+                                a.push(row);
+                                return Ok(());
+                            }
+                        }
+                    }
+                )*
+            };
+        }
+
+        // converts with `$convert`, nulls push the null of the vector.
+        macro_rules! push_scalar_type {
+            ($(($ddb_type:ident, $enum_name:ident, $convert:expr)), *) => {
+                $(
+                    if let VectorImpl::$ddb_type(v) = self {
+                        if let PrimitiveType::$enum_name(tmp) = value {
+                            v.push($convert(tmp));
+                            return Ok(());
+                        } else if let PrimitiveType::None = value {
+                            v.push(Default::default());
+                            return Ok(());
+                        }
+                    }
+                )*
+            };
+        }
+
+        // rounds to the declared scale before the exact conversion.
+        macro_rules! push_decimal_type {
+            ($($ddb_type:ident), *) => {
+                $(
+                    if let VectorImpl::$ddb_type(v) = self {
+                        if let PrimitiveType::Decimal(d) = value {
+                            let d = match v.scale() {
+                                Some(scale) => d.round_dp_with_strategy(
+                                    scale,
+                                    RoundingStrategy::MidpointAwayFromZero,
+                                ),
+                                None => d,
+                            };
+                            return v.try_push($ddb_type::try_from(d)?);
+                        } else if let PrimitiveType::None = value {
+                            v.push(Default::default());
+                            return Ok(());
+                        }
+                    }
+                )*
+            };
+        }
+
         for_primitive_types!(push_simple_type);
         for_array_types!(push_array_type);
+        push_scalar_type!(
+            (String, Symbol, DolphinString::new),
+            (Symbol, Symbol, Symbol::new),
+            (Symbol, String, Symbol::new),
+            (Blob, Blob, Blob::new),
+            (Int128, I128, Int128::new),
+            (Uuid, U128, Uuid::new),
+            (IpAddr, IpAddr, IpAddr::new)
+        );
+        push_decimal_type!(Decimal32, Decimal64, Decimal128);
+        push_vec_type!(
+            (Bool, VecBool, |b| Some(Bool::new(*b))),
+            (Date, VecNaiveDate, |d| Some(Date::new(*d))),
+            (Month, VecNaiveDate, |d| Some(Month::new(*d))),
+            (Time, VecNaiveTime, |t| Some(Time::new(*t))),
+            (Minute, VecNaiveTime, |t| Some(Minute::new(*t))),
+            (Second, VecNaiveTime, |t| Some(Second::new(*t))),
+            (NanoTime, VecNaiveTime, |t| Some(NanoTime::new(*t))),
+            (DateTime, VecNaiveDateTime, |dt| Some(DateTime::new(*dt))),
+            (Timestamp, VecNaiveDateTime, |dt| Some(Timestamp::new(*dt))),
+            (NanoTimestamp, VecNaiveDateTime, |dt| Some(
+                NanoTimestamp::new(*dt)
+            )),
+            (DateHour, VecNaiveDateTime, |dt| Some(DateHour::new(*dt))),
+            (Decimal32, VecDecimal, |d| i32::try_from(d.mantissa())
+                .ok()
+                .and_then(|m| Decimal32::from_raw(m, d.scale()))),
+            (Decimal64, VecDecimal, |d| i64::try_from(d.mantissa())
+                .ok()
+                .and_then(|m| Decimal64::from_raw(m, d.scale()))),
+            (Decimal128, VecDecimal, |d| Decimal128::from_raw(
+                d.mantissa(),
+                d.scale()
+            )),
+            (Int128, VecI128, |v| Some(Int128::new(*v))),
+            (Uuid, VecU128, |v| Some(Uuid::new(*v))),
+            (IpAddr, VecIpAddr, |v| Some(IpAddr::new(*v)))
+        );
+
+        // a null row can't be serialized, DolphinDB stores a null value as a single null element.
+        if let (VectorImpl::ArrayVector(a), PrimitiveType::None) = (&mut *self, &value) {
+            a.push_null_element();
+            return Ok(());
+        }
 
-        return Err(Error::InvalidConvert {
+        Err(Error::InvalidConvert {
             from: value.to_string(),
             to: self.data_type().to_string(),
-        });
+        })
     }
 
     pub fn push_unchecked(&mut self, value: ConstantImpl) {
@@ -1111,11 +1530,65 @@ impl VectorImpl {
 
         let s = match value {
             ConstantImpl::Scalar(s) => s,
+            ConstantImpl::Vector(row) => {
+                let VectorImpl::ArrayVector(a) = self else {
+                    unreachable!()
+                };
+                a.push_row(&row)
+                    .unwrap_or_else(|e| panic!("invalid push: {}", e));
+                return;
+            }
             _ => unreachable!(),
         };
 
+        let s = self
+            .fit_scale(s)
+            .unwrap_or_else(|e| panic!("invalid push: {}", e));
         self.push_scalar(s);
     }
+
+    /// Returns the declared scale of a decimal vector or decimal array vector.
+    pub fn decimal_scale(&self) -> Option<u32> {
+        match self {
+            VectorImpl::Decimal32(v) => v.scale(),
+            VectorImpl::Decimal64(v) => v.scale(),
+            VectorImpl::Decimal128(v) => v.scale(),
+            VectorImpl::ArrayVector(ArrayVectorImpl::Decimal32(v)) => v.scale(),
+            VectorImpl::ArrayVector(ArrayVectorImpl::Decimal64(v)) => v.scale(),
+            VectorImpl::ArrayVector(ArrayVectorImpl::Decimal128(v)) => v.scale(),
+            _ => None,
+        }
+    }
+
+    /// Declares the scale of a decimal vector, see [`Vector::set_scale`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vector is not a decimal vector or decimal array vector.
+    pub fn set_decimal_scale(&mut self, scale: u32) -> Result<()> {
+        match self {
+            VectorImpl::Decimal32(v) => v.set_scale(scale),
+            VectorImpl::Decimal64(v) => v.set_scale(scale),
+            VectorImpl::Decimal128(v) => v.set_scale(scale),
+            VectorImpl::ArrayVector(ArrayVectorImpl::Decimal32(v)) => v.set_scale(scale),
+            VectorImpl::ArrayVector(ArrayVectorImpl::Decimal64(v)) => v.set_scale(scale),
+            VectorImpl::ArrayVector(ArrayVectorImpl::Decimal128(v)) => v.set_scale(scale),
+            _ => Err(Error::InvalidConvert {
+                from: self.data_type().to_string(),
+                to: "decimal".into(),
+            }),
+        }
+    }
+
+    // rounds decimals to the declared scale of the vector.
+    pub(crate) fn fit_scale(&self, value: ScalarImpl) -> Result<ScalarImpl> {
+        match (self, value) {
+            (VectorImpl::Decimal32(v), ScalarImpl::Decimal32(d)) => v.fit(d).map(Into::into),
+            (VectorImpl::Decimal64(v), ScalarImpl::Decimal64(d)) => v.fit(d).map(Into::into),
+            (VectorImpl::Decimal128(v), ScalarImpl::Decimal128(d)) => v.fit(d).map(Into::into),
+            (_, value) => Ok(value),
+        }
+    }
 }
 
 macro_rules! deserialize_vector {
@@ -1142,8 +1615,11 @@ macro_rules! deserialize_vector {
                 return Ok(VectorImpl::Symbol(s));
             }
 
-            let data_type = data_type.try_into().unwrap();
-            let mut vecs = VectorImpl::from_type(data_type).unwrap();
+            let data_type: DataType = data_type.try_into()?;
+            let mut vecs = VectorImpl::from_type(data_type).ok_or_else(|| Error::Unsupported {
+                data_form: DataForm::Vector.to_string(),
+                data_type: data_type.to_string(),
+            })?;
 
             vecs.$deserialize_func(reader).await?;
 
@@ -0,0 +1,51 @@
+use super::{ConstantImpl, DataForm, DataType, ScalarImpl, VectorImpl};
+use crate::error::{Error, Result};
+
+impl VectorImpl {
+    /// Converts every element to `data_type`, see [`ScalarImpl::cast`] for the rules.
+    ///
+    /// Any vectors and array vectors are not supported.
+    pub fn cast(&self, data_type: DataType) -> Result<VectorImpl> {
+        self.cast_each(data_type, |s| s.cast(data_type))
+    }
+
+    /// Converts every element to the decimal type `data_type` with `scale`,
+    /// see [`ScalarImpl::cast_decimal`].
+    pub fn cast_decimal(&self, data_type: DataType, scale: u32) -> Result<VectorImpl> {
+        let mut res = self.cast_each(data_type, |s| s.cast_decimal(data_type, scale))?;
+        res.set_decimal_scale(scale)?;
+        Ok(res)
+    }
+
+    fn cast_each(
+        &self,
+        data_type: DataType,
+        cast: impl Fn(&ScalarImpl) -> Result<ScalarImpl>,
+    ) -> Result<VectorImpl> {
+        let unsupported = || Error::Unsupported {
+            data_form: DataForm::Vector.to_string(),
+            data_type: data_type.to_string(),
+        };
+
+        if matches!(self, VectorImpl::Any(_) | VectorImpl::ArrayVector(_)) {
+            return Err(unsupported());
+        }
+
+        let mut res = match VectorImpl::from_type(data_type) {
+            Some(VectorImpl::Any(_)) | Some(VectorImpl::ArrayVector(_)) | None => {
+                return Err(unsupported())
+            }
+            Some(v) => v,
+        };
+
+        for i in 0..self.len() {
+            let s = match self.get(i) {
+                Some(ConstantImpl::Scalar(s)) => s,
+                _ => unreachable!(),
+            };
+            res.push_scalar(cast(&s)?);
+        }
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,758 @@
+//! Client side computations over [`VectorImpl`] following DolphinDB null semantics.
+
+use std::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use super::super::{
+    decimal::*, primitive::*, ConstantImpl, DataForm, DataType, Scalar, ScalarImpl, Vector,
+    VectorImpl,
+};
+use crate::error::{Error, Result};
+
+// every vector type whose elements have a total order, nulls are the smallest.
+macro_rules! for_ordered_vectors {
+    ($macro:tt) => {
+        $macro!(
+            Void,
+            Bool,
+            Date,
+            Month,
+            Time,
+            Minute,
+            Second,
+            DateTime,
+            Timestamp,
+            NanoTime,
+            NanoTimestamp,
+            Symbol,
+            String,
+            DateHour,
+            Duration,
+            Decimal32,
+            Decimal64,
+            Decimal128,
+            Char,
+            Short,
+            Int,
+            Long,
+            Float,
+            Double,
+            Blob,
+            Int128,
+            Uuid,
+            IpAddr
+        );
+    };
+}
+
+macro_rules! dispatch_order {
+    ($($enum_name:ident), *) => {
+        impl VectorImpl {
+            // compares elements by position.
+            pub(crate) fn comparator(&self) -> Result<Box<dyn Fn(usize, usize) -> Ordering + '_>> {
+                match self {
+                    $(
+                        VectorImpl::$enum_name(v) => Ok(Box::new(move |i, j| v[i].cmp(&v[j]))),
+                    )*
+                    _ => Err(self.unsupported()),
+                }
+            }
+
+            pub(crate) fn is_null_at(&self, index: usize) -> Result<bool> {
+                match self {
+                    $(
+                        VectorImpl::$enum_name(v) => Ok(v[index].is_null()),
+                    )*
+                    VectorImpl::Any(v) => Ok(v[index].is_null()),
+                    _ => Err(self.unsupported()),
+                }
+            }
+        }
+    };
+}
+
+for_ordered_vectors!(dispatch_order);
+
+impl VectorImpl {
+    pub(crate) fn unsupported(&self) -> Error {
+        Error::Unsupported {
+            data_form: DataForm::Vector.to_string(),
+            data_type: self.data_type().to_string(),
+        }
+    }
+
+    // number of non-null elements at `indices`.
+    pub(crate) fn count_of(&self, indices: &[usize]) -> Result<usize> {
+        let mut count = 0;
+        for &i in indices {
+            if !self.is_null_at(i)? {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    // sum of non-null elements at `indices`, integers widen to LONG and floats to DOUBLE,
+    // decimals widen to the next decimal width. null if all elements are null.
+    pub(crate) fn sum_of(&self, indices: &[usize]) -> Result<ScalarImpl> {
+        macro_rules! sum_integral {
+            ($v:expr) => {{
+                let mut acc: Option<i64> = None;
+                for v in indices.iter().filter_map(|&i| $v[i].into_inner()) {
+                    acc = Some(acc.unwrap_or(0).wrapping_add(v as i64));
+                }
+                ScalarImpl::Long(acc.map_or_else(Long::default, Long::new))
+            }};
+        }
+
+        macro_rules! sum_floating {
+            ($v:expr) => {{
+                let mut acc: Option<f64> = None;
+                for v in indices.iter().filter_map(|&i| $v[i].into_inner()) {
+                    acc = Some(acc.unwrap_or(0.0) + v as f64);
+                }
+                ScalarImpl::Double(acc.map_or_else(Double::default, Double::new))
+            }};
+        }
+
+        macro_rules! sum_decimal {
+            ($v:expr, $enum_name:ident) => {{
+                let mut acc: Option<Decimal> = None;
+                for v in indices.iter().filter_map(|&i| $v[i].into_inner()) {
+                    acc = Some(
+                        acc.unwrap_or_default()
+                            .checked_add(v)
+                            .ok_or(Error::ConstraintsViolated("decimal sum overflow".into()))?,
+                    );
+                }
+                ScalarImpl::$enum_name($enum_name(acc))
+            }};
+        }
+
+        Ok(match self {
+            VectorImpl::Char(v) => sum_integral!(v),
+            VectorImpl::Short(v) => sum_integral!(v),
+            VectorImpl::Int(v) => sum_integral!(v),
+            VectorImpl::Long(v) => sum_integral!(v),
+            VectorImpl::Float(v) => sum_floating!(v),
+            VectorImpl::Double(v) => sum_floating!(v),
+            VectorImpl::Decimal32(v) => sum_decimal!(v, Decimal64),
+            VectorImpl::Decimal64(v) => sum_decimal!(v, Decimal128),
+            VectorImpl::Decimal128(v) => sum_decimal!(v, Decimal128),
+            _ => return Err(self.unsupported()),
+        })
+    }
+
+    // mean of non-null elements at `indices` as DOUBLE, null if all elements are null.
+    pub(crate) fn avg_of(&self, indices: &[usize]) -> Result<Double> {
+        let count = self.count_of(indices)?;
+        let sum = match self.sum_of(indices)? {
+            ScalarImpl::Long(v) => v.into_inner().map(|v| v as f64),
+            ScalarImpl::Double(v) => v.into_inner(),
+            ScalarImpl::Decimal64(v) => v.into_inner().and_then(|v| v.to_f64()),
+            ScalarImpl::Decimal128(v) => v.into_inner().and_then(|v| v.to_f64()),
+            _ => unreachable!(),
+        };
+
+        Ok(match sum {
+            Some(sum) if count > 0 => Double::new(sum / count as f64),
+            _ => Double::default(),
+        })
+    }
+
+    // position of the smallest (or largest) non-null element at `indices`, or of a null element
+    // if all of them are null.
+    pub(crate) fn extreme_of(&self, indices: &[usize], max: bool) -> Result<Option<usize>> {
+        let cmp = self.comparator()?;
+        let mut res: Option<usize> = None;
+
+        for &i in indices {
+            if self.is_null_at(i)? {
+                res = res.or(Some(i));
+                continue;
+            }
+
+            res = match res {
+                Some(r) if !self.is_null_at(r)? => {
+                    let ord = cmp(i, r);
+                    if (max && ord == Ordering::Greater) || (!max && ord == Ordering::Less) {
+                        Some(i)
+                    } else {
+                        Some(r)
+                    }
+                }
+                _ => Some(i),
+            };
+        }
+
+        Ok(res)
+    }
+}
+
+impl VectorImpl {
+    /// Sum of non-null elements, integers widen to LONG, floats to DOUBLE and decimals to the next
+    /// decimal width. Returns a null scalar if all elements are null.
+    pub fn sum(&self) -> Result<ScalarImpl> {
+        self.sum_of(&self.all_indices())
+    }
+
+    /// Mean of non-null elements.
+    pub fn avg(&self) -> Result<Double> {
+        self.avg_of(&self.all_indices())
+    }
+
+    /// Sample standard deviation of non-null elements, null if there are fewer than 2 of them.
+    pub fn std(&self) -> Result<Double> {
+        let values = match self.lanes()? {
+            Lanes::Integral(v) => v.into_iter().flatten().map(|v| v as f64).collect(),
+            Lanes::Floating(v) => v.into_iter().flatten().collect(),
+            Lanes::Decimal(v) => v.into_iter().flatten().filter_map(|v| v.to_f64()).collect(),
+        };
+
+        Ok(std_of(values))
+    }
+
+    /// Number of non-null elements.
+    pub fn count(&self) -> Result<usize> {
+        self.count_of(&self.all_indices())
+    }
+
+    /// Smallest non-null element, a null scalar if all elements are null.
+    pub fn min(&self) -> Result<ScalarImpl> {
+        self.extreme(false)
+    }
+
+    /// Largest non-null element, a null scalar if all elements are null.
+    pub fn max(&self) -> Result<ScalarImpl> {
+        self.extreme(true)
+    }
+
+    /// Cumulative sums typed like [`sum`](Self::sum). Null elements repeat the previous sum.
+    pub fn cumsum(&self) -> Result<VectorImpl> {
+        let data_type = self.sum_of(&[])?.data_type();
+
+        let lanes = match self.lanes()? {
+            Lanes::Integral(v) => Lanes::Integral(running(v, |a, b| Some(a.wrapping_add(b)))),
+            Lanes::Floating(v) => Lanes::Floating(running(v, |a, b| Some(a + b))),
+            Lanes::Decimal(v) => Lanes::Decimal(running(v, |a, b| a.checked_add(b))),
+        };
+
+        if let Lanes::Decimal(ref v) = lanes {
+            if v.iter().flatten().count() < self.count()? {
+                return Err(Error::ConstraintsViolated("decimal sum overflow".into()));
+            }
+        }
+
+        lanes.into_vector(data_type)
+    }
+
+    /// Element-wise `self + rhs`, see [`div`](Self::div) for the rules.
+    pub fn add<'a>(&self, rhs: impl Into<Operand<'a>>) -> Result<VectorImpl> {
+        self.arithmetic(rhs.into(), Operator::Add)
+    }
+
+    /// Element-wise `self - rhs`, see [`div`](Self::div) for the rules.
+    pub fn sub<'a>(&self, rhs: impl Into<Operand<'a>>) -> Result<VectorImpl> {
+        self.arithmetic(rhs.into(), Operator::Sub)
+    }
+
+    /// Element-wise `self * rhs`, see [`div`](Self::div) for the rules.
+    pub fn mul<'a>(&self, rhs: impl Into<Operand<'a>>) -> Result<VectorImpl> {
+        self.arithmetic(rhs.into(), Operator::Mul)
+    }
+
+    /// Element-wise `self / rhs` with a vector of the same length or a scalar.
+    ///
+    /// As in DolphinDB, a null operand or a zero divisor gives null, integer division rounds down
+    /// and integers keep the wider of both types. FLOAT with FLOAT stays FLOAT, other floating
+    /// operands give DOUBLE. Decimals with integers or decimals keep the wider decimal type, the
+    /// scale is the larger scale of both operands, or their sum for multiplication.
+    pub fn div<'a>(&self, rhs: impl Into<Operand<'a>>) -> Result<VectorImpl> {
+        self.arithmetic(rhs.into(), Operator::Div)
+    }
+
+    fn all_indices(&self) -> Vec<usize> {
+        (0..self.len()).collect()
+    }
+
+    fn extreme(&self, max: bool) -> Result<ScalarImpl> {
+        let index = self.extreme_of(&self.all_indices(), max)?;
+
+        let value = match index {
+            Some(i) => self.get(i),
+            // a null element of the same type.
+            None => {
+                let mut null = self.take(&[]);
+                null.resize(1);
+                null.get(0)
+            }
+        };
+
+        match value {
+            Some(ConstantImpl::Scalar(s)) => Ok(s),
+            _ => Err(self.unsupported()),
+        }
+    }
+
+    fn lanes(&self) -> Result<Lanes> {
+        macro_rules! integral {
+            ($v:expr) => {
+                Lanes::Integral(
+                    $v.iter()
+                        .map(|x| x.into_inner().map(|x| x as i64))
+                        .collect(),
+                )
+            };
+        }
+
+        macro_rules! floating {
+            ($v:expr) => {
+                Lanes::Floating(
+                    $v.iter()
+                        .map(|x| x.into_inner().map(|x| x as f64))
+                        .collect(),
+                )
+            };
+        }
+
+        macro_rules! decimal {
+            ($v:expr) => {
+                Lanes::Decimal($v.iter().map(|x| x.into_inner()).collect())
+            };
+        }
+
+        Ok(match self {
+            VectorImpl::Char(v) => integral!(v),
+            VectorImpl::Short(v) => integral!(v),
+            VectorImpl::Int(v) => integral!(v),
+            VectorImpl::Long(v) => integral!(v),
+            VectorImpl::Float(v) => floating!(v),
+            VectorImpl::Double(v) => floating!(v),
+            VectorImpl::Decimal32(v) => decimal!(v),
+            VectorImpl::Decimal64(v) => decimal!(v),
+            VectorImpl::Decimal128(v) => decimal!(v),
+            _ => return Err(self.unsupported()),
+        })
+    }
+
+    fn arithmetic(&self, rhs: Operand<'_>, op: Operator) -> Result<VectorImpl> {
+        let (rhs, broadcast) = match rhs {
+            Operand::Vector(v) => (v.clone(), false),
+            Operand::Scalar(s) => {
+                let mut v = VectorImpl::from_type(s.data_type()).ok_or(Error::Unsupported {
+                    data_form: DataForm::Scalar.to_string(),
+                    data_type: s.data_type().to_string(),
+                })?;
+                v.push_scalar(s.clone());
+                (v, true)
+            }
+        };
+
+        if !broadcast && rhs.len() != self.len() {
+            return Err(Error::ConstraintsViolated("mismatch vector size".into()));
+        }
+
+        let data_type =
+            arithmetic_type(self.data_type(), rhs.data_type()).ok_or(Error::InvalidConvert {
+                from: rhs.data_type().to_string(),
+                to: self.data_type().to_string(),
+            })?;
+
+        let (lhs, rhs) = (self.lanes()?, rhs.lanes()?);
+        let at = |i: usize| if broadcast { 0 } else { i };
+
+        let lanes = match data_type {
+            DataType::Float | DataType::Double => {
+                let (lhs, rhs) = (lhs.into_floating(), rhs.into_floating());
+                Lanes::Floating(
+                    (0..lhs.len())
+                        .map(|i| zip_with(lhs[i], rhs[at(i)], |a, b| op.floating(a, b)))
+                        .collect(),
+                )
+            }
+            DataType::Decimal32 | DataType::Decimal64 | DataType::Decimal128 => {
+                let (lhs, rhs) = (lhs.into_decimal(), rhs.into_decimal());
+                let mut res = Vec::with_capacity(lhs.len());
+                for i in 0..lhs.len() {
+                    res.push(match (lhs[i], rhs[at(i)]) {
+                        (Some(a), Some(b)) => op.decimal(a, b)?,
+                        _ => None,
+                    });
+                }
+                Lanes::Decimal(res)
+            }
+            _ => {
+                let (lhs, rhs) = (lhs.into_integral(), rhs.into_integral());
+                Lanes::Integral(
+                    (0..lhs.len())
+                        .map(|i| zip_with(lhs[i], rhs[at(i)], |a, b| op.integral(a, b)))
+                        .collect(),
+                )
+            }
+        };
+
+        lanes.into_vector(data_type)
+    }
+}
+
+/// Right hand side of element-wise arithmetic on [`VectorImpl`].
+#[derive(Debug, Clone, Copy)]
+pub enum Operand<'a> {
+    Vector(&'a VectorImpl),
+    Scalar(&'a ScalarImpl),
+}
+
+impl<'a> From<&'a VectorImpl> for Operand<'a> {
+    fn from(value: &'a VectorImpl) -> Self {
+        Operand::Vector(value)
+    }
+}
+
+impl<'a> From<&'a ScalarImpl> for Operand<'a> {
+    fn from(value: &'a ScalarImpl) -> Self {
+        Operand::Scalar(value)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operator {
+    fn integral(&self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Operator::Add => Some(a.wrapping_add(b)),
+            Operator::Sub => Some(a.wrapping_sub(b)),
+            Operator::Mul => Some(a.wrapping_mul(b)),
+            Operator::Div => {
+                let q = a.checked_div(b)?;
+                // rounds down.
+                if a % b != 0 && ((a < 0) != (b < 0)) {
+                    Some(q - 1)
+                } else {
+                    Some(q)
+                }
+            }
+        }
+    }
+
+    fn floating(&self, a: f64, b: f64) -> Option<f64> {
+        match self {
+            Operator::Add => Some(a + b),
+            Operator::Sub => Some(a - b),
+            Operator::Mul => Some(a * b),
+            Operator::Div if b == 0.0 => None,
+            Operator::Div => Some(a / b),
+        }
+    }
+
+    fn decimal(&self, a: Decimal, b: Decimal) -> Result<Option<Decimal>> {
+        let res = match self {
+            Operator::Add => a.checked_add(b),
+            Operator::Sub => a.checked_sub(b),
+            Operator::Mul => a.checked_mul(b),
+            Operator::Div if b.is_zero() => return Ok(None),
+            Operator::Div => a.checked_div(b).map(|mut d| {
+                d.rescale(a.scale().max(b.scale()));
+                d
+            }),
+        };
+
+        res.map(Some)
+            .ok_or(Error::ConstraintsViolated("decimal overflow".into()))
+    }
+}
+
+// numeric elements widened for computation.
+enum Lanes {
+    Integral(Vec<Option<i64>>),
+    Floating(Vec<Option<f64>>),
+    Decimal(Vec<Option<Decimal>>),
+}
+
+impl Lanes {
+    fn into_integral(self) -> Vec<Option<i64>> {
+        match self {
+            Lanes::Integral(v) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    fn into_floating(self) -> Vec<Option<f64>> {
+        match self {
+            Lanes::Integral(v) => v.into_iter().map(|x| x.map(|x| x as f64)).collect(),
+            Lanes::Floating(v) => v,
+            Lanes::Decimal(v) => v.into_iter().map(|x| x.and_then(|x| x.to_f64())).collect(),
+        }
+    }
+
+    fn into_decimal(self) -> Vec<Option<Decimal>> {
+        match self {
+            Lanes::Integral(v) => v.into_iter().map(|x| x.map(Decimal::from)).collect(),
+            Lanes::Decimal(v) => v,
+            Lanes::Floating(_) => unreachable!(),
+        }
+    }
+
+    fn into_vector(self, data_type: DataType) -> Result<VectorImpl> {
+        macro_rules! native {
+            ($v:expr, $struct_name:ident, $raw_type:tt) => {
+                VectorImpl::$struct_name(
+                    $v.into_iter()
+                        .map(|x| {
+                            x.map_or_else($struct_name::default, |x| $struct_name(x as $raw_type))
+                        })
+                        .collect(),
+                )
+            };
+        }
+
+        macro_rules! decimal {
+            ($v:expr, $struct_name:ident, $raw_type:tt, $max_scale:expr) => {{
+                let mut res = Vector::with_capacity($v.len());
+                for x in $v {
+                    res.push(match x {
+                        None => $struct_name::default(),
+                        Some(d)
+                            if d.scale() > $max_scale
+                                || $raw_type::try_from(d.mantissa()).is_err() =>
+                        {
+                            return Err(Error::ConstraintsViolated(format!(
+                                "{} overflows {}",
+                                d,
+                                DataType::$struct_name
+                            )))
+                        }
+                        Some(d) => $struct_name(Some(d)),
+                    });
+                }
+                VectorImpl::$struct_name(res)
+            }};
+        }
+
+        Ok(match (self, data_type) {
+            (Lanes::Integral(v), DataType::Char) => native!(v, Char, i8),
+            (Lanes::Integral(v), DataType::Short) => native!(v, Short, i16),
+            (Lanes::Integral(v), DataType::Int) => native!(v, Int, i32),
+            (Lanes::Integral(v), DataType::Long) => native!(v, Long, i64),
+            (Lanes::Floating(v), DataType::Float) => native!(v, Float, f32),
+            (Lanes::Floating(v), DataType::Double) => native!(v, Double, f64),
+            (Lanes::Decimal(v), DataType::Decimal32) => decimal!(v, Decimal32, i32, 9),
+            (Lanes::Decimal(v), DataType::Decimal64) => decimal!(v, Decimal64, i64, 18),
+            (Lanes::Decimal(v), DataType::Decimal128) => decimal!(v, Decimal128, i128, 38),
+            (_, data_type) => {
+                return Err(Error::Unsupported {
+                    data_form: DataForm::Vector.to_string(),
+                    data_type: data_type.to_string(),
+                })
+            }
+        })
+    }
+}
+
+fn zip_with<T>(a: Option<T>, b: Option<T>, f: impl Fn(T, T) -> Option<T>) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => f(a, b),
+        _ => None,
+    }
+}
+
+// running fold skipping nulls, a null element repeats the previous result.
+fn running<T: Copy>(values: Vec<Option<T>>, f: impl Fn(T, T) -> Option<T>) -> Vec<Option<T>> {
+    let mut acc: Option<T> = None;
+    values
+        .into_iter()
+        .map(|v| {
+            if let Some(v) = v {
+                acc = match acc {
+                    Some(acc) => f(acc, v),
+                    None => Some(v),
+                };
+            }
+            acc
+        })
+        .collect()
+}
+
+fn std_of(values: Vec<f64>) -> Double {
+    if values.len() < 2 {
+        return Double::default();
+    }
+
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let var = values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (n - 1.0);
+    Double::new(var.sqrt())
+}
+
+// result type of arithmetic between numeric types.
+fn arithmetic_type(a: DataType, b: DataType) -> Option<DataType> {
+    fn rank(data_type: DataType) -> Option<u8> {
+        Some(match data_type {
+            DataType::Char => 0,
+            DataType::Short => 1,
+            DataType::Int => 2,
+            DataType::Long => 3,
+            DataType::Decimal32 => 4,
+            DataType::Decimal64 => 5,
+            DataType::Decimal128 => 6,
+            DataType::Float => 7,
+            DataType::Double => 8,
+            _ => return None,
+        })
+    }
+
+    let (ra, rb) = (rank(a)?, rank(b)?);
+
+    Some(match (ra.max(rb), ra.min(rb)) {
+        (7, 7) => DataType::Float,
+        (7.., _) => DataType::Double,
+        _ if ra >= rb => a,
+        _ => b,
+    })
+}
+
+macro_rules! vector_aggregate_impl {
+    ($(($struct_name:ident, $sum_type:ident, $acc_type:tt)), *) => {
+        $(
+            impl Vector<$struct_name> {
+                /// Sum of non-null elements, null if all elements are null.
+                pub fn sum(&self) -> $sum_type {
+                    self.iter()
+                        .filter_map(|x| x.into_inner())
+                        .fold(None, |acc: Option<$acc_type>, x| Some(vector_aggregate_impl!(@add $acc_type, acc.unwrap_or_default(), x as $acc_type)))
+                        .map_or_else($sum_type::default, $sum_type::new)
+                }
+
+                /// Mean of non-null elements, null if all elements are null.
+                pub fn avg(&self) -> Double {
+                    let count = self.count();
+                    match self.sum().into_inner() {
+                        Some(sum) if count > 0 => Double::new(sum as f64 / count as f64),
+                        _ => Double::default(),
+                    }
+                }
+
+                /// Sample standard deviation of non-null elements, null if there are fewer than 2
+                /// of them.
+                pub fn std(&self) -> Double {
+                    std_of(self.iter().filter_map(|x| x.into_inner()).map(|x| x as f64).collect())
+                }
+
+                /// Cumulative sums, null elements repeat the previous sum.
+                pub fn cumsum(&self) -> Vector<$sum_type> {
+                    running(
+                        self.iter().map(|x| x.into_inner().map(|x| x as $acc_type)).collect(),
+                        |a, b| Some(vector_aggregate_impl!(@add $acc_type, a, b)),
+                    )
+                    .into_iter()
+                    .map(|x| x.map_or_else($sum_type::default, $sum_type::new))
+                    .collect()
+                }
+            }
+        )*
+    };
+
+    (@add i64, $a:expr, $b:expr) => {
+        $a.wrapping_add($b)
+    };
+
+    (@add f64, $a:expr, $b:expr) => {
+        $a + $b
+    };
+}
+
+vector_aggregate_impl!(
+    (Char, Long, i64),
+    (Short, Long, i64),
+    (Int, Long, i64),
+    (Long, Long, i64),
+    (Float, Double, f64),
+    (Double, Double, f64)
+);
+
+impl<S: Scalar> Vector<S> {
+    /// Number of non-null elements.
+    pub fn count(&self) -> usize {
+        self.iter().filter(|x| !x.is_null()).count()
+    }
+}
+
+impl<S: Scalar + Ord> Vector<S> {
+    /// Smallest non-null element, `None` if all elements are null.
+    pub fn min(&self) -> Option<&S> {
+        self.iter().filter(|x| !x.is_null()).min()
+    }
+
+    /// Largest non-null element, `None` if all elements are null.
+    pub fn max(&self) -> Option<&S> {
+        self.iter().filter(|x| !x.is_null()).max()
+    }
+}
+
+macro_rules! vector_arithmetic_impl {
+    ($struct_name:ident, $raw_type:tt, $trait_name:ident, $func_name:ident, $op:expr) => {
+        impl $trait_name<&Vector<$struct_name>> for &Vector<$struct_name> {
+            type Output = Vector<$struct_name>;
+
+            /// # Panics
+            ///
+            /// Panics if the vectors differ in length.
+            fn $func_name(self, rhs: &Vector<$struct_name>) -> Self::Output {
+                assert_eq!(self.len(), rhs.len(), "mismatch vector size");
+                self.iter()
+                    .zip(rhs.iter())
+                    .map(|(a, b)| vector_arithmetic_impl!(@apply $struct_name, $raw_type, $op, *a, *b))
+                    .collect()
+            }
+        }
+
+        impl $trait_name<$struct_name> for &Vector<$struct_name> {
+            type Output = Vector<$struct_name>;
+
+            fn $func_name(self, rhs: $struct_name) -> Self::Output {
+                self.iter()
+                    .map(|a| vector_arithmetic_impl!(@apply $struct_name, $raw_type, $op, *a, rhs))
+                    .collect()
+            }
+        }
+    };
+
+    (@apply $struct_name:ident, $raw_type:tt, $op:expr, $a:expr, $b:expr) => {
+        match ($a.into_inner(), $b.into_inner()) {
+            (Some(a), Some(b)) => ($op)(a, b).map_or_else($struct_name::default, $struct_name::new),
+            _ => $struct_name::default(),
+        }
+    };
+
+    (@integral $(($struct_name:ident, $raw_type:tt)), *) => {
+        $(
+            vector_arithmetic_impl!($struct_name, $raw_type, Add, add, |a: $raw_type, b: $raw_type| Some(a.wrapping_add(b)));
+            vector_arithmetic_impl!($struct_name, $raw_type, Sub, sub, |a: $raw_type, b: $raw_type| Some(a.wrapping_sub(b)));
+            vector_arithmetic_impl!($struct_name, $raw_type, Mul, mul, |a: $raw_type, b: $raw_type| Some(a.wrapping_mul(b)));
+            vector_arithmetic_impl!($struct_name, $raw_type, Div, div, |a: $raw_type, b: $raw_type| Operator::Div
+                .integral(a as i64, b as i64)
+                .map(|q| q as $raw_type));
+        )*
+    };
+
+    (@floating $(($struct_name:ident, $raw_type:tt)), *) => {
+        $(
+            vector_arithmetic_impl!($struct_name, $raw_type, Add, add, |a: $raw_type, b: $raw_type| Some(a + b));
+            vector_arithmetic_impl!($struct_name, $raw_type, Sub, sub, |a: $raw_type, b: $raw_type| Some(a - b));
+            vector_arithmetic_impl!($struct_name, $raw_type, Mul, mul, |a: $raw_type, b: $raw_type| Some(a * b));
+            vector_arithmetic_impl!($struct_name, $raw_type, Div, div, |a: $raw_type, b: $raw_type| (b != 0.0).then(|| a / b));
+        )*
+    };
+}
+
+vector_arithmetic_impl!(@integral (Char, i8), (Short, i16), (Int, i32), (Long, i64));
+vector_arithmetic_impl!(@floating (Float, f32), (Double, f64));
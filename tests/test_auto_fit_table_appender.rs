@@ -0,0 +1,157 @@
+mod setup;
+
+use dolphindb::client::{AutoFitTableAppender, Client, ClientBuilder, UpsertOptions};
+use dolphindb::types::*;
+use setup::settings::Config;
+
+async fn connect() -> Client {
+    let conf = Config::new();
+    let mut builder = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    builder.connect().await.unwrap()
+}
+
+// a table of (INT, STRING, STRING, DOUBLE) to be fitted into the target table.
+fn rows(ids: &[i32], values: &[f64]) -> Table {
+    let mut id = IntVector::new();
+    let mut sym = StringVector::new();
+    let mut date = StringVector::new();
+    let mut value = DoubleVector::new();
+    for (i, v) in ids.iter().zip(values) {
+        id.push((*i).into());
+        sym.push(DolphinString::new(format!("s{}", i)));
+        date.push(DolphinString::new(format!("2024.01.0{}", i % 9 + 1)));
+        value.push((*v).into());
+    }
+    let mut builder = TableBuilder::new();
+    builder.with_contents(
+        vec![id.into(), sym.into(), date.into(), value.into()],
+        vec![
+            "id".to_string(),
+            "sym".to_string(),
+            "date".to_string(),
+            "value".to_string(),
+        ],
+    );
+    builder.build().unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_auto_fit_table_appender_append() {
+    const TABLE: &str = "test_auto_fit_table_appender_append";
+    let mut client = connect().await;
+    client
+        .run_script(
+            format!(
+                "share table(10:0, `id`sym`date`value, [LONG, SYMBOL, DATE, DECIMAL32(2)]) as `{TABLE}"
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+    let mut appender = AutoFitTableAppender::new(connect().await, TABLE)
+        .await
+        .unwrap();
+    let rows = appender
+        .append(&rows(&[1, 2, 3], &[1.5, 2.25, 3.0]))
+        .await
+        .unwrap();
+    assert_eq!(rows, 3);
+    let res = client
+        .run_script(
+            format!(
+                "eqObj({TABLE}, table(long(1 2 3) as id, symbol(`s1`s2`s3) as sym, 2024.01.02 2024.01.03 2024.01.04 as date, decimal32(1.5 2.25 3.0, 2) as value))"
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(res, Bool::new(true).into());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_auto_fit_table_appender_columns_error() {
+    const TABLE: &str = "test_auto_fit_table_appender_columns_error";
+    let mut client = connect().await;
+    client
+        .run_script(format!("share table(10:0, `id`value, [LONG, DOUBLE]) as `{TABLE}").as_str())
+        .await
+        .unwrap();
+    let mut appender = AutoFitTableAppender::new(connect().await, TABLE)
+        .await
+        .unwrap();
+    assert!(appender.append(&rows(&[1], &[1.0])).await.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_auto_fit_table_appender_upsert_keyed_table() {
+    const TABLE: &str = "test_auto_fit_table_appender_upsert_keyed_table";
+    let mut client = connect().await;
+    client
+        .run_script(
+            format!(
+                "share keyedTable(`id, 10:0, `id`sym`date`value, [LONG, SYMBOL, DATE, DOUBLE]) as `{TABLE}"
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+    let mut appender = AutoFitTableAppender::new(connect().await, TABLE)
+        .await
+        .unwrap();
+    appender.with_upsert(&UpsertOptions::default());
+    appender.append(&rows(&[1, 2], &[1.0, 2.0])).await.unwrap();
+    appender
+        .append(&rows(&[2, 3], &[20.0, 30.0]))
+        .await
+        .unwrap();
+    let res = client
+        .run_script(format!("exec value from {TABLE} order by id").as_str())
+        .await
+        .unwrap()
+        .unwrap();
+    let expect: VectorImpl = DoubleVector::from_raw(&[1.0, 20.0, 30.0]).into();
+    assert_eq!(res, expect.into());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_auto_fit_table_appender_upsert_dfs_table() {
+    const DB: &str = "dfs://test_auto_fit_table_appender_upsert_dfs_table";
+    let mut client = connect().await;
+    client
+        .run_script(
+            format!(
+                r#"
+                if (existsDatabase("{DB}")) {{ dropDatabase("{DB}") }}
+                db = database("{DB}", VALUE, 1..10, engine="TSDB")
+                t = table(10:0, `id`sym`date`value, [LONG, SYMBOL, DATE, DOUBLE])
+                db.createPartitionedTable(t, "pt", `id, sortColumns=`sym`date)
+            "#
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+    let mut appender = AutoFitTableAppender::new(connect().await, (DB, "pt"))
+        .await
+        .unwrap();
+    let mut options = UpsertOptions::default();
+    options
+        .with_key_columns(&["id"])
+        .with_ignore_null(true)
+        .with_sort_columns(&["date"]);
+    appender.with_upsert(&options);
+    appender.append(&rows(&[1, 2], &[1.0, 2.0])).await.unwrap();
+    appender
+        .append(&rows(&[2, 3], &[20.0, 30.0]))
+        .await
+        .unwrap();
+    let res = client
+        .run_script(format!(r#"exec value from loadTable("{DB}", "pt") order by id"#).as_str())
+        .await
+        .unwrap()
+        .unwrap();
+    let expect: VectorImpl = DoubleVector::from_raw(&[1.0, 20.0, 30.0]).into();
+    assert_eq!(res, expect.into());
+}
@@ -11,6 +11,46 @@ use setup::settings::Config;
 mod test_client_client_builder {
     use super::*;
 
+    #[tokio::test]
+    async fn test_client_client_builder_timezone() {
+        use chrono::{TimeZone, Utc};
+        use chrono_tz::America::New_York;
+
+        let conf = Config::new();
+        let mut builder = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+        builder
+            .with_auth((conf.user.as_str(), conf.passwd.as_str()))
+            .with_timezone(New_York);
+        let mut client = builder.connect().await.unwrap();
+        assert_eq!(client.timezone(), New_York);
+        let res = client
+            .run_script("2024.07.02T09:30:00.000")
+            .await
+            .unwrap()
+            .unwrap();
+        let ConstantImpl::Scalar(value) = res else {
+            panic!("unexpected {}", res);
+        };
+        assert_eq!(
+            client.to_zoned(&value).unwrap().unwrap(),
+            Utc.with_ymd_and_hms(2024, 7, 2, 13, 30, 0).unwrap()
+        );
+        let res = client
+            .run_script("[2024.01.02T09:30:00, 2024.07.02T09:30:00]")
+            .await
+            .unwrap()
+            .unwrap();
+        let zoned = client.to_zoned_vector(res.as_vector().unwrap()).unwrap();
+        assert_eq!(
+            zoned[0].unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 2, 14, 30, 0).unwrap()
+        );
+        assert_eq!(
+            zoned[1].unwrap(),
+            Utc.with_ymd_and_hms(2024, 7, 2, 13, 30, 0).unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn test_client_client_builder_addr_error() {
         let builder = ClientBuilder::new("192.168.0.54:12345");
@@ -0,0 +1,281 @@
+use bytes::BytesMut;
+use dolphindb::codec::{decode, encode, ConstantCodec, Endian};
+use dolphindb::error::Error;
+use dolphindb::types::*;
+use rstest::rstest;
+use tokio_util::codec::{Decoder, Encoder};
+
+fn sample_table() -> ConstantImpl {
+    let mut ints = Vector::<Int>::new();
+    ints.extend_from_slice(&[1, i32::MIN, 3]);
+    let mut names = Vector::<Symbol>::new();
+    names.push(Symbol::new("a".into()));
+    names.push(Symbol::default());
+    names.push(Symbol::new("c".into()));
+    let mut prices = Vector::<Double>::new();
+    prices.extend_from_slice(&[1.5, 2.5, f64::MIN]);
+
+    let mut builder = TableBuilder::new();
+    builder.with_name("t".into());
+    builder.with_contents(
+        vec![ints.into(), names.into(), prices.into()],
+        vec!["id".into(), "name".into(), "price".into()],
+    );
+    builder.build().unwrap().into()
+}
+
+fn samples() -> Vec<ConstantImpl> {
+    let mut strings = Vector::<DolphinString>::new();
+    strings.push(DolphinString::new("hello".into()));
+    strings.push(DolphinString::default());
+    let mut dates = Vector::<Date>::new();
+    dates.push(Date::from_ymd(2024, 2, 29).unwrap());
+    dates.push(Date::default());
+
+    vec![
+        Int::new(42).into(),
+        Double::default().into(),
+        DolphinString::new("codec".into()).into(),
+        Duration::new(5, DurationUnit::Minute).into(),
+        strings.into(),
+        dates.into(),
+        sample_table(),
+    ]
+}
+
+#[rstest]
+#[case(Endian::Little)]
+#[case(Endian::Big)]
+fn test_codec_round_trip(#[case] endian: Endian) {
+    for value in samples() {
+        let bytes = encode(&value, endian).unwrap();
+        assert_eq!(decode(&bytes, endian).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_codec_decode_truncated() {
+    let bytes = encode(&sample_table(), Endian::Little).unwrap();
+    let res = decode(&bytes[..bytes.len() - 1], Endian::Little);
+    assert!(matches!(res, Err(Error::UnexpectedEof) | Err(Error::IO(_))));
+}
+
+#[test]
+fn test_codec_decode_trailing_bytes() {
+    let mut bytes = encode(&Int::new(1).into(), Endian::Little)
+        .unwrap()
+        .to_vec();
+    bytes.push(0);
+    assert!(decode(&bytes, Endian::Little).is_err());
+}
+
+#[test]
+fn test_codec_framed_partial_input() {
+    let mut codec = ConstantCodec::new(Endian::Little);
+    let mut encoded = BytesMut::new();
+    for value in samples() {
+        codec.encode(&value, &mut encoded).unwrap();
+    }
+
+    // feed the stream a few bytes at a time.
+    let mut src = BytesMut::new();
+    let mut decoded = Vec::new();
+    for chunk in encoded.chunks(3) {
+        src.extend_from_slice(chunk);
+        while let Some(value) = codec.decode(&mut src).unwrap() {
+            decoded.push(value);
+        }
+    }
+
+    assert!(src.is_empty());
+    assert_eq!(decoded, samples());
+}
+
+#[test]
+fn test_codec_save_load() {
+    let path =
+        std::env::temp_dir().join(format!("test_codec_save_load_{}.bin", std::process::id()));
+    let table = sample_table();
+    table.save_to(&path).unwrap();
+    let loaded = ConstantImpl::load_from(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, table);
+}
+
+#[test]
+fn test_codec_load_missing_file() {
+    let path = std::env::temp_dir().join("test_codec_load_missing_file.bin");
+    assert!(matches!(ConstantImpl::load_from(path), Err(Error::IO(_))));
+}
+
+#[test]
+fn test_codec_decimal_vector_unrepresentable_scale() {
+    // 1e9 doesn't fit a 32 bit mantissa once rescaled to the column's scale of 1.
+    let v: ConstantImpl = VectorImpl::from(Vector::from(vec![
+        Decimal32::from_raw(1_000_000_000, 0).unwrap(),
+        Decimal32::from_raw(1, 1).unwrap(),
+    ]))
+    .into();
+    assert!(encode(&v, Endian::Little).is_err());
+
+    let v: ConstantImpl = VectorImpl::from(Vector::from(vec![
+        Decimal32::from_raw(100_000_000, 0).unwrap(),
+        Decimal32::from_raw(1, 1).unwrap(),
+    ]))
+    .into();
+    let decoded = decode(&encode(&v, Endian::Little).unwrap(), Endian::Little).unwrap();
+    assert_eq!(decoded, v);
+}
+
+#[test]
+fn test_codec_decimal_vector_declared_scale() {
+    // the declared scale is kept even without values.
+    let v: ConstantImpl = VectorImpl::from(Decimal64Vector::with_scale(4).unwrap()).into();
+    let decoded = decode(&encode(&v, Endian::Big).unwrap(), Endian::Big).unwrap();
+    assert_eq!(decoded.as_vector().unwrap().decimal_scale(), Some(4));
+
+    let mut v = Decimal32Vector::new();
+    v.push(Decimal32::from_raw(1, 1).unwrap());
+    v.push(Decimal32::from_raw(1, 3).unwrap());
+    let mut v = VectorImpl::from(v);
+    v.set_decimal_scale(2).unwrap();
+    let decoded = decode(&encode(&v.into(), Endian::Little).unwrap(), Endian::Little).unwrap();
+    let decoded = decoded.as_vector().unwrap();
+    assert_eq!(decoded.decimal_scale(), Some(2));
+    assert_eq!(
+        decoded.get(1).unwrap(),
+        Decimal32::from_raw(0, 2).unwrap().into()
+    );
+}
+
+#[test]
+fn test_codec_decimal_array_vector_declared_scale() {
+    let mut rows = Decimal64ArrayVector::new();
+    rows.push(vec![Decimal64::from_raw(15, 1).unwrap()]);
+    let mut rows = VectorImpl::from(rows);
+    rows.set_decimal_scale(3).unwrap();
+    let decoded = decode(
+        &encode(&rows.into(), Endian::Little).unwrap(),
+        Endian::Little,
+    )
+    .unwrap();
+    let decoded = decoded.as_vector().unwrap();
+    assert_eq!(decoded.decimal_scale(), Some(3));
+    let VectorImpl::ArrayVector(ArrayVectorImpl::Decimal64(rows)) = decoded else {
+        panic!("expected a decimal64 array vector");
+    };
+    assert_eq!(rows[0][0].mantissa(), Some(1500));
+}
+
+#[test]
+fn test_codec_array_vector_blocks() {
+    // more rows than one block of the wire format holds.
+    let mut array_vector = IntArrayVector::new();
+    for i in 0..70000 {
+        array_vector.push_raw(Some(&[Some(i), Some(i % 7)][..(i % 3) as usize]));
+    }
+    let value: ConstantImpl = VectorImpl::from(array_vector).into();
+    let bytes = encode(&value, Endian::Little).unwrap();
+    assert_eq!(decode(&bytes, Endian::Little).unwrap(), value);
+}
+
+#[test]
+fn test_codec_array_vector_null_element_row() {
+    let mut null_element = IntArrayVector::new();
+    null_element.push_raw(Some(&[None]));
+    null_element.push_raw(Some(&[]));
+    null_element.push_raw(Some(&[Some(1), None]));
+    let value: ConstantImpl = VectorImpl::from(null_element.clone()).into();
+    let bytes = encode(&value, Endian::Little).unwrap();
+    assert_eq!(decode(&bytes, Endian::Little).unwrap(), value);
+
+    // a null row has no representation of its own on the wire.
+    null_element.push_null();
+    let null_row: ConstantImpl = VectorImpl::from(null_element).into();
+    assert!(matches!(
+        encode(&null_row, Endian::Little),
+        Err(Error::ConstraintsViolated(_))
+    ));
+    let mut decimals = Decimal32ArrayVector::new();
+    decimals.push_null();
+    let decimals: ConstantImpl = VectorImpl::from(decimals).into();
+    assert!(encode(&decimals, Endian::Little).is_err());
+}
+
+#[test]
+fn test_codec_framed_large_object() {
+    let mut ints = Vector::<Int>::new();
+    ints.extend_from_slice(&(0..200_000).collect::<Vec<_>>());
+    let value: ConstantImpl = VectorImpl::from(ints).into();
+    let mut codec = ConstantCodec::new(Endian::Little);
+    let mut encoded = BytesMut::new();
+    codec.encode(&value, &mut encoded).unwrap();
+    codec.encode(&samples()[0], &mut encoded).unwrap();
+
+    // the vector arrives in many small reads, the next object shares the last one.
+    let mut src = BytesMut::new();
+    let mut decoded = Vec::new();
+    for chunk in encoded.chunks(1000) {
+        src.extend_from_slice(chunk);
+        while let Some(value) = codec.decode(&mut src).unwrap() {
+            decoded.push(value);
+        }
+    }
+
+    assert!(src.is_empty());
+    assert_eq!(decoded, vec![value, samples()[0].clone()]);
+}
+
+#[test]
+fn test_codec_framed_truncated_at_eof() {
+    let mut codec = ConstantCodec::new(Endian::Little);
+    let bytes = encode(&sample_table(), Endian::Little).unwrap();
+    let mut src = BytesMut::from(&bytes[..bytes.len() - 1]);
+    assert!(codec.decode(&mut src).unwrap().is_none());
+    assert!(matches!(
+        codec.decode_eof(&mut src),
+        Err(Error::UnexpectedEof)
+    ));
+}
+
+#[test]
+fn test_codec_declared_length_limit() {
+    // an INT vector declaring i32::MAX rows, followed by a single one.
+    let mut bytes = encode(
+        &VectorImpl::from(IntVector::from_raw(&[1])).into(),
+        Endian::Little,
+    )
+    .unwrap()
+    .to_vec();
+    bytes[2..6].copy_from_slice(&i32::MAX.to_le_bytes());
+    assert!(matches!(
+        decode(&bytes, Endian::Little),
+        Err(Error::ConstraintsViolated(_))
+    ));
+
+    let mut codec = ConstantCodec::new(Endian::Little);
+    codec.with_max_size(1024);
+    let mut src = BytesMut::from(&bytes[..]);
+    assert!(matches!(
+        codec.decode(&mut src),
+        Err(Error::ConstraintsViolated(_))
+    ));
+}
+
+#[test]
+fn test_codec_framed_max_size() {
+    let value: ConstantImpl = VectorImpl::from(IntVector::from_raw(&[1; 1000])).into();
+    let bytes = encode(&value, Endian::Little).unwrap();
+    let mut codec = ConstantCodec::new(Endian::Little);
+    codec.with_max_size(2000);
+    let mut src = BytesMut::new();
+    let mut result = Ok(None);
+    for chunk in bytes.chunks(500) {
+        src.extend_from_slice(chunk);
+        result = codec.decode(&mut src);
+        if result.is_err() {
+            break;
+        }
+    }
+    assert!(matches!(result, Err(Error::ConstraintsViolated(_))));
+}
@@ -0,0 +1,245 @@
+mod setup;
+
+use std::time::Duration;
+
+use dolphindb::client::{Client, ClientBuilder, MultithreadedTableWriter, WriterOptions};
+use dolphindb::types::{Long, PrimitiveType};
+use setup::settings::Config;
+
+async fn connect() -> Client {
+    let conf = Config::new();
+    let mut builder = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    builder.connect().await.unwrap()
+}
+
+async fn connect_many(count: usize) -> Vec<Client> {
+    let mut clients = Vec::with_capacity(count);
+    for _ in 0..count {
+        clients.push(connect().await);
+    }
+    clients
+}
+
+async fn create_table(table: &str) {
+    let mut client = connect().await;
+    client
+        .run_script(format!("share table(10:0,[`sym,`price],[SYMBOL,DOUBLE]) as `{table}").as_str())
+        .await
+        .unwrap();
+}
+
+async fn assert_rows(table: &str, rows: i64) {
+    let mut client = connect().await;
+    let result = client
+        .run_script(format!("exec count(*) from {table}").as_str())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(result, Long::new(rows).into());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_multithreaded_table_writer_partition_column() {
+    const TABLE: &str = "test_multithreaded_table_writer_partition_column";
+    create_table(TABLE).await;
+    let mut options = WriterOptions::default();
+    options.with_batch_size(100).with_partition_column("sym");
+    let mut writer = MultithreadedTableWriter::new(connect_many(4).await, TABLE, &options)
+        .await
+        .unwrap();
+    for i in 0..1000 {
+        writer
+            .insert(vec![
+                PrimitiveType::String(format!("s{}", i % 10)),
+                PrimitiveType::F64(i as f64),
+            ])
+            .await
+            .unwrap();
+    }
+    let status = writer.wait_for_completion().await;
+    assert_eq!(status.len(), 4);
+    assert_eq!(status.iter().map(|s| s.sent_rows).sum::<u64>(), 1000);
+    assert!(status
+        .iter()
+        .all(|s| s.pending_rows == 0 && s.failed_rows == 0));
+    assert_rows(TABLE, 1000).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_multithreaded_table_writer_throttle() {
+    const TABLE: &str = "test_multithreaded_table_writer_throttle";
+    create_table(TABLE).await;
+    let mut options = WriterOptions::default();
+    options
+        .with_batch_size(1024)
+        .with_throttle(Duration::from_millis(100));
+    let mut writer = MultithreadedTableWriter::new(connect_many(2).await, TABLE, &options)
+        .await
+        .unwrap();
+    for i in 0..10 {
+        writer
+            .insert(vec![
+                PrimitiveType::String("a".into()),
+                PrimitiveType::F64(i as f64),
+            ])
+            .await
+            .unwrap();
+    }
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let status = writer.get_status();
+    assert_eq!(status.iter().map(|s| s.sent_rows).sum::<u64>(), 10);
+    let status = writer.wait_for_completion().await;
+    assert_eq!(status.iter().map(|s| s.sent_rows).sum::<u64>(), 10);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_multithreaded_table_writer_columns_error() {
+    const TABLE: &str = "test_multithreaded_table_writer_columns_error";
+    create_table(TABLE).await;
+    let mut writer =
+        MultithreadedTableWriter::new(connect_many(2).await, TABLE, &WriterOptions::default())
+            .await
+            .unwrap();
+    assert!(writer
+        .insert(vec![PrimitiveType::String("a".into())])
+        .await
+        .is_err());
+    let status = writer.wait_for_completion().await;
+    assert!(status
+        .iter()
+        .all(|s| s.sent_rows == 0 && s.pending_rows == 0));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_multithreaded_table_writer_partition_column_not_exist() {
+    const TABLE: &str = "test_multithreaded_table_writer_partition_column_not_exist";
+    create_table(TABLE).await;
+    let mut options = WriterOptions::default();
+    options.with_partition_column("not_exist");
+    assert!(
+        MultithreadedTableWriter::new(connect_many(2).await, TABLE, &options)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_multithreaded_table_writer_no_client() {
+    assert!(
+        MultithreadedTableWriter::new(Vec::new(), "t", &WriterOptions::default())
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_multithreaded_table_writer_void_insert_function() {
+    const TABLE: &str = "test_multithreaded_table_writer_void_insert_function";
+    create_table(TABLE).await;
+    let mut client = connect().await;
+    client
+        .run_script(format!("def {TABLE}_insert(t) {{ objByName(`{TABLE}).append!(t) }}").as_str())
+        .await
+        .unwrap();
+    let mut options = WriterOptions::default();
+    options
+        .with_batch_size(10)
+        .with_insert_function(format!("{TABLE}_insert").as_str());
+    let mut writer = MultithreadedTableWriter::new(connect_many(2).await, TABLE, &options)
+        .await
+        .unwrap();
+    for i in 0..105 {
+        writer
+            .insert(vec![
+                PrimitiveType::String("a".into()),
+                PrimitiveType::F64(i as f64),
+            ])
+            .await
+            .unwrap();
+    }
+    let status = writer.wait_for_completion().await;
+    assert_eq!(status.iter().map(|s| s.sent_rows).sum::<u64>(), 105);
+    assert!(status
+        .iter()
+        .all(|s| s.pending_rows == 0 && s.failed_rows == 0));
+    assert_rows(TABLE, 105).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_multithreaded_table_writer_range_partitions() {
+    const DB: &str = "dfs://test_multithreaded_table_writer_range_partitions";
+    let mut client = connect().await;
+    client
+        .run_script(
+            format!(
+                r#"
+                if (existsDatabase("{DB}")) {{ dropDatabase("{DB}") }}
+                db = database("{DB}", RANGE, [0, 50, 100])
+                db.createPartitionedTable(table(1:0, [`id, `price], [INT, DOUBLE]), `pt, `id)
+            "#
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+    let mut options = WriterOptions::default();
+    options.with_batch_size(10).with_partition_column("id");
+    let mut writer = MultithreadedTableWriter::new(connect_many(2).await, (DB, "pt"), &options)
+        .await
+        .unwrap();
+    for i in 0..100 {
+        writer
+            .insert(vec![PrimitiveType::I32(i), PrimitiveType::F64(i as f64)])
+            .await
+            .unwrap();
+    }
+    assert!(writer
+        .insert(vec![PrimitiveType::I32(100), PrimitiveType::F64(0.0)])
+        .await
+        .is_err());
+    let status = writer.wait_for_completion().await;
+    // each range is written by one worker.
+    assert!(status.iter().all(|s| s.sent_rows == 50));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_multithreaded_table_writer_date_partitions() {
+    const DB: &str = "dfs://test_multithreaded_table_writer_date_partitions";
+    let mut client = connect().await;
+    client
+        .run_script(
+            format!(
+                r#"
+                if (existsDatabase("{DB}")) {{ dropDatabase("{DB}") }}
+                db = database("{DB}", VALUE, 2024.01.01..2024.01.02)
+                db.createPartitionedTable(table(1:0, [`ts, `price], [TIMESTAMP, DOUBLE]), `pt, `ts)
+            "#
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+    let mut options = WriterOptions::default();
+    options.with_batch_size(10).with_partition_column("ts");
+    let mut writer = MultithreadedTableWriter::new(connect_many(2).await, (DB, "pt"), &options)
+        .await
+        .unwrap();
+    for day in 1..=2 {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+        for i in 0..10 {
+            let ts = date.and_hms_opt(i, 30, 0).unwrap();
+            writer
+                .insert(vec![
+                    PrimitiveType::NaiveDateTime(ts),
+                    PrimitiveType::F64(i as f64),
+                ])
+                .await
+                .unwrap();
+        }
+    }
+    let status = writer.wait_for_completion().await;
+    assert_eq!(status.iter().map(|s| s.sent_rows).sum::<u64>(), 20);
+    // the rows of a day are written by one worker.
+    assert!(status.iter().all(|s| s.sent_rows % 10 == 0));
+}
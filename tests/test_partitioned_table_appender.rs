@@ -0,0 +1,172 @@
+mod setup;
+
+use dolphindb::client::{Client, ClientBuilder, PartitionedTableAppender};
+use dolphindb::types::*;
+use setup::settings::Config;
+
+async fn connect() -> Client {
+    let conf = Config::new();
+    let mut builder = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    builder.connect().await.unwrap()
+}
+
+async fn connect_many(count: usize) -> Vec<Client> {
+    let mut clients = Vec::with_capacity(count);
+    for _ in 0..count {
+        clients.push(connect().await);
+    }
+    clients
+}
+
+// creates `pt` in `db` partitioned by `partition` over the columns id, sym, date and value.
+async fn create_table(db: &str, partition: &str, partition_columns: &str) {
+    let mut client = connect().await;
+    client
+        .run_script(
+            format!(
+                r#"
+                if (existsDatabase("{db}")) {{ dropDatabase("{db}") }}
+                db = {partition}
+                t = table(10:0, `id`sym`date`value, [INT, SYMBOL, DATE, DOUBLE])
+                db.createPartitionedTable(t, "pt", {partition_columns})
+            "#
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+}
+
+fn rows(count: i32) -> Table {
+    let mut id = IntVector::new();
+    let mut sym = SymbolVector::new();
+    let mut date = DateVector::new();
+    let mut value = DoubleVector::new();
+    for i in 0..count {
+        id.push((i % 9 + 1).into());
+        sym.push(Symbol::new(format!("s{}", i % 5)));
+        date.push(Date::from_ymd(2024, 1, (i % 28 + 1) as u32).unwrap());
+        value.push((i as f64).into());
+    }
+    let mut builder = TableBuilder::new();
+    builder.with_contents(
+        vec![id.into(), sym.into(), date.into(), value.into()],
+        vec![
+            "id".to_string(),
+            "sym".to_string(),
+            "date".to_string(),
+            "value".to_string(),
+        ],
+    );
+    builder.build().unwrap()
+}
+
+async fn assert_rows(db: &str, rows: i64) {
+    let mut client = connect().await;
+    let result = client
+        .run_script(format!(r#"exec count(*) from loadTable("{db}", "pt")"#).as_str())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(result, Long::new(rows).into());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_partitioned_table_appender_value() {
+    const DB: &str = "dfs://test_partitioned_table_appender_value";
+    create_table(
+        DB,
+        &format!(r#"database("{DB}", VALUE, 2024.01.01..2024.01.31)"#),
+        "`date",
+    )
+    .await;
+    let mut appender = PartitionedTableAppender::new(connect_many(4).await, DB, "pt", "date")
+        .await
+        .unwrap();
+    assert_eq!(appender.append(&rows(1000)).await.unwrap(), 1000);
+    assert_rows(DB, 1000).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_partitioned_table_appender_range() {
+    const DB: &str = "dfs://test_partitioned_table_appender_range";
+    create_table(DB, &format!(r#"database("{DB}", RANGE, 1 4 7 10)"#), "`id").await;
+    let mut appender = PartitionedTableAppender::new(connect_many(3).await, DB, "pt", "id")
+        .await
+        .unwrap();
+    assert_eq!(appender.append(&rows(1000)).await.unwrap(), 1000);
+    assert_rows(DB, 1000).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_partitioned_table_appender_range_out_of_scheme() {
+    const DB: &str = "dfs://test_partitioned_table_appender_range_out_of_scheme";
+    create_table(DB, &format!(r#"database("{DB}", RANGE, 1 4 7)"#), "`id").await;
+    let mut appender = PartitionedTableAppender::new(connect_many(2).await, DB, "pt", "id")
+        .await
+        .unwrap();
+    assert!(appender.append(&rows(10)).await.is_err());
+    assert_rows(DB, 0).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_partitioned_table_appender_hash() {
+    const DB: &str = "dfs://test_partitioned_table_appender_hash";
+    create_table(
+        DB,
+        &format!(r#"database("{DB}", HASH, [SYMBOL, 3])"#),
+        "`sym",
+    )
+    .await;
+    let mut appender = PartitionedTableAppender::new(connect_many(3).await, DB, "pt", "sym")
+        .await
+        .unwrap();
+    assert_eq!(appender.append(&rows(1000)).await.unwrap(), 1000);
+    assert_rows(DB, 1000).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_partitioned_table_appender_list() {
+    const DB: &str = "dfs://test_partitioned_table_appender_list";
+    create_table(
+        DB,
+        &format!(r#"database("{DB}", LIST, [`s0`s1, `s2, `s3`s4])"#),
+        "`sym",
+    )
+    .await;
+    let mut appender = PartitionedTableAppender::new(connect_many(3).await, DB, "pt", "sym")
+        .await
+        .unwrap();
+    assert_eq!(appender.append(&rows(1000)).await.unwrap(), 1000);
+    assert_rows(DB, 1000).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_partitioned_table_appender_compo() {
+    const DB: &str = "dfs://test_partitioned_table_appender_compo";
+    create_table(
+        DB,
+        &format!(
+            r#"database("{DB}", COMPO, [database("", VALUE, 2024.01.01..2024.01.31), database("", HASH, [INT, 4])])"#
+        ),
+        "`date`id",
+    )
+    .await;
+    let mut appender = PartitionedTableAppender::new(connect_many(4).await, DB, "pt", "id")
+        .await
+        .unwrap();
+    assert_eq!(appender.append(&rows(1000)).await.unwrap(), 1000);
+    assert_rows(DB, 1000).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_partitioned_table_appender_not_partition_column() {
+    const DB: &str = "dfs://test_partitioned_table_appender_not_partition_column";
+    create_table(DB, &format!(r#"database("{DB}", RANGE, 1 4 7 10)"#), "`id").await;
+    assert!(
+        PartitionedTableAppender::new(connect_many(2).await, DB, "pt", "sym")
+            .await
+            .is_err()
+    );
+}
@@ -157,3 +157,122 @@ async fn test_run_function_error() {
     let res = client.run_function("test_run_function_error", &args).await;
     assert!(!res.is_ok());
 }
+
+#[tokio::test]
+async fn test_run_function_duration() {
+    // connect
+    let conf = Config::new();
+    let mut builder = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client = builder.connect().await.unwrap();
+    // prapare
+    let _ = client
+        .run_script(
+            r#"
+                def test_run_function_duration(x){
+                    return x
+                }
+            "#,
+        )
+        .await;
+    for duration in [
+        Duration::new(5, DurationUnit::Minute),
+        Duration::new(-1, DurationUnit::Day),
+        Duration::new(3, DurationUnit::BusinessDay),
+        Duration::from_exchange(1, "XNYS").unwrap(),
+    ] {
+        let args = vec![duration.into()];
+        let res = client
+            .run_function("test_run_function_duration", &args)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(res.data_form(), DataForm::Scalar);
+        assert_eq!(res.data_type(), DataType::Duration);
+        assert_eq!(Duration::try_from(res).unwrap(), duration);
+    }
+    let mut minutes = Vector::<Minute>::new();
+    minutes.push(Minute::from_hm(10, 7).unwrap());
+    let args = vec![
+        minutes.into(),
+        Duration::new(5, DurationUnit::Minute).into(),
+    ];
+    let res = client.run_function("bar", &args).await.unwrap().unwrap();
+    let mut expect = Vector::<Minute>::new();
+    expect.push(Minute::from_hm(10, 5).unwrap());
+    assert_eq!(res, expect.into());
+}
+
+// objects written by `ConstantImpl::save_to` and the server's `writeObject` read back on the
+// other side, the file bytes travel as CHAR vectors.
+#[tokio::test]
+async fn test_run_function_save_to_interop() {
+    const NAME: &str = "test_run_function_save_to_interop";
+    // connect
+    let conf = Config::new();
+    let mut builder = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client = builder.connect().await.unwrap();
+    // prapare
+    client
+        .run_script(
+            format!(
+                r#"
+                path = getHomeDir() + "/{NAME}.bin"
+                def {NAME}_write(path, obj) {{
+                    f = file(path, "w")
+                    writeObject(f, obj)
+                    f.close()
+                    f = file(path)
+                    n = seek(f, 0, TAIL)
+                    seek(f, 0, HEAD)
+                    return readBytes(f, n)
+                }}
+                def {NAME}_read(path, bytes) {{
+                    f = file(path, "w")
+                    writeBytes(f, bytes)
+                    f.close()
+                    return readObject(file(path))
+                }}
+                t = table(1 2 3 as id, `a`b`c as sym, [1.5, NULL, 2.5] as price)
+            "#
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+    let expect = client.run_script("t").await.unwrap().unwrap();
+    let local = std::env::temp_dir().join(format!("{NAME}.bin"));
+
+    // server to client.
+    let bytes = client
+        .run_script(format!("{NAME}_write(path, t)").as_str())
+        .await
+        .unwrap()
+        .unwrap();
+    let VectorImpl::Char(bytes) = bytes.as_vector().unwrap() else {
+        panic!("unexpected bytes {}", bytes);
+    };
+    let bytes: Vec<u8> = bytes
+        .iter()
+        .map(|b| b.into_inner().unwrap_or(i8::MIN) as u8)
+        .collect();
+    std::fs::write(&local, bytes).unwrap();
+    assert_eq!(ConstantImpl::load_from(&local).unwrap(), expect);
+
+    // client to server.
+    expect.save_to(&local).unwrap();
+    let bytes: Vec<i8> = std::fs::read(&local)
+        .unwrap()
+        .into_iter()
+        .map(|b| b as i8)
+        .collect();
+    let path = client.run_script("path").await.unwrap().unwrap();
+    let bytes: ConstantImpl = VectorImpl::from(CharVector::from_raw(&bytes)).into();
+    let loaded = client
+        .run_function(format!("{NAME}_read").as_str(), &[path, bytes])
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(loaded, expect);
+}
@@ -2422,9 +2422,14 @@ mod test_run_script_vector {
         DataForm::Vector,
         DataType::CharArray,
         ArrayVectorImpl::Char(array_vector_build!(
-            i8,
-            vec![0i8, 127i8, -127i8, -128i8],
-            vec![0i8, 1i8, 2i8]
+            Char,
+            vec![
+                Char::new(0i8),
+                Char::new(127i8),
+                Char::new(-127i8),
+                Char::default()
+            ],
+            vec![Char::new(0i8), Char::new(1i8), Char::new(2i8)]
         )),
         ConstantImpl::Vector,
         VectorImpl::ArrayVector
@@ -2445,9 +2450,14 @@ mod test_run_script_vector {
         DataForm::Vector,
         DataType::ShortArray,
         ArrayVectorImpl::Short(array_vector_build!(
-            i16,
-            vec![0i16, 32767i16, -32767i16, -32768i16],
-            vec![0i16, 1i16, 2i16]
+            Short,
+            vec![
+                Short::new(0i16),
+                Short::new(32767i16),
+                Short::new(-32767i16),
+                Short::default()
+            ],
+            vec![Short::new(0i16), Short::new(1i16), Short::new(2i16)]
         )),
         ConstantImpl::Vector,
         VectorImpl::ArrayVector
@@ -2468,13 +2478,32 @@ mod test_run_script_vector {
         DataForm::Vector,
         DataType::IntArray,
         ArrayVectorImpl::Int(array_vector_build!(
-            i32,
-            vec![0i32, 2147483647i32, -2147483647i32, -2147483648i32],
-            vec![0i32, 1i32, 2i32]
+            Int,
+            vec![
+                Int::new(0i32),
+                Int::new(2147483647i32),
+                Int::new(-2147483647i32),
+                Int::default()
+            ],
+            vec![Int::new(0i32), Int::new(1i32), Int::new(2i32)]
         )),
         ConstantImpl::Vector,
         VectorImpl::ArrayVector
     );
+    macro_test_run_script!(
+        test_run_script_vector_int_array_vector_null_element_row,
+        "x=array(INT[]).append!([[1i,00i],[00i]]);x",
+        DataForm::Vector,
+        DataType::IntArray,
+        ArrayVectorImpl::Int({
+            let mut v = IntArrayVector::new();
+            v.push_raw(Some(&[Some(1), None]));
+            v.push_raw(Some(&[None]));
+            v
+        }),
+        ConstantImpl::Vector,
+        VectorImpl::ArrayVector
+    );
     macro_test_run_script!(
         test_run_script_vector_int_array_vector_empty,
         "array(INT[])",
@@ -2492,9 +2521,9 @@ mod test_run_script_vector {
         DataType::LongArray,
         ArrayVectorImpl::Long(
             array_vector_build!(
-                i64,
-                vec![0i64,9223372036854775807i64,-9223372036854775807i64,-9223372036854775808i64],
-                vec![0i64,1i64,2i64]
+                Long,
+                vec![Long::new(0i64), Long::new(9223372036854775807i64), Long::new(-9223372036854775807i64), Long::default()],
+                vec![Long::new(0i64), Long::new(1i64), Long::new(2i64)]
             )
         ),
         ConstantImpl::Vector,
@@ -2516,9 +2545,13 @@ mod test_run_script_vector {
         DataForm::Vector,
         DataType::FloatArray,
         ArrayVectorImpl::Float(array_vector_build!(
-            f32,
-            vec![0.0f32, 3.14f32, f32::MIN],
-            vec![3.14f32, 3.15f32, 3.16f32]
+            Float,
+            vec![Float::new(0.0f32), Float::new(3.14f32), Float::default()],
+            vec![
+                Float::new(3.14f32),
+                Float::new(3.15f32),
+                Float::new(3.16f32)
+            ]
         )),
         ConstantImpl::Vector,
         VectorImpl::ArrayVector
@@ -2539,9 +2572,13 @@ mod test_run_script_vector {
         DataForm::Vector,
         DataType::DoubleArray,
         ArrayVectorImpl::Double(array_vector_build!(
-            f64,
-            vec![0.0f64, 3.14f64, f64::MIN],
-            vec![3.14f64, 3.15f64, 3.16f64]
+            Double,
+            vec![Double::new(0.0f64), Double::new(3.14f64), Double::default()],
+            vec![
+                Double::new(3.14f64),
+                Double::new(3.15f64),
+                Double::new(3.16f64)
+            ]
         )),
         ConstantImpl::Vector,
         VectorImpl::ArrayVector
@@ -2555,6 +2592,111 @@ mod test_run_script_vector {
         ConstantImpl::Vector,
         VectorImpl::ArrayVector
     );
+    // bool array vector
+    macro_test_run_script!(
+        test_run_script_vector_bool_array_vector_normal,
+        "x=array(BOOL[]).append!([[true,false,00b],[true]]);x",
+        DataForm::Vector,
+        DataType::BoolArray,
+        ArrayVectorImpl::Bool(array_vector_build!(
+            Bool,
+            vec![Bool::new(true), Bool::new(false), Bool::default()],
+            vec![Bool::new(true)]
+        )),
+        ConstantImpl::Vector,
+        VectorImpl::ArrayVector
+    );
+    // date array vector
+    macro_test_run_script!(
+        test_run_script_vector_date_array_vector_normal,
+        "x=array(DATE[]).append!([[1970.01.01d,2024.02.29d,00d],[2024.02.29d]]);x",
+        DataForm::Vector,
+        DataType::DateArray,
+        ArrayVectorImpl::Date(array_vector_build!(
+            Date,
+            vec![
+                Date::from_ymd(1970, 1, 1).unwrap(),
+                Date::from_ymd(2024, 2, 29).unwrap(),
+                Date::default()
+            ],
+            vec![Date::from_ymd(2024, 2, 29).unwrap()]
+        )),
+        ConstantImpl::Vector,
+        VectorImpl::ArrayVector
+    );
+    // timestamp array vector
+    macro_test_run_script!(
+        test_run_script_vector_timestamp_array_vector_normal,
+        "x=array(TIMESTAMP[]).append!([[1970.01.01T00:00:00.001,00T],[]]);x",
+        DataForm::Vector,
+        DataType::TimestampArray,
+        ArrayVectorImpl::Timestamp(array_vector_build!(
+            Timestamp,
+            vec![Timestamp::from_raw(1).unwrap(), Timestamp::default()],
+            vec![]
+        )),
+        ConstantImpl::Vector,
+        VectorImpl::ArrayVector
+    );
+    // decimal32 array vector
+    macro_test_run_script!(
+        test_run_script_vector_decimal32_array_vector_normal,
+        "x=array(DECIMAL32(2)[]).append!([[1.23,-0.5,NULL],[3]]);x",
+        DataForm::Vector,
+        DataType::Decimal32Array,
+        ArrayVectorImpl::Decimal32(array_vector_build!(
+            Decimal32,
+            vec![
+                Decimal32::from_raw(123, 2).unwrap(),
+                Decimal32::from_raw(-50, 2).unwrap(),
+                Decimal32::default()
+            ],
+            vec![Decimal32::from_raw(300, 2).unwrap()]
+        )),
+        ConstantImpl::Vector,
+        VectorImpl::ArrayVector
+    );
+    macro_test_run_script!(
+        test_run_script_vector_decimal32_array_vector_empty,
+        "array(DECIMAL32(2)[])",
+        DataForm::Vector,
+        DataType::Decimal32Array,
+        ArrayVectorImpl::Decimal32(Decimal32ArrayVector::new()),
+        ConstantImpl::Vector,
+        VectorImpl::ArrayVector
+    );
+    // uuid array vector
+    macro_test_run_script!(
+        test_run_script_vector_uuid_array_vector_normal,
+        "x=array(UUID[]).append!([[uuid('5d212a78-cc48-e3b1-4235-b4d91473ee87'),uuid('')]]);x",
+        DataForm::Vector,
+        DataType::UuidArray,
+        ArrayVectorImpl::Uuid(array_vector_build!(
+            Uuid,
+            vec![
+                Uuid::new(0x5d212a78_cc48_e3b1_4235_b4d91473ee87u128),
+                Uuid::default()
+            ]
+        )),
+        ConstantImpl::Vector,
+        VectorImpl::ArrayVector
+    );
+    // ipaddr array vector
+    macro_test_run_script!(
+        test_run_script_vector_ipaddr_array_vector_normal,
+        "x=array(IPADDR[]).append!([[ipaddr('192.168.1.13'),ipaddr('e1fe:cb5f::1')]]);x",
+        DataForm::Vector,
+        DataType::IpAddrArray,
+        ArrayVectorImpl::IpAddr(array_vector_build!(
+            IpAddr,
+            vec![
+                IpAddr::new("192.168.1.13".parse().unwrap()),
+                IpAddr::new("e1fe:cb5f::1".parse().unwrap())
+            ]
+        )),
+        ConstantImpl::Vector,
+        VectorImpl::ArrayVector
+    );
 }
 
 // todo:matrix
@@ -4503,9 +4645,9 @@ mod test_run_script_table {
         "table(array(CHAR[]).append!([[0c,127c,-127c,00c],[0c,1c,2c]]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Char(array_vector_build!(
-                i8,
-                vec![0i8,127i8,-127i8,-128i8],
-                vec![0i8,1i8,2i8]
+                Char,
+                vec![Char::new(0i8), Char::new(127i8), Char::new(-127i8), Char::default()],
+                vec![Char::new(0i8), Char::new(1i8), Char::new(2i8)]
             )))
         )
     );
@@ -4514,7 +4656,7 @@ mod test_run_script_table {
         "table(array(CHAR[]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Char(
-                ArrayVector::<i8>::new()
+                CharArrayVector::new()
             ))
         )
     );
@@ -4524,9 +4666,9 @@ mod test_run_script_table {
         "table(array(SHORT[]).append!([[0h,32767h,-32767h,00h],[0h,1h,2h]]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Short(array_vector_build!(
-                i16,
-                vec![0i16,32767i16,-32767i16,-32768i16],
-                vec![0i16,1i16,2i16]
+                Short,
+                vec![Short::new(0i16), Short::new(32767i16), Short::new(-32767i16), Short::default()],
+                vec![Short::new(0i16), Short::new(1i16), Short::new(2i16)]
             )))
         )
     );
@@ -4535,7 +4677,7 @@ mod test_run_script_table {
         "table(array(SHORT[]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Short(
-                ArrayVector::<i16>::new()
+                ShortArrayVector::new()
             ))
         )
     );
@@ -4545,9 +4687,9 @@ mod test_run_script_table {
         "table(array(INT[]).append!([[0i,2147483647i,-2147483647i,00i],[0i,1i,2i]]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Int(array_vector_build!(
-                i32,
-                vec![0i32,2147483647i32,-2147483647i32,-2147483648i32],
-                vec![0i32,1i32,2i32]
+                Int,
+                vec![Int::new(0i32), Int::new(2147483647i32), Int::new(-2147483647i32), Int::default()],
+                vec![Int::new(0i32), Int::new(1i32), Int::new(2i32)]
             )))
         )
     );
@@ -4556,7 +4698,7 @@ mod test_run_script_table {
         "table(array(INT[]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Int(
-                ArrayVector::<i32>::new()
+                IntArrayVector::new()
             ))
         )
     );
@@ -4566,9 +4708,9 @@ mod test_run_script_table {
         "table(array(LONG[]).append!([[0l,9223372036854775807l,-9223372036854775807l,00l],[0l,1l,2l]]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Long(array_vector_build!(
-                i64,
-                vec![0i64,9223372036854775807i64,-9223372036854775807i64,-9223372036854775808i64],
-                vec![0i64,1i64,2i64]
+                Long,
+                vec![Long::new(0i64), Long::new(9223372036854775807i64), Long::new(-9223372036854775807i64), Long::default()],
+                vec![Long::new(0i64), Long::new(1i64), Long::new(2i64)]
             )))
         )
     );
@@ -4577,7 +4719,7 @@ mod test_run_script_table {
         "table(array(LONG[]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Long(
-                ArrayVector::<i64>::new()
+                LongArrayVector::new()
             ))
         )
     );
@@ -4587,9 +4729,9 @@ mod test_run_script_table {
         "table(array(FLOAT[]).append!([[0.0f,3.14f,00f],[3.14f,3.15f,3.16f]]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Float(array_vector_build!(
-                f32,
-                vec![0.0f32,3.14f32,f32::MIN],
-                vec![3.14f32,3.15f32,3.16f32]
+                Float,
+                vec![Float::new(0.0f32), Float::new(3.14f32), Float::default()],
+                vec![Float::new(3.14f32), Float::new(3.15f32), Float::new(3.16f32)]
             )))
         )
     );
@@ -4598,7 +4740,7 @@ mod test_run_script_table {
         "table(array(FLOAT[]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Float(
-                ArrayVector::<f32>::new()
+                FloatArrayVector::new()
             ))
         )
     );
@@ -4608,9 +4750,9 @@ mod test_run_script_table {
         "table(array(DOUBLE[]).append!([[0.0F,3.14F,00F],[3.14F,3.15F,3.16F]]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Double(array_vector_build!(
-                f64,
-                vec![0.0f64,3.14f64,f64::MIN],
-                vec![3.14f64,3.15f64,3.16f64]
+                Double,
+                vec![Double::new(0.0f64), Double::new(3.14f64), Double::default()],
+                vec![Double::new(3.14f64), Double::new(3.15f64), Double::new(3.16f64)]
             )))
         )
     );
@@ -4619,7 +4761,7 @@ mod test_run_script_table {
         "table(array(DOUBLE[]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Double(
-                ArrayVector::<f64>::new()
+                DoubleArrayVector::new()
             ))
         )
     );
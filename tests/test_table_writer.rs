@@ -1,12 +1,17 @@
 mod setup;
 mod utils;
 
+use std::collections::HashMap;
+
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use futures::{stream, SinkExt, StreamExt};
 
 use dolphindb::client::ClientBuilder;
-use dolphindb::client::TableWriter;
+use dolphindb::client::{SpoolOptions, TableWriter, WriteSummary};
+use dolphindb::error::Error;
 use dolphindb::types::PrimitiveType;
 use dolphindb::types::*;
+use rust_decimal::Decimal;
 use setup::settings::Config;
 
 mod test_table_writer_type {
@@ -29,7 +34,7 @@ mod test_table_writer_type {
                 let _ = client_writer.run_script(
                     format!("share table(10:0,[`data],[{0}]) as `{TABLE}", $type_str).as_str()
                 ).await;
-                let mut table_writer = TableWriter::new(client_writer, TABLE, 1024).await;
+                let mut table_writer = TableWriter::new(client_writer, TABLE, 1024).await.unwrap();
                 // append
                 let mut _index: usize = 0;
                 $(
@@ -403,14 +408,51 @@ mod test_table_writer_type {
         ) => "table([\"abc!@#中文 123\",\"\"] as data)"
     );
     // blob
-    // macro_test_table_writer_type!(
-    //     test_table_writer_type_blob,
-    //     "BLOB",
-    //     (
-    //         &mut vec![PrimitiveType::String(String::from("abc!@#中文 123"))],
-    //         &mut vec![PrimitiveType::None]
-    //     ) => "table(blob([\"abc!@#中文 123\",\"\"]) as data)"
-    // );
+    macro_test_table_writer_type!(
+        test_table_writer_type_blob,
+        "BLOB",
+        (
+            &mut vec![PrimitiveType::Blob("abc!@#中文 123".as_bytes().to_vec())],
+            &mut vec![PrimitiveType::None]
+        ) => "table(blob([\"abc!@#中文 123\",\"\"]) as data)"
+    );
+    // symbol from PrimitiveType::Symbol
+    macro_test_table_writer_type!(
+        test_table_writer_type_symbol_variant,
+        "SYMBOL",
+        (
+            &mut vec![PrimitiveType::Symbol(String::from("abc"))],
+            &mut vec![PrimitiveType::None]
+        ) => "table(symbol([\"abc\",\"\"]) as data)"
+    );
+    // decimal32
+    macro_test_table_writer_type!(
+        test_table_writer_type_decimal32,
+        "DECIMAL32(2)",
+        (
+            &mut vec![PrimitiveType::Decimal(Decimal::new(12345, 3))],
+            &mut vec![PrimitiveType::Decimal(Decimal::new(-5, 1))],
+            &mut vec![PrimitiveType::None]
+        ) => "table(decimal32([12.35,-0.5,NULL],2) as data)"
+    );
+    // decimal64
+    macro_test_table_writer_type!(
+        test_table_writer_type_decimal64,
+        "DECIMAL64(4)",
+        (
+            &mut vec![PrimitiveType::Decimal(Decimal::new(12345, 3))],
+            &mut vec![PrimitiveType::None]
+        ) => "table(decimal64([12.345,NULL],4) as data)"
+    );
+    // uuid
+    macro_test_table_writer_type!(
+        test_table_writer_type_uuid,
+        "UUID",
+        (
+            &mut vec![PrimitiveType::U128(0x5d212a78cc48e3b14235b4d91473ee87)],
+            &mut vec![PrimitiveType::None]
+        ) => "table(uuid([\"5d212a78-cc48-e3b1-4235-b4d91473ee87\",\"\"]) as data)"
+    );
     // char array vector
     macro_test_table_writer_type!(
         test_table_writer_type_char_array_vector,
@@ -468,7 +510,6 @@ mod test_table_writer_type {
 }
 
 #[tokio::test(flavor = "multi_thread")]
-#[should_panic(expected = "batch_size must be positive.")]
 async fn test_table_writer_batch_size_0() {
     const TABLE: &str = "test_table_writer_batch_size_0";
     // connect
@@ -480,11 +521,11 @@ async fn test_table_writer_batch_size_0() {
     let _ = client_writer
         .run_script(format!("share table(10:0,[`data],[INT]) as `{TABLE}").as_str())
         .await;
-    let _ = TableWriter::new(client_writer, TABLE, 0).await;
+    let result = TableWriter::new(client_writer, TABLE, 0).await;
+    assert!(matches!(result, Err(Error::ConstraintsViolated(_))));
 }
 
 #[tokio::test(flavor = "multi_thread")]
-#[should_panic(expected = "test_table_writer_columns_error has 1 columns, but 2 provided.")]
 async fn test_table_writer_columns_error() {
     const TABLE: &str = "test_table_writer_columns_error";
     // connect
@@ -496,17 +537,21 @@ async fn test_table_writer_columns_error() {
     let _ = client_writer
         .run_script(format!("share table(10:0,[`data],[INT]) as `{TABLE}").as_str())
         .await;
-    let mut table_writer = TableWriter::new(client_writer, TABLE, 1).await;
-    let _ = table_writer
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 1).await.unwrap();
+    let result = table_writer
         .append_row(&mut vec![
             PrimitiveType::I32(0i32),
             PrimitiveType::I32(1i32),
         ])
         .await;
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "violation: row 0: table test_table_writer_columns_error has 1 columns, but 2 provided"
+    );
+    assert_eq!(table_writer.size(), 0);
 }
 
 #[tokio::test(flavor = "multi_thread")]
-#[should_panic(expected = "Failed to get schema for table test_table_writer_table_not_exist")]
 async fn test_table_writer_table_not_exist() {
     const TABLE: &str = "test_table_writer_table_not_exist";
     // connect
@@ -515,11 +560,10 @@ async fn test_table_writer_table_not_exist() {
     builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
     let client_writer = builder_writer.connect().await.unwrap();
     // create table writer
-    let _ = TableWriter::new(client_writer, TABLE, 3).await;
+    assert!(TableWriter::new(client_writer, TABLE, 3).await.is_err());
 }
 
 #[tokio::test(flavor = "multi_thread")]
-#[should_panic(expected = "not implemented")]
 async fn test_table_writer_type_not_support() {
     const TABLE: &str = "test_table_writer_type_not_support";
     // connect
@@ -531,13 +575,11 @@ async fn test_table_writer_type_not_support() {
     let _ = client_writer.run_script(
         format!("share table(10:0,`data1`data2`data3`data4,[DECIMAL32(2),DECIMAL64(3),DECIMAL128(4),ANY]) as `{TABLE}").as_str()
     ).await;
-    let _ = TableWriter::new(client_writer, TABLE, 3).await;
+    let result = TableWriter::new(client_writer, TABLE, 3).await;
+    assert!(matches!(result, Err(Error::Unsupported { .. })));
 }
 
 #[tokio::test(flavor = "multi_thread")]
-#[should_panic(
-    expected = "Failed to insert into column `data1`: type NaiveDate cannot be converted to Second."
-)]
 async fn test_table_writer_type_error_naive_date() {
     const TABLE: &str = "test_table_writer_type_error_naive_date";
     // connect
@@ -549,18 +591,20 @@ async fn test_table_writer_type_error_naive_date() {
     let _ = client_writer
         .run_script(format!("share table(10:0,[`data1],[SECOND]) as `{TABLE}").as_str())
         .await;
-    let mut table_writer = TableWriter::new(client_writer, TABLE, 1).await;
-    let _ = table_writer
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 1).await.unwrap();
+    let result = table_writer
         .append_row(&mut vec![PrimitiveType::NaiveDate(
             NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
         )])
         .await;
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "row 0 rejected at column `data1`: type NaiveDate cannot be converted to Second"
+    );
+    assert_eq!(table_writer.size(), 0);
 }
 
 #[tokio::test(flavor = "multi_thread")]
-#[should_panic(
-    expected = "Failed to insert into column `data1`: type NaiveTime cannot be converted to Date."
-)]
 async fn test_table_writer_type_error_naive_time() {
     const TABLE: &str = "test_table_writer_type_error_naive_time";
     // connect
@@ -572,18 +616,20 @@ async fn test_table_writer_type_error_naive_time() {
     let _ = client_writer
         .run_script(format!("share table(10:0,[`data1],[DATE]) as `{TABLE}").as_str())
         .await;
-    let mut table_writer = TableWriter::new(client_writer, TABLE, 1).await;
-    let _ = table_writer
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 1).await.unwrap();
+    let result = table_writer
         .append_row(&mut vec![PrimitiveType::NaiveTime(
             NaiveTime::from_hms_milli_opt(0, 0, 0, 0).unwrap(),
         )])
         .await;
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "row 0 rejected at column `data1`: type NaiveTime cannot be converted to Date"
+    );
+    assert_eq!(table_writer.size(), 0);
 }
 
 #[tokio::test(flavor = "multi_thread")]
-#[should_panic(
-    expected = "Failed to insert into column `data1`: type NaiveDateTime cannot be converted to Date."
-)]
 async fn test_table_writer_type_error_naive_date_time() {
     const TABLE: &str = "test_table_writer_type_error_naive_date_time";
     // connect
@@ -595,13 +641,18 @@ async fn test_table_writer_type_error_naive_date_time() {
     let _ = client_writer
         .run_script(format!("share table(10:0,[`data1],[DATE]) as `{TABLE}").as_str())
         .await;
-    let mut table_writer = TableWriter::new(client_writer, TABLE, 1).await;
-    let _ = table_writer
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 1).await.unwrap();
+    let result = table_writer
         .append_row(&mut vec![PrimitiveType::NaiveDateTime(NaiveDateTime::new(
             NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
             NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
         ))])
         .await;
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "row 0 rejected at column `data1`: type NaiveDateTime cannot be converted to Date"
+    );
+    assert_eq!(table_writer.size(), 0);
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -616,7 +667,7 @@ async fn test_table_writer_auto_flush() {
     let _ = client_writer
         .run_script(format!("share table(10:0,[`data],[INT]) as `{TABLE}").as_str())
         .await;
-    let mut table_writer = TableWriter::new(client_writer, TABLE, 3).await;
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 3).await.unwrap();
     for i in 0..3 {
         let _ = table_writer
             .append_row(&mut vec![PrimitiveType::I32(i)])
@@ -624,3 +675,536 @@ async fn test_table_writer_auto_flush() {
     }
     assert_eq!(table_writer.size(), 0);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_row_rejected_keeps_buffer() {
+    const TABLE: &str = "test_table_writer_row_rejected_keeps_buffer";
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    // create table writer
+    let _ = client_writer
+        .run_script(format!("share table(10:0,`data1`data2,[INT,DATE]) as `{TABLE}").as_str())
+        .await;
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 2).await.unwrap();
+    let date = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let result = table_writer
+        .append_row(&mut vec![
+            PrimitiveType::I32(0),
+            PrimitiveType::NaiveDate(date),
+        ])
+        .await;
+    assert!(result.unwrap().is_none());
+    let result = table_writer
+        .append_row(&mut vec![
+            PrimitiveType::I32(1),
+            PrimitiveType::NaiveTime(NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+        ])
+        .await;
+    match result {
+        Err(Error::InvalidRow { row, column, .. }) => {
+            assert_eq!(row, 1);
+            assert_eq!(column, "data2");
+        }
+        _ => panic!("expect InvalidRow"),
+    }
+    assert_eq!(table_writer.size(), 1);
+    let result = table_writer
+        .append_row(&mut vec![
+            PrimitiveType::I32(2),
+            PrimitiveType::NaiveDate(date),
+        ])
+        .await;
+    assert_eq!(result.unwrap().unwrap(), Int::new(2).into());
+    assert_eq!(table_writer.size(), 0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_dfs_table() {
+    const DB: &str = "dfs://test_table_writer_dfs_table";
+    const TABLE: &str = "pt";
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    let mut builder_query = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_query.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_query = builder_query.connect().await.unwrap();
+    // create table writer
+    client_writer
+        .run_script(
+            format!(
+                r#"
+                if (existsDatabase("{DB}")) {{ dropDatabase("{DB}") }}
+                db = database("{DB}", VALUE, 1..10)
+                t = table(10:0, `id`value, [INT, DOUBLE])
+                db.createPartitionedTable(t, "{TABLE}", `id)
+            "#
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+    let mut table_writer = TableWriter::new(client_writer, (DB, TABLE), 4)
+        .await
+        .unwrap();
+    for i in 0..4 {
+        let _ = table_writer
+            .append_row(&mut vec![
+                PrimitiveType::I32(i % 3 + 1),
+                PrimitiveType::F64(i as f64),
+            ])
+            .await
+            .unwrap();
+    }
+    assert_eq!(table_writer.size(), 0);
+    let res = client_query
+        .run_script(format!(r#"exec count(*) from loadTable("{DB}", "{TABLE}")"#).as_str())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(res, Long::new(4).into());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_insert_function() {
+    const TABLE: &str = "test_table_writer_insert_function";
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    // create table writer
+    client_writer
+        .run_script(
+            format!(
+                r#"
+                share table(10:0,[`data],[INT]) as `{TABLE}
+                def {TABLE}_insert(t) {{ return tableInsert(objByName("{TABLE}"), select data * 2 as data from t) }}
+            "#
+            )
+            .as_str(),
+        )
+        .await
+        .unwrap();
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 2).await.unwrap();
+    table_writer.with_insert_function(format!("{TABLE}_insert").as_str());
+    let _ = table_writer
+        .append_row(&mut vec![PrimitiveType::I32(1)])
+        .await
+        .unwrap();
+    let res = table_writer
+        .append_row(&mut vec![PrimitiveType::I32(2)])
+        .await
+        .unwrap();
+    assert_eq!(res.unwrap(), Int::new(2).into());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_append_columns() {
+    const TABLE: &str = "test_table_writer_append_columns";
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    let mut builder_query = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_query.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_query = builder_query.connect().await.unwrap();
+    // create table writer
+    let _ = client_writer
+        .run_script(
+            format!("share table(10:0,`data1`data2`data3,[INT,DATE,SYMBOL]) as `{TABLE}").as_str(),
+        )
+        .await;
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 4).await.unwrap();
+    let mut dates = DateVector::new();
+    for day in 1..=10 {
+        dates.push(Date::from_ymd(2024, 1, day).unwrap());
+    }
+    let columns: Vec<VectorImpl> = vec![
+        IntVector::from_raw(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]).into(),
+        dates.into(),
+        SymbolVector::from_raw(&["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"]).into(),
+    ];
+    // two full batches are inserted, two rows stay buffered.
+    let results = table_writer.append_columns(columns).await.unwrap();
+    assert_eq!(results, vec![Int::new(4).into(), Int::new(4).into()]);
+    assert_eq!(table_writer.size(), 2);
+    table_writer.flush().await.unwrap();
+    let res = client_query
+        .run_script(
+            format!("eqObj({TABLE}, table(0..9 as data1, 2024.01.01..2024.01.10 as data2, symbol(`a`b`c`d`e`f`g`h`i`j) as data3))").as_str(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(res, Bool::new(true).into());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_append_columns_type_error() {
+    const TABLE: &str = "test_table_writer_append_columns_type_error";
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    // create table writer
+    let _ = client_writer
+        .run_script(format!("share table(10:0,`data1`data2,[INT,DATE]) as `{TABLE}").as_str())
+        .await;
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 4).await.unwrap();
+    let columns: Vec<VectorImpl> = vec![
+        IntVector::from_raw(&[0, 1]).into(),
+        IntVector::from_raw(&[0, 1]).into(),
+    ];
+    assert!(matches!(
+        table_writer.append_columns(columns).await,
+        Err(Error::InvalidData { .. })
+    ));
+    assert_eq!(table_writer.size(), 0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_append_map() {
+    const TABLE: &str = "test_table_writer_append_map";
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    let mut builder_query = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_query.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_query = builder_query.connect().await.unwrap();
+    // create table writer
+    let _ = client_writer
+        .run_script(
+            format!("share table(10:0,`data1`data2`data3,[INT,STRING,DOUBLE]) as `{TABLE}")
+                .as_str(),
+        )
+        .await;
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 3).await.unwrap();
+    table_writer
+        .with_default("data3", PrimitiveType::F64(1.5))
+        .unwrap();
+    assert!(table_writer
+        .with_default("not_exist", PrimitiveType::None)
+        .is_err());
+    let _ = table_writer
+        .append_map(HashMap::from([
+            ("data1", PrimitiveType::I32(1)),
+            ("data2", PrimitiveType::String("a".into())),
+            ("data3", PrimitiveType::F64(2.5)),
+        ]))
+        .await
+        .unwrap();
+    let _ = table_writer
+        .append_map([("data2", PrimitiveType::String("b".into()))])
+        .await
+        .unwrap();
+    // unknown and repeated columns reject the row.
+    let result = table_writer
+        .append_map([("not_exist", PrimitiveType::I32(1))])
+        .await;
+    match result {
+        Err(Error::InvalidRow { row, column, .. }) => {
+            assert_eq!(row, 2);
+            assert_eq!(column, "not_exist");
+        }
+        _ => panic!("expect InvalidRow"),
+    }
+    assert!(table_writer
+        .append_map([
+            ("data1", PrimitiveType::I32(1)),
+            ("data1", PrimitiveType::I32(2)),
+        ])
+        .await
+        .is_err());
+    assert_eq!(table_writer.size(), 2);
+    let result = table_writer
+        .append_map([("data1", PrimitiveType::I32(3))])
+        .await
+        .unwrap();
+    assert_eq!(result.unwrap(), Int::new(3).into());
+    let res = client_query
+        .run_script(
+            format!(r#"eqObj({TABLE}, table([1, NULL, 3] as data1, ["a", "b", ""] as data2, [2.5, 1.5, 1.5] as data3))"#).as_str(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(res, Bool::new(true).into());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_spool() {
+    const TABLE: &str = "test_table_writer_spool";
+    let dir = std::env::temp_dir().join(TABLE);
+    let _ = std::fs::remove_dir_all(&dir);
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    // create table writer
+    let _ = client_writer
+        .run_script(format!("share table(10:0,[`data],[INT]) as `{TABLE}").as_str())
+        .await;
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 2).await.unwrap();
+    table_writer.with_spool(&SpoolOptions::new(&dir)).unwrap();
+    let _ = table_writer
+        .append_row(&mut vec![PrimitiveType::I32(1)])
+        .await
+        .unwrap();
+    let result = table_writer
+        .append_row(&mut vec![PrimitiveType::I32(2)])
+        .await
+        .unwrap();
+    assert_eq!(result.unwrap(), Int::new(2).into());
+    let metrics = table_writer.spool_metrics().unwrap();
+    assert_eq!(metrics.spooled_batches, 1);
+    assert_eq!(metrics.acknowledged_batches, 1);
+    assert_eq!(metrics.pending_batches, 0);
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_spool_replay() {
+    const TABLE: &str = "test_table_writer_spool_replay";
+    let dir = std::env::temp_dir().join(TABLE);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    // batches a previous process left behind, the interrupted one is discarded.
+    for (seq, data) in [(0, [1, 2]), (1, [3, 4])] {
+        let mut builder = TableBuilder::new();
+        builder.with_contents(
+            vec![IntVector::from_raw(&data).into()],
+            vec!["data".to_string()],
+        );
+        let table: ConstantImpl = builder.build().unwrap().into();
+        let bytes = dolphindb::codec::encode(&table, dolphindb::codec::Endian::Little).unwrap();
+        std::fs::write(dir.join(format!("{:020}.batch", seq)), &bytes).unwrap();
+    }
+    std::fs::write(dir.join(format!("{:020}.tmp", 2)), b"partial").unwrap();
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    let mut builder_query = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_query.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_query = builder_query.connect().await.unwrap();
+    // create table writer
+    let _ = client_writer
+        .run_script(format!("share table(10:0,[`data],[INT]) as `{TABLE}").as_str())
+        .await;
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 2).await.unwrap();
+    table_writer.with_spool(&SpoolOptions::new(&dir)).unwrap();
+    assert_eq!(table_writer.spool_metrics().unwrap().pending_batches, 2);
+    table_writer.replay().await.unwrap();
+    assert_eq!(table_writer.spool_metrics().unwrap().pending_batches, 0);
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+    let res = client_query
+        .run_script(format!("eqObj(exec data from {TABLE}, 1 2 3 4)").as_str())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(res, Bool::new(true).into());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_spool_reconnect() {
+    const TABLE: &str = "test_table_writer_spool_reconnect";
+    let dir = std::env::temp_dir().join(TABLE);
+    let _ = std::fs::remove_dir_all(&dir);
+    // connect
+    let connect = || {
+        let conf = Config::new();
+        async move {
+            let mut builder = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+            builder.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+            builder.connect().await
+        }
+    };
+    let mut client_writer = connect().await.unwrap();
+    let mut client_query = connect().await.unwrap();
+    // create table writer
+    let _ = client_writer
+        .run_script(format!("share table(10:0,[`data],[INT]) as `{TABLE}").as_str())
+        .await;
+    let session = client_writer
+        .run_script("getCurrentSessionAndUser()[0]")
+        .await
+        .unwrap()
+        .unwrap();
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 2).await.unwrap();
+    table_writer
+        .with_spool(&SpoolOptions::new(&dir))
+        .unwrap()
+        .with_reconnect(connect)
+        .unwrap();
+    // the server drops the writer's connection.
+    let _ = client_query
+        .run_script(format!("closeSessions({session})").as_str())
+        .await;
+    table_writer
+        .append_row(&mut vec![PrimitiveType::I32(1)])
+        .await
+        .unwrap();
+    table_writer
+        .append_row(&mut vec![PrimitiveType::I32(2)])
+        .await
+        .unwrap();
+    let metrics = table_writer.spool_metrics().unwrap();
+    assert_eq!(metrics.pending_batches, 0);
+    assert_eq!(metrics.acknowledged_batches, 1);
+    let res = client_query
+        .run_script(format!("eqObj(exec data from {TABLE}, 1 2)").as_str())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(res, Bool::new(true).into());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_sink_rows() {
+    const TABLE: &str = "test_table_writer_sink_rows";
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    let mut builder_query = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_query.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_query = builder_query.connect().await.unwrap();
+    // create table writer
+    let _ = client_writer
+        .run_script(format!("share table(10:0,[`data],[INT]) as `{TABLE}").as_str())
+        .await;
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 2).await.unwrap();
+    let rows = stream::iter(1..=5).map(|i| Ok(vec![PrimitiveType::I32(i)]));
+    rows.forward(&mut table_writer).await.unwrap();
+    let summary = table_writer.close().await.unwrap();
+    assert_eq!(
+        summary,
+        WriteSummary {
+            rows: 5,
+            inserted_rows: 5,
+            batches: 3,
+        }
+    );
+    let res = client_query
+        .run_script(format!("eqObj(exec data from {TABLE}, 1 2 3 4 5)").as_str())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(res, Bool::new(true).into());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_sink_tables() {
+    const TABLE: &str = "test_table_writer_sink_tables";
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    // create table writer
+    let _ = client_writer
+        .run_script(format!("share table(10:0,[`data],[INT]) as `{TABLE}").as_str())
+        .await;
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 2).await.unwrap();
+    for data in [&[1, 2, 3][..], &[4, 5][..]] {
+        let mut builder = TableBuilder::new();
+        builder.with_contents(
+            vec![IntVector::from_raw(data).into()],
+            vec!["data".to_string()],
+        );
+        table_writer.feed(builder.build().unwrap()).await.unwrap();
+    }
+    let summary = table_writer.close().await.unwrap();
+    assert_eq!(summary.rows, 5);
+    assert_eq!(summary.inserted_rows, 5);
+    assert_eq!(summary.batches, 3);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_sink_large_table() {
+    const TABLE: &str = "test_table_writer_sink_large_table";
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    // create table writer
+    let _ = client_writer
+        .run_script(format!("share table(10:0,[`data],[INT]) as `{TABLE}").as_str())
+        .await;
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 2).await.unwrap();
+    let mut builder = TableBuilder::new();
+    builder.with_contents(
+        vec![IntVector::from_raw(&[1, 2, 3, 4, 5, 6, 7]).into()],
+        vec!["data".to_string()],
+    );
+    let table = builder.build().unwrap();
+    // the table is buffered whole.
+    table_writer.feed(table.clone()).await.unwrap();
+    assert_eq!(table_writer.size(), 7);
+    // and sent in batches before the next one is taken.
+    table_writer.feed(table.head(1)).await.unwrap();
+    assert_eq!(table_writer.size(), 2);
+    let summary = table_writer.close().await.unwrap();
+    assert_eq!(summary.inserted_rows, 8);
+    assert_eq!(summary.batches, 4);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_writer_sink_error() {
+    const TABLE: &str = "test_table_writer_sink_error";
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    // create table writer
+    let _ = client_writer
+        .run_script(format!("share table(10:0,[`data],[INT]) as `{TABLE}").as_str())
+        .await;
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 2).await.unwrap();
+    let rows = stream::iter([
+        Ok(vec![PrimitiveType::I32(1)]),
+        Ok(vec![PrimitiveType::String("a".into())]),
+    ]);
+    let result = rows.forward(&mut table_writer).await;
+    assert!(matches!(result, Err(Error::InvalidRow { row: 1, .. })));
+}
+
+// dropping a writer with buffered rows used to panic on a current-thread runtime, the rows
+// can only be kept in a spool there.
+#[tokio::test]
+async fn test_table_writer_drop_current_thread() {
+    const TABLE: &str = "test_table_writer_drop_current_thread";
+    let dir = std::env::temp_dir().join(TABLE);
+    let _ = std::fs::remove_dir_all(&dir);
+    // connect
+    let conf = Config::new();
+    let mut builder_writer = ClientBuilder::new(format!("{}:{}", conf.host, conf.port));
+    builder_writer.with_auth((conf.user.as_str(), conf.passwd.as_str()));
+    let mut client_writer = builder_writer.connect().await.unwrap();
+    // create table writer
+    let _ = client_writer
+        .run_script(format!("share table(10:0,[`data],[INT]) as `{TABLE}").as_str())
+        .await;
+    let mut table_writer = TableWriter::new(client_writer, TABLE, 2).await.unwrap();
+    table_writer.with_spool(&SpoolOptions::new(&dir)).unwrap();
+    let _ = table_writer
+        .append_row(&mut vec![PrimitiveType::I32(1)])
+        .await
+        .unwrap();
+    drop(table_writer);
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+}
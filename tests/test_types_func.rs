@@ -616,6 +616,19 @@ mod test_types_func_type {
         assert_eq!(result, 3.14f32);
     }
 
+    #[test]
+    fn test_types_func_type_float_as_mut_write_back() {
+        let mut result = Float::new(2.5f32);
+        *result.as_mut() = Some(3.5f32);
+        assert_eq!(result, Float::new(3.5f32));
+        assert_eq!(*result.as_ref(), Some(3.5f32));
+        *result.as_mut() = None;
+        assert!(result.is_null());
+        assert_eq!(*result.as_ref(), None);
+        *result.native_mut() = 1.5f32;
+        assert_eq!(result.native(), 1.5f32);
+    }
+
     // hash
     #[test]
     fn test_types_func_type_float_hash() {
@@ -998,6 +1011,87 @@ mod test_types_func_type {
         assert_eq!(any_default, ConstantImpl::Scalar(ScalarImpl::default()));
         assert_eq!(any_default, ConstantImpl::default());
     }
+
+    // duration
+    macro_test_is_null!(
+        test_types_func_type_is_null_duration,
+        Duration,
+        Duration::new(5, DurationUnit::Minute) => false,
+        Duration::default() => true
+    );
+
+    #[rstest]
+    #[case(Duration::new(5, DurationUnit::Minute), "5m")]
+    #[case(Duration::new(-3, DurationUnit::Month), "-3M")]
+    #[case(Duration::new(2, DurationUnit::BusinessDay), "2B")]
+    #[case(Duration::from_exchange(1, "XNYS").unwrap(), "1XNYS")]
+    #[case(Duration::default(), "null")]
+    fn test_types_func_type_duration_display(#[case] value: Duration, #[case] expect: &str) {
+        assert_eq!(value.to_string(), expect);
+    }
+
+    #[test]
+    fn test_types_func_type_duration_unit_suffix() {
+        assert_eq!(
+            DurationUnit::from_suffix("ns"),
+            Some(DurationUnit::Nanosecond)
+        );
+        assert_eq!(DurationUnit::from_suffix("H"), Some(DurationUnit::Hour));
+        assert_eq!(DurationUnit::from_suffix("M"), Some(DurationUnit::Month));
+        assert_eq!(DurationUnit::from_suffix("m"), Some(DurationUnit::Minute));
+        assert_eq!(
+            DurationUnit::from_suffix("XSHG"),
+            DurationUnit::exchange("XSHG")
+        );
+        assert!(DurationUnit::from_suffix("xnys").is_none());
+        assert!(DurationUnit::from_suffix("XNY").is_none());
+        assert!(Duration::from_exchange(1, "XNYSE").is_none());
+    }
+
+    #[rstest]
+    #[case(
+        std::time::Duration::from_secs(300),
+        Duration::new(5, DurationUnit::Minute)
+    )]
+    #[case(
+        std::time::Duration::from_secs(86400),
+        Duration::new(1, DurationUnit::Day)
+    )]
+    #[case(
+        std::time::Duration::from_millis(1500),
+        Duration::new(1500, DurationUnit::Millisecond)
+    )]
+    #[case(
+        std::time::Duration::from_nanos(7),
+        Duration::new(7, DurationUnit::Nanosecond)
+    )]
+    #[case(std::time::Duration::ZERO, Duration::new(0, DurationUnit::Week))]
+    fn test_types_func_type_duration_from_std(
+        #[case] value: std::time::Duration,
+        #[case] expect: Duration,
+    ) {
+        assert_eq!(Duration::try_from(value).unwrap(), expect);
+        assert_eq!(std::time::Duration::try_from(expect).unwrap(), value);
+    }
+
+    #[test]
+    fn test_types_func_type_duration_from_chrono() {
+        let result = Duration::try_from(chrono::Duration::milliseconds(-2500)).unwrap();
+        assert_eq!(result, Duration::new(-2500, DurationUnit::Millisecond));
+        assert_eq!(
+            chrono::Duration::try_from(result).unwrap(),
+            chrono::Duration::milliseconds(-2500)
+        );
+    }
+
+    #[test]
+    fn test_types_func_type_duration_convert_error() {
+        assert!(Duration::try_from(std::time::Duration::from_secs(u64::MAX)).is_err());
+        assert!(std::time::Duration::try_from(Duration::new(1, DurationUnit::Month)).is_err());
+        assert!(std::time::Duration::try_from(Duration::new(-1, DurationUnit::Second)).is_err());
+        assert!(chrono::Duration::try_from(Duration::from_exchange(1, "XNYS").unwrap()).is_err());
+        assert!(chrono::Duration::try_from(Duration::default()).is_err());
+    }
 }
 
 mod test_types_func_form {
@@ -1284,27 +1378,146 @@ mod test_types_func_form {
         assert_eq!(result, Bool::new(true).into());
     }
 
+    #[test]
+    fn test_types_func_form_vector_as_slice() {
+        let mut vector = IntVector::from_raw(&[1, 2, 3]);
+        vector.push(Int::default());
+        assert_eq!(vector.as_slice(), &[1, 2, 3, Int::NULL_VALUE]);
+        vector.as_mut_slice()[0] = 4;
+        assert_eq!(vector[0], Int::new(4));
+        let vector = DoubleVector::from_raw(&[1.5, f64::MIN]);
+        assert_eq!(vector.as_slice(), &[1.5, Double::NULL_VALUE]);
+        assert!(vector[1].is_null());
+    }
+
+    #[test]
+    fn test_types_func_form_vector_extend_from_slice_validity() {
+        let mut vector = LongVector::new();
+        vector.extend_from_slice(&[0, i64::MIN, 2, 3, 4, 5, 6, 7, i64::MIN]);
+        assert_eq!(vector.len(), 9);
+        assert_eq!(vector[1], Long::default());
+        assert_eq!(vector.validity(), vec![0b11111101u8, 0b0]);
+        assert_eq!(vector.null_count(), 2);
+        assert_eq!(CharVector::new().validity(), Vec::<u8>::new());
+    }
+
     // array vector
     #[test]
     fn test_types_func_form_array_vector_index() {
         let array_vector = array_vector_build!(
-            f64,
-            vec![0.0f64, 3.14f64, f64::MIN],
-            vec![3.14f64, 3.15f64, 3.16f64]
+            Double,
+            vec![Double::new(0.0f64), Double::new(3.14f64), Double::default()],
+            vec![
+                Double::new(3.14f64),
+                Double::new(3.15f64),
+                Double::new(3.16f64)
+            ]
+        );
+        assert_eq!(
+            array_vector[0],
+            vec![Double::new(0.0f64), Double::new(3.14f64), Double::default()]
+        );
+        assert_eq!(
+            array_vector[1],
+            vec![
+                Double::new(3.14f64),
+                Double::new(3.15f64),
+                Double::new(3.16f64)
+            ]
         );
-        assert_eq!(array_vector[0], vec![0.0f64, 3.14f64, f64::MIN]);
-        assert_eq!(array_vector[1], vec![3.14f64, 3.15f64, 3.16f64]);
     }
 
     #[test]
     fn test_types_func_form_array_vector_index_mut() {
         let mut array_vector = array_vector_build!(
-            f64,
-            vec![0.0f64, 3.14f64, f64::MIN],
-            vec![3.14f64, 3.15f64, 3.16f64]
+            Double,
+            vec![Double::new(0.0f64), Double::new(3.14f64), Double::default()],
+            vec![
+                Double::new(3.14f64),
+                Double::new(3.15f64),
+                Double::new(3.16f64)
+            ]
+        );
+        array_vector[0][0] = Double::new(3.14f64);
+        assert_eq!(
+            array_vector[0],
+            vec![
+                Double::new(3.14f64),
+                Double::new(3.14f64),
+                Double::default()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_types_func_form_array_vector_push_raw_null() {
+        let mut array_vector = IntArrayVector::new();
+        array_vector.push_raw(Some(&[Some(1), None]));
+        array_vector.push_raw(None);
+        array_vector.push_raw(Some(&[]));
+        array_vector.push_null();
+        assert_eq!(array_vector.len(), 4);
+        assert!(!array_vector.is_null(0));
+        assert!(array_vector.is_null(1));
+        assert!(!array_vector.is_null(2));
+        assert!(array_vector.is_null(3));
+        assert_eq!(
+            array_vector.get(0),
+            Some(Some(&[Int::new(1), Int::default()][..]))
+        );
+        assert_eq!(array_vector.get(1), Some(None));
+        assert_eq!(array_vector.get(2), Some(Some(&[][..])));
+        assert_eq!(array_vector.get(4), None);
+        assert_eq!(array_vector.to_string(), "[[1,null], null, [], null]");
+    }
+
+    #[test]
+    fn test_types_func_form_array_vector_iter() {
+        let mut array_vector = IntArrayVector::new();
+        array_vector.push_raw(Some(&[Some(1), Some(2)]));
+        array_vector.push_null();
+        array_vector.push_raw(Some(&[None]));
+        let rows: Vec<_> = array_vector.iter().collect();
+        assert_eq!(
+            rows,
+            vec![
+                Some(&[Int::new(1), Int::new(2)][..]),
+                None,
+                Some(&[Int::default()][..])
+            ]
         );
-        array_vector[0][0] = 3.14f64;
-        assert_eq!(array_vector[0], vec![3.14f64, 3.14f64, f64::MIN]);
+    }
+
+    #[test]
+    fn test_types_func_form_array_vector_insert_remove() {
+        let mut array_vector = IntArrayVector::new();
+        array_vector.push_raw(Some(&[Some(1), Some(2)]));
+        array_vector.push_raw(Some(&[Some(3)]));
+        array_vector.insert(1, None);
+        array_vector.insert(0, Some(vec![Int::new(0)]));
+        assert_eq!(array_vector.to_string(), "[[0], [1,2], null, [3]]");
+        assert_eq!(array_vector.remove(2), None);
+        assert_eq!(array_vector.remove(1), Some(vec![Int::new(1), Int::new(2)]));
+        assert_eq!(array_vector.to_string(), "[[0], [3]]");
+        assert_eq!(array_vector[1], [Int::new(3)]);
+        array_vector.insert(2, Some(vec![]));
+        assert_eq!(array_vector.to_string(), "[[0], [3], []]");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_types_func_form_array_vector_insert_out_of_bounds() {
+        let mut array_vector = IntArrayVector::new();
+        array_vector.insert(1, None);
+    }
+
+    #[test]
+    fn test_types_func_form_array_vector_null_rows_eq() {
+        let mut array_vector_1 = IntArrayVector::new();
+        array_vector_1.push_null();
+        let mut array_vector_2 = IntArrayVector::new();
+        array_vector_2.push(vec![]);
+        assert_ne!(array_vector_1, array_vector_2);
     }
 
     #[test]
@@ -1337,31 +1550,129 @@ mod test_types_func_form {
 
     #[test]
     fn test_types_func_form_array_vector_from() {
-        let _vector_impl: VectorImpl =
-            array_vector_build!(i32, vec![0i32, 1i32, 2i32], vec![], vec![-127i32]).into();
-        let _constant_impl: ConstantImpl =
-            array_vector_build!(i32, vec![0i32, 1i32, 2i32], vec![], vec![-127i32]).into();
+        let _vector_impl: VectorImpl = array_vector_build!(
+            Int,
+            vec![0.into(), 1.into(), 2.into()],
+            vec![],
+            vec![(-127).into()]
+        )
+        .into();
+        let _constant_impl: ConstantImpl = array_vector_build!(
+            Int,
+            vec![0.into(), 1.into(), 2.into()],
+            vec![],
+            vec![(-127).into()]
+        )
+        .into();
+    }
+
+    #[test]
+    fn test_types_func_form_array_vector_decimal_display() {
+        let array_vector = array_vector_build!(
+            Decimal32,
+            vec![Decimal32::from_raw(123, 2).unwrap(), Decimal32::default()],
+            vec![]
+        );
+        assert_eq!(array_vector.to_string(), "[[1.23,null], []]");
+    }
+
+    #[test]
+    fn test_types_func_form_array_vector_push_primitive_type() {
+        let mut dates = VectorImpl::from(DateArrayVector::new());
+        dates
+            .push_primitive_type(vec![NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()].into())
+            .unwrap();
+        assert_eq!(
+            dates,
+            array_vector_build!(Date, vec![Date::from_ymd(2024, 2, 29).unwrap()]).into()
+        );
+
+        let mut prices = VectorImpl::from(Decimal32ArrayVector::new());
+        prices
+            .push_primitive_type(vec![Decimal::new(123, 2), Decimal::new(5, 1)].into())
+            .unwrap();
+        assert_eq!(
+            prices,
+            array_vector_build!(
+                Decimal32,
+                vec![
+                    Decimal32::from_raw(123, 2).unwrap(),
+                    Decimal32::from_raw(5, 1).unwrap()
+                ]
+            )
+            .into()
+        );
+        // mantissa out of range for DECIMAL32
+        assert!(prices
+            .push_primitive_type(vec![Decimal::new(i64::MAX, 2)].into())
+            .is_err());
+        // element type mismatch
+        assert!(prices.push_primitive_type(vec![1i32].into()).is_err());
+        assert_eq!(prices.len(), 1);
+    }
+
+    #[test]
+    fn test_types_func_form_vector_push_primitive_type() {
+        let mut prices = VectorImpl::from(Decimal32Vector::with_scale(2).unwrap());
+        prices
+            .push_primitive_type(Decimal::new(12345, 3).into())
+            .unwrap();
+        prices.push_primitive_type(None::<Decimal>.into()).unwrap();
+        let mut expect = Decimal32Vector::from(vec![
+            Decimal32::from_raw(1235, 2).unwrap(),
+            Decimal32::default(),
+        ]);
+        expect.set_scale(2).unwrap();
+        assert_eq!(prices, expect.into());
+        assert!(prices
+            .push_primitive_type(Decimal::new(i64::MAX, 2).into())
+            .is_err());
+        assert_eq!(prices.len(), 2);
+
+        let mut blobs = VectorImpl::from(BlobVector::new());
+        blobs.push_primitive_type(vec![1u8, 2].into()).unwrap();
+        assert_eq!(blobs, BlobVector::from(vec![Blob::new(vec![1, 2])]).into());
+
+        let mut symbols = VectorImpl::from(SymbolVector::new());
+        symbols
+            .push_primitive_type(PrimitiveType::Symbol("a".into()))
+            .unwrap();
+        symbols.push_primitive_type("b".to_string().into()).unwrap();
+        assert_eq!(symbols, SymbolVector::from_raw(&["a", "b"]).into());
+
+        let mut chars = VectorImpl::from(CharVector::new());
+        chars.push_primitive_type(b'a'.into()).unwrap();
+        chars.push_primitive_type(None::<u8>.into()).unwrap();
+        assert_eq!(
+            chars,
+            CharVector::from(vec![Char::new(b'a' as i8), Char::default()]).into()
+        );
+
+        let mut uuids = VectorImpl::from(UuidVector::new());
+        uuids.push_primitive_type(1u128.into()).unwrap();
+        assert_eq!(uuids, UuidVector::from(vec![Uuid::new(1)]).into());
+        assert!(uuids.push_primitive_type(1i128.into()).is_err());
     }
 
     // array vector impl
     #[test]
     fn test_types_func_form_array_vector_impl_len_is_empty() {
         let array_vector_impl_1 = ArrayVectorImpl::Int(array_vector_build!(
-            i32,
-            vec![0i32, 1i32, 2i32],
-            vec![0i32],
-            vec![1i32]
+            Int,
+            vec![Int::new(0i32), Int::new(1i32), Int::new(2i32)],
+            vec![Int::new(0i32)],
+            vec![Int::new(1i32)]
         ));
         assert!(!array_vector_impl_1.is_empty());
         assert_eq!(array_vector_impl_1.len(), 3);
-        let array_vector_impl_2 = ArrayVectorImpl::Int(ArrayVector::<i32>::new());
+        let array_vector_impl_2 = ArrayVectorImpl::Int(IntArrayVector::new());
         assert!(array_vector_impl_2.is_empty());
         assert_eq!(array_vector_impl_2.len(), 0);
     }
 
     #[test]
     fn test_types_func_form_array_vector_impl_data_form_data_type() {
-        let array_vector_impl = ArrayVectorImpl::Int(ArrayVector::<i32>::new());
+        let array_vector_impl = ArrayVectorImpl::Int(IntArrayVector::new());
         assert_eq!(array_vector_impl.data_form(), DataForm::Vector);
         assert_eq!(array_vector_impl.data_type(), DataType::IntArray);
     }
@@ -1369,10 +1680,10 @@ mod test_types_func_form {
     #[test]
     fn test_types_func_form_array_vector_impl_display() {
         let array_vector_impl = ArrayVectorImpl::Int(array_vector_build!(
-            i32,
-            vec![0i32, 1i32, 2i32],
+            Int,
+            vec![Int::new(0i32), Int::new(1i32), Int::new(2i32)],
             vec![],
-            vec![-127i32]
+            vec![Int::new(-127i32)]
         ));
         println!("{array_vector_impl}");
     }
@@ -1969,6 +2280,1106 @@ mod test_types_func_form {
         );
     }
 
+    fn sample_rows_table() -> Table {
+        table_build!(
+            String::from("id") => vector_build!(Int, Int::new(1), Int::new(2), Int::new(3), Int::default()),
+            String::from("name") => vector_build!(
+                Symbol,
+                Symbol::new("a".into()),
+                Symbol::new("b".into()),
+                Symbol::new("c".into()),
+                Symbol::new("d".into())
+            )
+        )
+    }
+
+    #[test]
+    fn test_types_func_form_table_row() {
+        let table = sample_rows_table();
+        let row = table.row(1).unwrap();
+        assert_eq!(row.index(), 1);
+        assert_eq!(row.len(), 2);
+        assert_eq!(row.get(0), Some(Int::new(2).into()));
+        assert_eq!(
+            row.get_by_name("name"),
+            Some(Symbol::new("b".into()).into())
+        );
+        assert_eq!(row.get_as::<Int>("id").unwrap(), Int::new(2));
+        assert!(row.get_as::<Int>("name").is_err());
+        assert!(row.get_as::<Int>("none").is_err());
+        assert!(table.row(4).is_none());
+        let ids: Vec<_> = table.rows().map(|r| r.get(0).unwrap()).collect();
+        assert_eq!(ids.len(), 4);
+        assert_eq!(ids[3], Int::default().into());
+    }
+
+    #[test]
+    fn test_types_func_form_table_row_array_vector() {
+        let mut values = IntArrayVector::new();
+        values.push_raw(Some(&[Some(1), None]));
+        values.push_null();
+        let mut builder = TableBuilder::new();
+        builder.with_contents(
+            vec![
+                vector_build!(Int, Int::new(1), Int::new(2)).into(),
+                values.into(),
+            ],
+            vec!["id".to_string(), "values".to_string()],
+        );
+        let table = builder.build().unwrap();
+        let rows: Vec<_> = table.rows().map(|r| r.get(1).unwrap()).collect();
+        let expect: VectorImpl = vector_build!(Int, Int::new(1), Int::default()).into();
+        assert_eq!(rows[0], expect.into());
+        let expect: VectorImpl = vector_build!(Int, Int::default()).into();
+        assert_eq!(rows[1], expect.into());
+        assert_eq!(
+            table
+                .row(0)
+                .unwrap()
+                .iter()
+                .filter(|(_, v)| v.is_none())
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_types_func_form_table_append_row_array_vector() {
+        let mut values = IntArrayVector::new();
+        values.push_raw(Some(&[Some(1)]));
+        let mut prices = Decimal32ArrayVector::new();
+        prices.push(vec![Decimal32::from_raw(15, 1).unwrap()]);
+        let mut prices = VectorImpl::from(prices);
+        prices.set_decimal_scale(1).unwrap();
+        let mut builder = TableBuilder::new();
+        builder.with_contents(
+            vec![values.into(), prices],
+            vec!["values".to_string(), "prices".to_string()],
+        );
+        let mut table = builder.build().unwrap();
+        let len = table.len();
+
+        let row: VectorImpl = vector_build!(Int, Int::new(2), Int::default()).into();
+        let price: VectorImpl =
+            vector_build!(Decimal32, Decimal32::from_raw(125, 2).unwrap()).into();
+        table
+            .append_row(vec![row.clone().into(), price.into()])
+            .unwrap();
+        assert_eq!(table.len(), len + 1);
+        assert_eq!(table.row(len).unwrap().get(0).unwrap(), row.clone().into());
+        let expect: VectorImpl =
+            vector_build!(Decimal32, Decimal32::from_raw(13, 1).unwrap()).into();
+        assert_eq!(
+            table.row(len).unwrap().get(1).unwrap(),
+            expect.clone().into()
+        );
+
+        // rows must hold the element type, scalars are rejected.
+        let longs: VectorImpl = vector_build!(Long, Long::new(1)).into();
+        assert!(table
+            .append_row(vec![longs.into(), expect.clone().into()])
+            .is_err());
+        assert!(table
+            .append_row(vec![Int::new(1).into(), expect.into()])
+            .is_err());
+        assert_eq!(table.len(), len + 1);
+    }
+
+    #[test]
+    fn test_types_func_form_table_slice_head_tail() {
+        let table = sample_rows_table();
+        assert_eq!(
+            *table
+                .slice(1..3)
+                .unwrap()
+                .get_columns_by_name("id")
+                .unwrap(),
+            vector_build!(Int, Int::new(2), Int::new(3)).into()
+        );
+        assert_eq!(table.slice(..).unwrap(), table);
+        assert!(table.slice(2..5).is_err());
+        assert!(table.slice(..=usize::MAX).is_err());
+        assert!(table
+            .slice((
+                std::ops::Bound::Excluded(usize::MAX),
+                std::ops::Bound::Unbounded
+            ))
+            .is_err());
+        assert_eq!(table.head(2), table.slice(..2).unwrap());
+        assert_eq!(table.tail(1), table.slice(3..).unwrap());
+        assert_eq!(table.head(10), table);
+        assert!(table.tail(0).is_empty());
+    }
+
+    #[test]
+    fn test_types_func_form_table_take_filter_select() {
+        let table = sample_rows_table();
+        let taken = table.take(&[3, 0, 0]).unwrap();
+        assert_eq!(
+            *taken.get_columns_by_index(0),
+            vector_build!(Int, Int::default(), Int::new(1), Int::new(1)).into()
+        );
+        assert!(table.take(&[4]).is_err());
+        let filtered = table.filter(&[true, false, false, true]).unwrap();
+        assert_eq!(filtered, table.take(&[0, 3]).unwrap());
+        assert!(table.filter(&[true]).is_err());
+        let selected = table.select(&["name"]).unwrap();
+        assert_eq!(selected.column_names(), &vec![String::from("name")]);
+        assert!(table.select(&["none"]).is_err());
+        assert!(table.select(&["id", "id"]).is_err());
+    }
+
+    #[test]
+    fn test_types_func_form_table_concat() {
+        let table = sample_rows_table();
+        let res = table.concat(&[table.head(1)]).unwrap();
+        assert_eq!(res.len(), 5);
+        assert_eq!(res.row(4).unwrap().get(0), Some(Int::new(1).into()));
+        let other = table_build!(String::from("id") => vector_build!(Int, Int::new(1)));
+        assert!(table.concat(&[other]).is_err());
+        let other = table_build!(
+            String::from("id") => vector_build!(Long, Long::new(1)),
+            String::from("name") => vector_build!(Symbol, Symbol::new("a".into()))
+        );
+        assert!(table.concat(&[other]).is_err());
+    }
+
+    #[test]
+    fn test_types_func_form_table_append_row() {
+        let mut table = sample_rows_table();
+        table
+            .append_row(vec![Int::new(5).into(), Symbol::new("e".into()).into()])
+            .unwrap();
+        assert_eq!(table.len(), 5);
+        assert!(table
+            .append_row(vec![Long::new(5).into(), Symbol::new("e".into()).into()])
+            .is_err());
+        assert!(table.append_row(vec![Int::new(5).into()]).is_err());
+        assert_eq!(table.len(), 5);
+    }
+
+    #[test]
+    fn test_types_func_form_table_column_ops() {
+        let mut table = sample_rows_table();
+        table.rename_column("name", String::from("label")).unwrap();
+        assert!(table.rename_column("label", String::from("id")).is_err());
+        assert!(table.rename_column("none", String::from("x")).is_err());
+        let old = table
+            .set_column(
+                vector_build!(Int, Int::new(0), Int::new(0), Int::new(0), Int::new(0)).into(),
+                String::from("id"),
+            )
+            .unwrap();
+        assert_eq!(
+            old,
+            Some(sample_rows_table().get_columns_by_index(0).clone())
+        );
+        assert!(table
+            .set_column(vector_build!(Int, Int::new(0)).into(), String::from("x"))
+            .is_err());
+        let removed = table.remove_column("label").unwrap();
+        assert_eq!(removed.len(), 4);
+        assert_eq!(table.column_names(), &vec![String::from("id")]);
+        assert!(table.remove_column("label").is_err());
+        let mut empty = Table::default();
+        empty
+            .set_column(vector_build!(Int, Int::new(0)).into(), String::from("x"))
+            .unwrap();
+        assert_eq!(empty.len(), 1);
+    }
+
+    fn sample_group_table() -> Table {
+        table_build!(
+            String::from("sym") => vector_build!(
+                Symbol,
+                Symbol::new("b".into()),
+                Symbol::new("a".into()),
+                Symbol::new("b".into()),
+                Symbol::new("a".into()),
+                Symbol::default()
+            ),
+            String::from("qty") => vector_build!(Int, Int::new(3), Int::default(), Int::new(5), Int::new(1), Int::new(7)),
+            String::from("price") => vector_build!(
+                Double,
+                Double::new(1.5),
+                Double::new(2.0),
+                Double::default(),
+                Double::new(4.0),
+                Double::new(0.5)
+            ),
+            String::from("amount") => vector_build!(
+                Decimal32,
+                Decimal32::from_raw(150, 2).unwrap(),
+                Decimal32::from_raw(200, 2).unwrap(),
+                Decimal32::from_raw(5, 1).unwrap(),
+                Decimal32::default(),
+                Decimal32::from_raw(1, 0).unwrap()
+            ),
+            String::from("date") => vector_build!(
+                Date,
+                Date::from_ymd(2024, 1, 3).unwrap(),
+                Date::from_ymd(2024, 1, 1).unwrap(),
+                Date::from_ymd(2024, 1, 2).unwrap(),
+                Date::default(),
+                Date::from_ymd(2024, 1, 5).unwrap()
+            )
+        )
+    }
+
+    #[test]
+    fn test_types_func_form_table_sort_by() {
+        let table = sample_group_table();
+        let sorted = table.sort_by(&["qty"], &[true]).unwrap();
+        assert_eq!(
+            *sorted.get_columns_by_name("qty").unwrap(),
+            vector_build!(
+                Int,
+                Int::default(),
+                Int::new(1),
+                Int::new(3),
+                Int::new(5),
+                Int::new(7)
+            )
+            .into()
+        );
+        let sorted = table.sort_by(&["qty"], &[false]).unwrap();
+        assert_eq!(
+            *sorted.get_columns_by_name("qty").unwrap(),
+            vector_build!(
+                Int,
+                Int::new(7),
+                Int::new(5),
+                Int::new(3),
+                Int::new(1),
+                Int::default()
+            )
+            .into()
+        );
+        let sorted = table.sort_by(&["sym", "price"], &[true, false]).unwrap();
+        assert_eq!(
+            *sorted.get_columns_by_name("price").unwrap(),
+            vector_build!(
+                Double,
+                Double::new(0.5),
+                Double::new(4.0),
+                Double::new(2.0),
+                Double::new(1.5),
+                Double::default()
+            )
+            .into()
+        );
+        assert!(table
+            .sort_by(&["qty", "price"], &[true, false, true])
+            .is_err());
+        assert!(table.sort_by(&["none"], &[true]).is_err());
+    }
+
+    #[test]
+    fn test_types_func_form_table_group_by_agg() {
+        let table = sample_group_table();
+        let groups = table.group_by(&["sym"]).unwrap();
+        assert_eq!(groups.len(), 3);
+        let res = groups
+            .agg(&[
+                ("qty", Aggregation::Sum),
+                ("price", Aggregation::Avg),
+                ("qty", Aggregation::Count),
+                ("date", Aggregation::Min),
+                ("date", Aggregation::Max),
+                ("price", Aggregation::First),
+                ("price", Aggregation::Last),
+                ("amount", Aggregation::Sum),
+            ])
+            .unwrap();
+        assert_eq!(
+            res.column_names(),
+            &vec![
+                "sym",
+                "sum_qty",
+                "avg_price",
+                "count_qty",
+                "min_date",
+                "max_date",
+                "first_price",
+                "last_price",
+                "sum_amount"
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>()
+        );
+        // groups are sorted by key, null first.
+        assert_eq!(
+            *res.get_columns_by_name("sym").unwrap(),
+            vector_build!(
+                Symbol,
+                Symbol::default(),
+                Symbol::new("a".into()),
+                Symbol::new("b".into())
+            )
+            .into()
+        );
+        assert_eq!(
+            *res.get_columns_by_name("sum_qty").unwrap(),
+            vector_build!(Long, Long::new(7), Long::new(1), Long::new(8)).into()
+        );
+        assert_eq!(
+            *res.get_columns_by_name("avg_price").unwrap(),
+            vector_build!(Double, Double::new(0.5), Double::new(3.0), Double::new(1.5)).into()
+        );
+        assert_eq!(
+            *res.get_columns_by_name("count_qty").unwrap(),
+            vector_build!(Int, Int::new(1), Int::new(1), Int::new(2)).into()
+        );
+        assert_eq!(
+            *res.get_columns_by_name("min_date").unwrap(),
+            vector_build!(
+                Date,
+                Date::from_ymd(2024, 1, 5).unwrap(),
+                Date::from_ymd(2024, 1, 1).unwrap(),
+                Date::from_ymd(2024, 1, 2).unwrap()
+            )
+            .into()
+        );
+        assert_eq!(
+            *res.get_columns_by_name("max_date").unwrap(),
+            vector_build!(
+                Date,
+                Date::from_ymd(2024, 1, 5).unwrap(),
+                Date::from_ymd(2024, 1, 1).unwrap(),
+                Date::from_ymd(2024, 1, 3).unwrap()
+            )
+            .into()
+        );
+        assert_eq!(
+            *res.get_columns_by_name("last_price").unwrap(),
+            vector_build!(
+                Double,
+                Double::new(0.5),
+                Double::new(4.0),
+                Double::default()
+            )
+            .into()
+        );
+        assert_eq!(
+            *res.get_columns_by_name("sum_amount").unwrap(),
+            vector_build!(
+                Decimal64,
+                Decimal64::from_raw(1, 0).unwrap(),
+                Decimal64::from_raw(200, 2).unwrap(),
+                Decimal64::from_raw(200, 2).unwrap()
+            )
+            .into()
+        );
+        assert!(groups.agg(&[("date", Aggregation::Sum)]).is_err());
+        assert!(groups.agg(&[("none", Aggregation::Sum)]).is_err());
+    }
+
+    #[test]
+    fn test_types_func_form_vector_aggregate() {
+        let ints = vector_build!(Int, Int::new(1), Int::default(), Int::new(4), Int::new(-2));
+        assert_eq!(ints.sum(), Long::new(3));
+        assert_eq!(ints.avg(), Double::new(1.0));
+        assert_eq!(ints.count(), 3);
+        assert_eq!(ints.min(), Some(&Int::new(-2)));
+        assert_eq!(ints.max(), Some(&Int::new(4)));
+        assert_eq!(ints.std(), Double::new(3.0));
+        assert_eq!(
+            ints.cumsum(),
+            vector_build!(Long, Long::new(1), Long::new(1), Long::new(5), Long::new(3))
+        );
+        let nulls = vector_build!(Double, Double::default());
+        assert!(nulls.sum().is_null());
+        assert!(nulls.avg().is_null());
+        assert!(nulls.std().is_null());
+        assert_eq!(nulls.min(), None);
+
+        let v: VectorImpl = ints.into();
+        assert_eq!(v.sum().unwrap(), ScalarImpl::Long(Long::new(3)));
+        assert_eq!(v.count().unwrap(), 3);
+        assert_eq!(v.min().unwrap(), ScalarImpl::Int(Int::new(-2)));
+        assert_eq!(v.std().unwrap(), Double::new(3.0));
+        let v: VectorImpl = nulls.into();
+        assert_eq!(v.max().unwrap(), ScalarImpl::Double(Double::default()));
+        let v: VectorImpl = vector_build!(Symbol, Symbol::new("a".into())).into();
+        assert!(v.sum().is_err());
+        assert_eq!(
+            v.max().unwrap(),
+            ScalarImpl::Symbol(Symbol::new("a".into()))
+        );
+    }
+
+    #[test]
+    fn test_types_func_form_vector_decimal_aggregate() {
+        let v: VectorImpl = vector_build!(
+            Decimal64,
+            Decimal64::from_raw(125, 2).unwrap(),
+            Decimal64::default(),
+            Decimal64::from_raw(5, 1).unwrap()
+        )
+        .into();
+        assert_eq!(
+            v.sum().unwrap(),
+            ScalarImpl::Decimal128(Decimal128::from_raw(175, 2).unwrap())
+        );
+        assert_eq!(v.avg().unwrap(), Double::new(0.875));
+        assert_eq!(
+            v.cumsum().unwrap(),
+            vector_build!(
+                Decimal128,
+                Decimal128::from_raw(125, 2).unwrap(),
+                Decimal128::from_raw(125, 2).unwrap(),
+                Decimal128::from_raw(175, 2).unwrap()
+            )
+            .into()
+        );
+    }
+
+    #[test]
+    fn test_types_func_form_vector_arithmetic() {
+        let a = vector_build!(Int, Int::new(7), Int::new(-7), Int::default(), Int::new(1));
+        let b = vector_build!(Int, Int::new(2), Int::new(2), Int::new(1), Int::new(0));
+        assert_eq!(
+            &a + &b,
+            vector_build!(Int, Int::new(9), Int::new(-5), Int::default(), Int::new(1))
+        );
+        assert_eq!(
+            &a / &b,
+            vector_build!(
+                Int,
+                Int::new(3),
+                Int::new(-4),
+                Int::default(),
+                Int::default()
+            )
+        );
+        assert_eq!(
+            &a * Int::new(2),
+            vector_build!(
+                Int,
+                Int::new(14),
+                Int::new(-14),
+                Int::default(),
+                Int::new(2)
+            )
+        );
+        let d = vector_build!(Double, Double::new(1.0), Double::new(3.0));
+        assert_eq!(
+            &d / Double::new(0.0),
+            vector_build!(Double, Double::default(), Double::default())
+        );
+
+        let a: VectorImpl = a.into();
+        let long: VectorImpl =
+            vector_build!(Long, Long::new(1), Long::new(1), Long::new(1), Long::new(1)).into();
+        assert_eq!(
+            a.sub(&long).unwrap(),
+            vector_build!(
+                Long,
+                Long::new(6),
+                Long::new(-8),
+                Long::default(),
+                Long::new(0)
+            )
+            .into()
+        );
+        assert_eq!(
+            a.mul(&ScalarImpl::Double(Double::new(0.5))).unwrap(),
+            vector_build!(
+                Double,
+                Double::new(3.5),
+                Double::new(-3.5),
+                Double::default(),
+                Double::new(0.5)
+            )
+            .into()
+        );
+        let dec: VectorImpl = vector_build!(
+            Decimal32,
+            Decimal32::from_raw(150, 2).unwrap(),
+            Decimal32::from_raw(1, 1).unwrap(),
+            Decimal32::from_raw(1, 1).unwrap(),
+            Decimal32::from_raw(1, 1).unwrap()
+        )
+        .into();
+        assert_eq!(
+            dec.add(&a).unwrap(),
+            vector_build!(
+                Decimal32,
+                Decimal32::from_raw(850, 2).unwrap(),
+                Decimal32::from_raw(-69, 1).unwrap(),
+                Decimal32::default(),
+                Decimal32::from_raw(11, 1).unwrap()
+            )
+            .into()
+        );
+        assert_eq!(
+            dec.mul(&dec).unwrap(),
+            vector_build!(
+                Decimal32,
+                Decimal32::from_raw(22500, 4).unwrap(),
+                Decimal32::from_raw(1, 2).unwrap(),
+                Decimal32::from_raw(1, 2).unwrap(),
+                Decimal32::from_raw(1, 2).unwrap()
+            )
+            .into()
+        );
+        let short: VectorImpl = vector_build!(Int, Int::new(1)).into();
+        assert!(a.add(&short).is_err());
+        let sym: VectorImpl = vector_build!(Symbol, Symbol::new("a".into())).into();
+        assert!(sym.add(&sym).is_err());
+    }
+
+    #[test]
+    fn test_types_func_form_scalar_cast() {
+        let int = ScalarImpl::Int(Int::new(42));
+        assert_eq!(
+            int.cast(DataType::Long).unwrap(),
+            ScalarImpl::Long(Long::new(42))
+        );
+        assert_eq!(
+            int.cast(DataType::Symbol).unwrap(),
+            ScalarImpl::Symbol(Symbol::new("42".into()))
+        );
+        assert_eq!(
+            ScalarImpl::Long(Long::new(300))
+                .cast(DataType::Char)
+                .unwrap(),
+            ScalarImpl::Char(Char::default())
+        );
+        assert_eq!(
+            ScalarImpl::Double(Double::new(-2.5))
+                .cast(DataType::Int)
+                .unwrap(),
+            ScalarImpl::Int(Int::new(-3))
+        );
+        assert_eq!(
+            ScalarImpl::String(DolphinString::new("3.7".into()))
+                .cast(DataType::Int)
+                .unwrap(),
+            ScalarImpl::Int(Int::new(3))
+        );
+        assert_eq!(
+            ScalarImpl::String(DolphinString::new("abc".into()))
+                .cast(DataType::Symbol)
+                .unwrap(),
+            ScalarImpl::Symbol(Symbol::new("abc".into()))
+        );
+        assert_eq!(
+            ScalarImpl::Int(Int::default())
+                .cast(DataType::Double)
+                .unwrap(),
+            ScalarImpl::Double(Double::default())
+        );
+        assert_eq!(
+            ScalarImpl::Double(Double::new(1.23456))
+                .cast_decimal(DataType::Decimal64, 4)
+                .unwrap(),
+            ScalarImpl::Decimal64(Decimal64::from_raw(12346, 4).unwrap())
+        );
+        assert_eq!(
+            ScalarImpl::Decimal64(Decimal64::from_raw(-199, 2).unwrap())
+                .cast(DataType::Int)
+                .unwrap(),
+            ScalarImpl::Int(Int::new(-1))
+        );
+        assert_eq!(
+            ScalarImpl::Long(Long::new(i64::MAX))
+                .cast_decimal(DataType::Decimal32, 2)
+                .unwrap(),
+            ScalarImpl::Decimal32(Decimal32::default())
+        );
+        assert!(int.cast_decimal(DataType::Decimal32, 10).is_err());
+        assert!(int.cast_decimal(DataType::Double, 2).is_err());
+        assert!(ScalarImpl::Blob(Blob::new(vec![1]))
+            .cast(DataType::Int)
+            .is_err());
+    }
+
+    #[test]
+    fn test_types_func_form_scalar_cast_temporal() {
+        let ts = ScalarImpl::Timestamp(Timestamp::from(
+            NaiveDate::from_ymd_opt(2024, 3, 15)
+                .unwrap()
+                .and_hms_milli_opt(13, 45, 30, 123)
+                .unwrap(),
+        ));
+        assert_eq!(
+            ts.cast(DataType::Date).unwrap(),
+            ScalarImpl::Date(Date::from_ymd(2024, 3, 15).unwrap())
+        );
+        assert_eq!(
+            ts.cast(DataType::Month).unwrap(),
+            ScalarImpl::Month(Month::from_ym(2024, 3).unwrap())
+        );
+        assert_eq!(
+            ts.cast(DataType::DateHour).unwrap(),
+            ScalarImpl::DateHour(DateHour::from_ymd_h(2024, 3, 15, 13).unwrap())
+        );
+        assert_eq!(
+            ts.cast(DataType::Minute).unwrap(),
+            ScalarImpl::Minute(Minute::from_raw(13 * 60 + 45).unwrap())
+        );
+        assert_eq!(
+            ts.cast(DataType::Second)
+                .unwrap()
+                .cast(DataType::Time)
+                .unwrap(),
+            ScalarImpl::Time(Time::from_raw((13 * 3600 + 45 * 60 + 30) * 1000).unwrap())
+        );
+
+        let date = ScalarImpl::Date(Date::from_ymd(1970, 1, 2).unwrap());
+        assert_eq!(
+            date.cast(DataType::Timestamp).unwrap(),
+            ScalarImpl::Timestamp(Timestamp::from_raw(86_400_000).unwrap())
+        );
+        assert_eq!(
+            date.cast(DataType::Int).unwrap(),
+            ScalarImpl::Int(Int::new(1))
+        );
+        assert_eq!(
+            ScalarImpl::Int(Int::new(1)).cast(DataType::Date).unwrap(),
+            date
+        );
+        assert_eq!(
+            ScalarImpl::Int(Int::new(24 * 3600))
+                .cast(DataType::Second)
+                .unwrap(),
+            ScalarImpl::Second(Second::default())
+        );
+        assert!(ScalarImpl::Minute(Minute::from_raw(1).unwrap())
+            .cast(DataType::Date)
+            .is_err());
+    }
+
+    #[test]
+    fn test_types_func_form_vector_cast() {
+        let v: VectorImpl = vector_build!(
+            Double,
+            Double::new(1.6),
+            Double::default(),
+            Double::new(1e20)
+        )
+        .into();
+        assert_eq!(
+            v.cast(DataType::Int).unwrap(),
+            vector_build!(Int, Int::new(2), Int::default(), Int::default()).into()
+        );
+        assert_eq!(
+            v.cast_decimal(DataType::Decimal64, 1).unwrap(),
+            vector_build!(
+                Decimal64,
+                Decimal64::from_raw(16, 1).unwrap(),
+                Decimal64::default(),
+                Decimal64::default()
+            )
+            .into()
+        );
+        let s: VectorImpl = vector_build!(
+            DolphinString,
+            DolphinString::new("a".into()),
+            DolphinString::default()
+        )
+        .into();
+        assert_eq!(
+            s.cast(DataType::Symbol).unwrap(),
+            vector_build!(Symbol, Symbol::new("a".into()), Symbol::default()).into()
+        );
+        assert!(v.cast(DataType::Any).is_err());
+    }
+
+    #[test]
+    fn test_types_func_form_scalar_parse() {
+        assert_eq!(
+            "2024.01.02".parse::<Date>().unwrap(),
+            Date::from_ymd(2024, 1, 2).unwrap()
+        );
+        assert_eq!(
+            "2024.01M".parse::<Month>().unwrap(),
+            Month::from_ym(2024, 1).unwrap()
+        );
+        assert_eq!(
+            "13:30:10.008".parse::<Time>().unwrap(),
+            Time::from_hms_milli(13, 30, 10, 8).unwrap()
+        );
+        assert_eq!(
+            "10:00m".parse::<Minute>().unwrap(),
+            Minute::from_hm(10, 0).unwrap()
+        );
+        assert_eq!(
+            "2024.01.02T13:30:10.008123456"
+                .parse::<NanoTimestamp>()
+                .unwrap(),
+            NanoTimestamp::from_raw(1_704_202_210_008_123_456).unwrap()
+        );
+        assert_eq!(
+            "2024.01.02T13:30:10.008123456"
+                .parse::<Timestamp>()
+                .unwrap(),
+            Timestamp::from_raw(1_704_202_210_008).unwrap()
+        );
+        assert_eq!(
+            "2024.01.02T13".parse::<DateHour>().unwrap(),
+            DateHour::from_ymd_h(2024, 1, 2, 13).unwrap()
+        );
+        assert_eq!("null".parse::<Int>().unwrap(), Int::default());
+        assert_eq!("".parse::<Date>().unwrap(), Date::default());
+        assert_eq!(
+            "-3m".parse::<Duration>().unwrap(),
+            Duration::new(-3, DurationUnit::Minute)
+        );
+        assert_eq!(
+            "1.50".parse::<Decimal32>().unwrap(),
+            Decimal32::from_raw(150, 2).unwrap()
+        );
+        assert!("1.0000000001".parse::<Decimal32>().is_err());
+        assert!("2024.13.01".parse::<Date>().is_err());
+        assert!("abc".parse::<Int>().is_err());
+
+        assert_eq!(
+            ScalarImpl::parse(DataType::Second, "13:30:10").unwrap(),
+            ScalarImpl::Second(Second::from_hms(13, 30, 10).unwrap())
+        );
+        assert_eq!(
+            ScalarImpl::parse(DataType::Symbol, "null").unwrap(),
+            ScalarImpl::Symbol(Symbol::new("null".into()))
+        );
+        assert!(ScalarImpl::parse(DataType::Any, "1").is_err());
+        assert_eq!(
+            ScalarImpl::String(DolphinString::new("2024.01.02".into()))
+                .cast(DataType::Date)
+                .unwrap(),
+            ScalarImpl::Date(Date::from_ymd(2024, 1, 2).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_types_func_form_scalar_parse_round_trip() {
+        let dt = NaiveDate::from_ymd_opt(2024, 1, 2)
+            .unwrap()
+            .and_hms_nano_opt(13, 30, 10, 8_123_456)
+            .unwrap();
+        let scalars: Vec<ScalarImpl> = vec![
+            Bool::new(false).into(),
+            Char::new(-5).into(),
+            Char::new(b'a' as i8).into(),
+            Short::new(-7).into(),
+            Int::new(i32::MAX).into(),
+            Long::new(-42).into(),
+            Float::new(0.1).into(),
+            Double::new(-1.0e-300).into(),
+            Date::from(dt.date()).into(),
+            Month::from_ym(1969, 12).unwrap().into(),
+            Time::from_hms_milli(13, 30, 10, 8).unwrap().into(),
+            Time::from_hms_milli(13, 30, 10, 0).unwrap().into(),
+            Minute::from_hm(23, 59).unwrap().into(),
+            Second::from_hms(0, 0, 1).unwrap().into(),
+            DateTime::from_raw(-1).unwrap().into(),
+            Timestamp::from_raw(1_704_202_210_008).unwrap().into(),
+            NanoTime::from(dt.time()).into(),
+            NanoTimestamp::from(dt).into(),
+            DateHour::from_ymd_h(2024, 1, 2, 13).unwrap().into(),
+            Duration::new(5, DurationUnit::Exchange(*b"XNYS")).into(),
+            Decimal32::from_raw(-1, 9).unwrap().into(),
+            Decimal64::from_raw(i64::MAX, 4).unwrap().into(),
+            Decimal128::from_raw(123456, 0).unwrap().into(),
+            Blob::new(vec![0, 255]).into(),
+            Int128::new(-2).into(),
+            Uuid::new(0x5d212a78_cc48_e3b1_4235_b4d91473ee87).into(),
+            IpAddr::new("192.168.1.13".parse().unwrap()).into(),
+            IpAddr::new("::1".parse().unwrap()).into(),
+            DolphinString::new("a b".into()).into(),
+            Int::default().into(),
+            NanoTimestamp::default().into(),
+        ];
+        for s in scalars {
+            assert_eq!(ScalarImpl::parse(s.data_type(), &s.to_string()).unwrap(), s);
+        }
+    }
+
+    #[test]
+    fn test_types_func_form_temporal_zoned() {
+        use chrono::{FixedOffset, TimeZone, Utc};
+
+        let shanghai = FixedOffset::east_opt(8 * 3600).unwrap();
+        let utc = Utc.with_ymd_and_hms(2024, 1, 2, 5, 30, 10).unwrap()
+            + chrono::Duration::nanoseconds(8_123_456);
+
+        let ts = Timestamp::from_zoned(&utc, &shanghai).unwrap();
+        assert_eq!(ts.to_string(), "2024.01.02T13:30:10.008");
+        assert_eq!(
+            ts.to_zoned(&shanghai).unwrap(),
+            utc - chrono::Duration::nanoseconds(123_456)
+        );
+        assert_eq!(
+            ts.to_utc().unwrap().to_rfc3339(),
+            "2024-01-02T13:30:10.008+00:00"
+        );
+        assert_eq!(
+            NanoTimestamp::from_utc(&utc).unwrap().to_utc().unwrap(),
+            utc
+        );
+        assert_eq!(
+            DateTime::from_zoned(&utc, &shanghai).unwrap().to_string(),
+            "2024.01.02T13:30:10"
+        );
+        assert_eq!(
+            DateHour::from_utc(&utc).unwrap(),
+            DateHour::from_ymd_h(2024, 1, 2, 5).unwrap()
+        );
+        assert_eq!(Timestamp::default().to_utc(), None);
+        let far = Utc.with_ymd_and_hms(2100, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(DateTime::from_utc(&far), None);
+
+        let v = Vector::<Timestamp>::from_zoned([&utc, &far], &shanghai);
+        assert_eq!(v.len(), 2);
+        assert_eq!(
+            v.to_zoned(&Utc)[0],
+            Some(utc + chrono::Duration::hours(8) - chrono::Duration::nanoseconds(123_456))
+        );
+        assert_eq!(
+            Vector::<DateTime>::from_zoned([&utc, &far], &Utc).to_zoned(&Utc),
+            vec![Some(utc - chrono::Duration::nanoseconds(8_123_456)), None]
+        );
+    }
+
+    #[test]
+    fn test_types_func_form_temporal_zoned_dst() {
+        use chrono::{TimeZone, Utc};
+        use chrono_tz::America::New_York;
+        let at = |m, d, h, min| {
+            chrono::NaiveDate::from_ymd_opt(2024, m, d)
+                .unwrap()
+                .and_hms_opt(h, min, 0)
+                .unwrap()
+        };
+
+        // the offset of an exchange-local zone follows daylight saving time.
+        let winter = ScalarImpl::from(Timestamp::from(at(1, 2, 9, 30)));
+        let summer = ScalarImpl::from(Timestamp::from(at(7, 2, 9, 30)));
+        assert_eq!(
+            winter.to_zoned(&New_York).unwrap().unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 2, 14, 30, 0).unwrap()
+        );
+        assert_eq!(
+            summer.to_zoned(&New_York).unwrap().unwrap(),
+            Utc.with_ymd_and_hms(2024, 7, 2, 13, 30, 0).unwrap()
+        );
+        // skipped by the transition to daylight saving time.
+        let skipped = ScalarImpl::from(DateTime::from(at(3, 10, 2, 30)));
+        assert_eq!(skipped.to_zoned(&New_York).unwrap(), None);
+        assert!(ScalarImpl::from(Int::new(1)).to_zoned(&New_York).is_err());
+
+        let v: VectorImpl = vector_build!(
+            DateHour,
+            DateHour::from_ymd_h(2024, 7, 2, 9).unwrap(),
+            DateHour::default()
+        )
+        .into();
+        assert_eq!(
+            v.to_zoned(&New_York).unwrap(),
+            vec![
+                Some(New_York.with_ymd_and_hms(2024, 7, 2, 9, 0, 0).unwrap()),
+                None
+            ]
+        );
+        let v: VectorImpl = vector_build!(Int, Int::new(1)).into();
+        assert!(v.to_zoned(&New_York).is_err());
+    }
+
+    #[test]
+    fn test_types_func_form_decimal_arithmetic() {
+        use rust_decimal::RoundingStrategy;
+
+        let a = Decimal32::from_raw(125, 2).unwrap();
+        let b = Decimal32::from_raw(-3, 1).unwrap();
+        assert_eq!(
+            a.checked_add(&b).unwrap(),
+            Decimal32::from_raw(95, 2).unwrap()
+        );
+        assert_eq!(a.checked_add(&b).unwrap().scale(), Some(2));
+        assert_eq!(
+            a.checked_sub(&b).unwrap(),
+            Decimal32::from_raw(155, 2).unwrap()
+        );
+        assert_eq!(
+            a.checked_mul(&b, 2, RoundingStrategy::MidpointAwayFromZero)
+                .unwrap(),
+            Decimal32::from_raw(-38, 2).unwrap()
+        );
+        assert_eq!(
+            a.checked_mul(&b, 2, RoundingStrategy::ToZero).unwrap(),
+            Decimal32::from_raw(-37, 2).unwrap()
+        );
+        assert_eq!(
+            a.checked_div(&b, 3, RoundingStrategy::MidpointNearestEven)
+                .unwrap(),
+            Decimal32::from_raw(-4167, 3).unwrap()
+        );
+        assert!(a
+            .checked_div(
+                &Decimal32::from_raw(0, 0).unwrap(),
+                2,
+                RoundingStrategy::ToZero
+            )
+            .is_err());
+        assert!(a.checked_add(&Decimal32::default()).unwrap().is_null());
+
+        let max = Decimal32::from_raw(i32::MAX, 0).unwrap();
+        assert!(max.checked_add(&max).is_err());
+        assert!(a.round(10, RoundingStrategy::ToZero).is_err());
+        assert_eq!(
+            a.round(1, RoundingStrategy::MidpointNearestEven).unwrap(),
+            Decimal32::from_raw(12, 1).unwrap()
+        );
+
+        let mut c = a;
+        assert!(c.checked_rescale(1).is_err());
+        assert_eq!(c, a);
+        let mut big = Decimal32::from_raw(325, 2).unwrap();
+        assert!(big.checked_rescale(9).is_err());
+        c.checked_rescale(4).unwrap();
+        assert_eq!(c.mantissa(), Some(12500));
+    }
+
+    #[test]
+    fn test_types_func_form_decimal_conversion() {
+        use rust_decimal::RoundingStrategy;
+
+        let d: Decimal128 = Decimal64::from_raw(i64::MAX, 3).unwrap().into();
+        assert_eq!(d.mantissa(), Some(i64::MAX as i128));
+        assert!(Decimal32::try_from(d).is_err());
+        assert!(Decimal64::try_from(d).is_ok());
+        assert_eq!(
+            Decimal32::try_from(Decimal128::from_raw(-7, 9).unwrap()).unwrap(),
+            Decimal32::from_raw(-7, 9).unwrap()
+        );
+        assert!(Decimal32::try_from(Decimal64::default()).unwrap().is_null());
+
+        assert_eq!(
+            Decimal64::try_from(Decimal::new(12345, 3)).unwrap(),
+            Decimal64::from_raw(12345, 3).unwrap()
+        );
+        assert!(Decimal64::try_from(Decimal::new(1, 20)).is_err());
+        assert_eq!(
+            Decimal64::from_f64(2.675, 2, RoundingStrategy::MidpointAwayFromZero).unwrap(),
+            Decimal64::from_raw(268, 2).unwrap()
+        );
+        assert!(Decimal64::from_f64(f64::NAN, 2, RoundingStrategy::ToZero).is_err());
+        assert!(Decimal32::from_f64(1e10, 0, RoundingStrategy::ToZero).is_err());
+        assert_eq!(Decimal32::MAX_SCALE, 9);
+    }
+
+    #[test]
+    fn test_types_func_form_vector_decimal_scale() {
+        assert!(Decimal32Vector::with_scale(10).is_err());
+        let mut v = Decimal64Vector::with_scale(2).unwrap();
+        assert_eq!(v.scale(), Some(2));
+        v.try_push(Decimal64::from_raw(12345, 3).unwrap()).unwrap();
+        v.try_push(Decimal64::default()).unwrap();
+        assert_eq!(v[0], Decimal64::from_raw(1235, 2).unwrap());
+        assert_eq!(v[0].scale(), Some(2));
+        assert!(v[1].is_null());
+        assert!(v
+            .try_push(Decimal64::from_raw(i64::MAX, 0).unwrap())
+            .is_err());
+        assert_eq!(v.len(), 2);
+
+        let mut v = Decimal32Vector::new();
+        assert_eq!(v.scale(), None);
+        v.push(Decimal32::from_raw(1_000_000, 0).unwrap());
+        assert!(v.set_scale(4).is_err());
+        assert_eq!(v.scale(), None);
+        v.set_scale(3).unwrap();
+        assert_eq!(v[0].mantissa(), Some(1_000_000_000));
+
+        let mut v: VectorImpl = Decimal128Vector::with_scale(1).unwrap().into();
+        assert_eq!(v.decimal_scale(), Some(1));
+        v.push(Decimal128::from_raw(-15, 2).unwrap().into())
+            .unwrap();
+        assert_eq!(
+            v,
+            vector_build!(Decimal128, Decimal128::from_raw(-2, 1).unwrap()).into()
+        );
+        let mut ints: VectorImpl = vector_build!(Int, Int::new(1)).into();
+        assert!(ints.set_decimal_scale(1).is_err());
+        assert_eq!(ints.decimal_scale(), None);
+
+        let empty: VectorImpl = LongVector::new().into();
+        let cast = empty.cast_decimal(DataType::Decimal64, 3).unwrap();
+        assert_eq!(cast.decimal_scale(), Some(3));
+
+        let mut rows = Decimal64ArrayVector::new();
+        rows.push(vec![Decimal64::from_raw(12345, 3).unwrap()]);
+        let mut rows = VectorImpl::from(rows);
+        assert_eq!(rows.decimal_scale(), None);
+        rows.set_decimal_scale(2).unwrap();
+        assert_eq!(rows.decimal_scale(), Some(2));
+        let VectorImpl::ArrayVector(ArrayVectorImpl::Decimal64(r)) = &rows else {
+            panic!("expected a decimal64 array vector");
+        };
+        assert_eq!(r[0][0], Decimal64::from_raw(1235, 2).unwrap());
+        assert!(rows.set_decimal_scale(19).is_err());
+    }
+
+    #[test]
+    fn test_types_func_form_table_decimal_scale_mismatch() {
+        let mut prices = Decimal32Vector::with_scale(1).unwrap();
+        prices.push(Decimal32::from_raw(15, 1).unwrap());
+        let table = table_build!(String::from("price") => prices);
+        let mut other = Decimal32Vector::with_scale(2).unwrap();
+        other.push(Decimal32::from_raw(125, 2).unwrap());
+        let other = table_build!(String::from("price") => other);
+        let res = table.concat(&[other]).unwrap();
+        assert_eq!(res.columns()[0].decimal_scale(), Some(1));
+        assert_eq!(
+            res.row(1).unwrap().get(0),
+            Some(Decimal32::from_raw(13, 1).unwrap().into())
+        );
+
+        let mut table = table;
+        assert!(table
+            .append_row(vec![Decimal32::from_raw(i32::MAX, 0).unwrap().into()])
+            .is_err());
+        assert_eq!(table.len(), 1);
+        table
+            .append_row(vec![Decimal32::from_raw(125, 2).unwrap().into()])
+            .unwrap();
+        assert_eq!(
+            table.row(1).unwrap().get(0),
+            Some(Decimal32::from_raw(13, 1).unwrap().into())
+        );
+    }
+
+    #[test]
+    fn test_types_func_form_table_builder_scale() {
+        let prices: VectorImpl = vector_build!(
+            Decimal64,
+            Decimal64::from_raw(15, 1).unwrap(),
+            Decimal64::from_raw(125, 3).unwrap()
+        )
+        .into();
+        let mut builder = TableBuilder::new();
+        builder.with_contents(vec![prices.clone()], vec!["price".into()]);
+        builder.with_scale("price", 2);
+        let table = builder.build().unwrap();
+        let price = table.get_columns_by_name("price").unwrap();
+        assert_eq!(price.decimal_scale(), Some(2));
+        assert_eq!(
+            price.get(1).unwrap(),
+            Decimal64::from_raw(13, 2).unwrap().into()
+        );
+
+        let mut builder = TableBuilder::new();
+        builder.with_contents(vec![prices.clone()], vec!["price".into()]);
+        builder.with_scale("qty", 2);
+        assert!(builder.build().is_err());
+        let mut builder = TableBuilder::new();
+        builder.with_contents(vec![prices], vec!["price".into()]);
+        builder.with_scale("price", 19);
+        assert!(builder.build().is_err());
+    }
+
     // data form
     #[test]
     fn test_types_func_form_data_form_try_from_error() {
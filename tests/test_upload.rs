@@ -2232,14 +2232,14 @@ mod test_upload_table {
         test_upload_table_array_vector_char,
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Char(array_vector_build!(
-                i8,
-                vec![0i8,127i8,-127i8,-128i8],
-                vec![0i8,1i8,2i8]
+                Char,
+                vec![Char::new(0i8), Char::new(127i8), Char::new(-127i8), Char::default()],
+                vec![Char::new(0i8), Char::new(1i8), Char::new(2i8)]
             )))
         ) => "table(array(CHAR[]).append!([[0c,127c,-127c,00c],[0c,1c,2c]]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Char(
-                ArrayVector::<i8>::new()
+                CharArrayVector::new()
             ))
         ) => "table(array(CHAR[]) as `a)"
     );
@@ -2248,14 +2248,14 @@ mod test_upload_table {
         test_upload_table_array_vector_short,
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Short(array_vector_build!(
-                i16,
-                vec![0i16,32767i16,-32767i16,-32768i16],
-                vec![0i16,1i16,2i16]
+                Short,
+                vec![Short::new(0i16), Short::new(32767i16), Short::new(-32767i16), Short::default()],
+                vec![Short::new(0i16), Short::new(1i16), Short::new(2i16)]
             )))
         ) => "table(array(SHORT[]).append!([[0h,32767h,-32767h,00h],[0h,1h,2h]]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Short(
-                ArrayVector::<i16>::new()
+                ShortArrayVector::new()
             ))
         ) => "table(array(SHORT[]) as `a)"
     );
@@ -2264,14 +2264,14 @@ mod test_upload_table {
         test_upload_table_array_vector_int,
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Int(array_vector_build!(
-                i32,
-                vec![0i32,2147483647i32,-2147483647i32,-2147483648i32],
-                vec![0i32,1i32,2i32]
+                Int,
+                vec![Int::new(0i32), Int::new(2147483647i32), Int::new(-2147483647i32), Int::default()],
+                vec![Int::new(0i32), Int::new(1i32), Int::new(2i32)]
             )))
         ) => "table(array(INT[]).append!([[0i,2147483647i,-2147483647i,00i],[0i,1i,2i]]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Int(
-                ArrayVector::<i32>::new()
+                IntArrayVector::new()
             ))
         ) => "table(array(INT[]) as `a)"
     );
@@ -2280,14 +2280,14 @@ mod test_upload_table {
         test_upload_table_array_vector_long,
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Long(array_vector_build!(
-                i64,
-                vec![0i64,9223372036854775807i64,-9223372036854775807i64,-9223372036854775808i64],
-                vec![0i64,1i64,2i64]
+                Long,
+                vec![Long::new(0i64), Long::new(9223372036854775807i64), Long::new(-9223372036854775807i64), Long::default()],
+                vec![Long::new(0i64), Long::new(1i64), Long::new(2i64)]
             )))
         ) => "table(array(LONG[]).append!([[0l,9223372036854775807l,-9223372036854775807l,00l],[0l,1l,2l]]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Long(
-                ArrayVector::<i64>::new()
+                LongArrayVector::new()
             ))
         ) => "table(array(LONG[]) as `a)"
     );
@@ -2296,14 +2296,14 @@ mod test_upload_table {
         test_upload_table_array_vector_float,
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Float(array_vector_build!(
-                f32,
-                vec![0.0f32,3.14f32,f32::MIN],
-                vec![3.14f32,3.15f32,3.16f32]
+                Float,
+                vec![Float::new(0.0f32), Float::new(3.14f32), Float::default()],
+                vec![Float::new(3.14f32), Float::new(3.15f32), Float::new(3.16f32)]
             )))
         ) => "table(array(FLOAT[]).append!([[0.0f,3.14f,00f],[3.14f,3.15f,3.16f]]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Float(
-                ArrayVector::<f32>::new()
+                FloatArrayVector::new()
             ))
         ) => "table(array(FLOAT[]) as `a)"
     );
@@ -2312,14 +2312,14 @@ mod test_upload_table {
         test_upload_table_array_vector_double,
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Double(array_vector_build!(
-                f64,
-                vec![0.0f64,3.14f64,f64::MIN],
-                vec![3.14f64,3.15f64,3.16f64]
+                Double,
+                vec![Double::new(0.0f64), Double::new(3.14f64), Double::default()],
+                vec![Double::new(3.14f64), Double::new(3.15f64), Double::new(3.16f64)]
             )))
         ) => "table(array(DOUBLE[]).append!([[0.0F,3.14F,00F],[3.14F,3.15F,3.16F]]) as `a)",
         table_build!(
             String::from("a") => VectorImpl::ArrayVector(ArrayVectorImpl::Double(
-                ArrayVector::<f64>::new()
+                DoubleArrayVector::new()
             ))
         ) => "table(array(DOUBLE[]) as `a)"
     );
@@ -2341,13 +2341,13 @@ mod test_upload_array_vector {
         // )) => "array(CHAR[]).append!([[0c,127c,-127c,00c],[],[0c,1c,2c]])",
         VectorImpl::ArrayVector(ArrayVectorImpl::Char(
             array_vector_build!(
-                i8,
-                vec![0i8,127i8,-127i8,-128i8],
-                vec![0i8,1i8,2i8]
+                Char,
+                vec![Char::new(0i8), Char::new(127i8), Char::new(-127i8), Char::default()],
+                vec![Char::new(0i8), Char::new(1i8), Char::new(2i8)]
             )
         )) => "array(CHAR[]).append!([[0c,127c,-127c,00c],[0c,1c,2c]])",
         VectorImpl::ArrayVector(ArrayVectorImpl::Char(
-            ArrayVector::<i8>::new()
+            CharArrayVector::new()
         )) => "array(CHAR[])"
     );
     // short
@@ -2363,13 +2363,13 @@ mod test_upload_array_vector {
         // )) => "array(SHORT[]).append!([[0h,32767h,-32767h,00h],[00h]])",
         VectorImpl::ArrayVector(ArrayVectorImpl::Short(
             array_vector_build!(
-                i16,
-                vec![0i16,32767i16,-32767i16,-32768i16],
-                vec![0i16,1i16,2i16]
+                Short,
+                vec![Short::new(0i16), Short::new(32767i16), Short::new(-32767i16), Short::default()],
+                vec![Short::new(0i16), Short::new(1i16), Short::new(2i16)]
             )
         )) => "array(SHORT[]).append!([[0h,32767h,-32767h,00h],[0h,1h,2h]])",
         VectorImpl::ArrayVector(ArrayVectorImpl::Short(
-            ArrayVector::<i16>::new()
+            ShortArrayVector::new()
         )) => "array(SHORT[])"
     );
     // int
@@ -2385,13 +2385,13 @@ mod test_upload_array_vector {
         // )) => "array(INT[]).append!([[0i,2147483647i,-2147483647i,00i],[00i]])",
         VectorImpl::ArrayVector(ArrayVectorImpl::Int(
             array_vector_build!(
-                i32,
-                vec![0i32,2147483647i32,-2147483647i32,-2147483648i32],
-                vec![0i32,1i32,2i32]
+                Int,
+                vec![Int::new(0i32), Int::new(2147483647i32), Int::new(-2147483647i32), Int::default()],
+                vec![Int::new(0i32), Int::new(1i32), Int::new(2i32)]
             )
         )) => "array(INT[]).append!([[0i,2147483647i,-2147483647i,00i],[0i,1i,2i]])",
         VectorImpl::ArrayVector(ArrayVectorImpl::Int(
-            ArrayVector::<i32>::new()
+            IntArrayVector::new()
         )) => "array(INT[])"
     );
     // long
@@ -2407,13 +2407,13 @@ mod test_upload_array_vector {
         // )) => "array(LONG[]).append!([[0l,9223372036854775807i64,-9223372036854775807i64,00l],[00l]])",
         VectorImpl::ArrayVector(ArrayVectorImpl::Long(
             array_vector_build!(
-                i64,
-                vec![0i64,9223372036854775807i64,-9223372036854775807i64,-9223372036854775808i64],
-                vec![0i64,1i64,2i64]
+                Long,
+                vec![Long::new(0i64), Long::new(9223372036854775807i64), Long::new(-9223372036854775807i64), Long::default()],
+                vec![Long::new(0i64), Long::new(1i64), Long::new(2i64)]
             )
         )) => "array(LONG[]).append!([[0l,9223372036854775807l,-9223372036854775807l,00l],[0l,1l,2l]])",
         VectorImpl::ArrayVector(ArrayVectorImpl::Long(
-            ArrayVector::<i64>::new()
+            LongArrayVector::new()
         )) => "array(LONG[])"
     );
     // float
@@ -2429,13 +2429,13 @@ mod test_upload_array_vector {
         // )) => "array(FLOAT[]).append!([[0.0f,3.14f,00f],[3.14f,3.15f,3.16f]])",
         VectorImpl::ArrayVector(ArrayVectorImpl::Float(
             array_vector_build!(
-                f32,
-                vec![0.0f32,3.14f32,f32::MIN],
-                vec![3.14f32,3.15f32,3.16f32]
+                Float,
+                vec![Float::new(0.0f32), Float::new(3.14f32), Float::default()],
+                vec![Float::new(3.14f32), Float::new(3.15f32), Float::new(3.16f32)]
             )
         )) => "array(FLOAT[]).append!([[0.0f,3.14f,00f],[3.14f,3.15f,3.16f]])",
         VectorImpl::ArrayVector(ArrayVectorImpl::Float(
-            ArrayVector::<f32>::new()
+            FloatArrayVector::new()
         )) => "array(FLOAT[])"
     );
     // double
@@ -2451,13 +2451,13 @@ mod test_upload_array_vector {
         // )) => "array(DOUBLE[]).append!([[0.0F,3.14F,00F],[3.14F,3.15F,3.16F]])",
         VectorImpl::ArrayVector(ArrayVectorImpl::Double(
             array_vector_build!(
-                f64,
-                vec![0.0f64,3.14f64,f64::MIN],
-                vec![3.14f64,3.15f64,3.16f64]
+                Double,
+                vec![Double::new(0.0f64), Double::new(3.14f64), Double::default()],
+                vec![Double::new(3.14f64), Double::new(3.15f64), Double::new(3.16f64)]
             )
         )) => "array(DOUBLE[]).append!([[0.0F,3.14F,00F],[3.14F,3.15F,3.16F]])",
         VectorImpl::ArrayVector(ArrayVectorImpl::Double(
-            ArrayVector::<f64>::new()
+            DoubleArrayVector::new()
         )) => "array(DOUBLE[])"
     );
 }